@@ -0,0 +1,108 @@
+//! AES/RSA 암복호화 처리량 측정
+//!
+//! `cargo bench --bench crypto_bench`로 실행한다.
+
+use cliff3_util::encrypt_util::{
+    aes_decrypt, aes_encrypt, generate_rsa_keypair, rsa_decrypt, rsa_decrypt_into,
+    rsa_encrypt_with_keypair, rsa_encrypt_without_key, RsaContext, AES_TYPE, RSA_BIT,
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+
+const AES_PLAIN_SIZE: usize = 1024 * 1024;
+const AES_SECRET: &[u8] = b"benchmark secret";
+const AES_SALT: &[u8] = b"saltsalt";
+const AES_REPEAT_COUNT: usize = 10;
+const RSA_PLAIN: &[u8] = "RSA 벤치마크 대상 문자열".as_bytes();
+
+fn aes_256_bench(c: &mut Criterion) {
+    let plain = vec![0x5au8; AES_PLAIN_SIZE];
+    let encrypted = aes_encrypt(
+        AES_TYPE::AES_256,
+        plain.as_slice(),
+        AES_SECRET,
+        Some(AES_SALT),
+        AES_REPEAT_COUNT,
+    )
+    .unwrap();
+    let mut group = c.benchmark_group("aes_256_1mb");
+
+    group.throughput(Throughput::Bytes(AES_PLAIN_SIZE as u64));
+    group.bench_function("encrypt", |b| {
+        b.iter(|| {
+            aes_encrypt(
+                AES_TYPE::AES_256,
+                black_box(plain.as_slice()),
+                AES_SECRET,
+                Some(AES_SALT),
+                AES_REPEAT_COUNT,
+            )
+            .unwrap()
+        })
+    });
+    group.bench_function("decrypt", |b| {
+        b.iter(|| {
+            aes_decrypt(
+                AES_TYPE::AES_256,
+                Some(black_box(encrypted.result())),
+                AES_SECRET,
+                encrypted.iv(),
+                Some(AES_SALT),
+                AES_REPEAT_COUNT,
+            )
+            .unwrap()
+        })
+    });
+    group.finish();
+}
+
+fn rsa_2048_bench(c: &mut Criterion) {
+    let key = generate_rsa_keypair(RSA_BIT::B_2048).unwrap();
+    let encrypted = rsa_encrypt_with_keypair(RSA_PLAIN, &key).unwrap();
+    let mut group = c.benchmark_group("rsa_2048");
+
+    group.throughput(Throughput::Bytes(RSA_PLAIN.len() as u64));
+    group.bench_function("encrypt", |b| {
+        b.iter(|| rsa_encrypt_with_keypair(black_box(RSA_PLAIN), &key).unwrap())
+    });
+    group.bench_function("decrypt", |b| {
+        b.iter(|| rsa_decrypt(black_box(encrypted.result()), encrypted.private_key()).unwrap())
+    });
+
+    // 결과 버퍼 재사용
+    let mut buffer: Vec<u8> = Vec::with_capacity(RSA_BIT::B_2048.bytes() as usize);
+
+    group.bench_function("decrypt_into", |b| {
+        b.iter(|| {
+            rsa_decrypt_into(
+                black_box(encrypted.result()),
+                encrypted.private_key(),
+                &mut buffer,
+            )
+            .unwrap()
+        })
+    });
+    group.finish();
+}
+
+/// 키 쌍을 매번 생성하는 경우와 [RsaContext]로 재사용하는 경우 비교
+fn rsa_context_bench(c: &mut Criterion) {
+    let context = RsaContext::new(RSA_BIT::B_2048).unwrap();
+    let mut group = c.benchmark_group("rsa_context");
+
+    group.sample_size(10);
+    group.bench_function("encrypt_without_key", |b| {
+        b.iter(|| rsa_encrypt_without_key(black_box(RSA_PLAIN), RSA_BIT::B_2048).unwrap())
+    });
+    group.bench_function("context_encrypt_decrypt", |b| {
+        b.iter(|| {
+            let encrypted = context.encrypt(black_box(RSA_PLAIN)).unwrap();
+
+            context.decrypt(&encrypted).unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, aes_256_bench, rsa_2048_bench, rsa_context_bench);
+criterion_main!(benches);
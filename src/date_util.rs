@@ -1,7 +1,11 @@
 //! 날짜 관련 함수 모음
 
 use crate::error::InvalidArgumentError;
-use chrono::{DateTime, Datelike, Days, Months, NaiveDateTime, Offset, TimeZone, Utc, Weekday};
+use chrono::format::{Item, Numeric, ParseErrorKind, StrftimeItems};
+use chrono::{
+    DateTime, Datelike, Days, FixedOffset, Months, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    TimeZone, Timelike, Utc, Weekday,
+};
 use chrono_tz::Tz;
 
 /// 지정된 날짜 및 시간 문자열을 UTC 날짜로 변경
@@ -82,6 +86,97 @@ pub fn local_datetime_to_utc(
     })
 }
 
+/// 2자리 연도(`%y`)를 포함한 지역 날짜 및 시간 문자열을 피벗 연도 기준으로 해석하여 UTC로 변환
+///
+/// chrono의 `%y`는 `69` ~ `99`를 1900년대, `00` ~ `68`을 2000년대로 고정 해석한다. 이 함수는 파싱된 2자리
+/// 연도가 `pivot`보다 작으면 2000년대, 크거나 같으면 1900년대로 해석한다. (e.g. `pivot`이 `50`일 경우 `49` → 2049,
+/// `50` → 1950)
+///
+/// 시간이 없는 패턴(e.g. `%y-%m-%d`)은 해당 날짜의 `00:00:00`으로 처리한다. 4자리 연도는 [local_datetime_to_utc]를
+/// 사용한다.
+///
+/// # Arguments
+///
+/// - `datetime` - 지역 날짜 및 시간 문자열 (e.g. '24-11-22')
+/// - `pattern` - `%y`를 포함한 날짜 및 시간 패턴 (e.g. '%y-%m-%d')
+/// - `pivot` - 피벗 연도(`0` ~ `100`). `0`이면 모두 1900년대, `100`이면 모두 2000년대로 해석
+/// - `timezone` - `datetime`의 시간대 정보 (e.g. [Tz::Asia__Seoul])
+///
+/// # Return
+///
+/// - 변환 결과 `Result<DateTime<Utc>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `pivot` 범위 초과, `pattern`에 `%y`가 없는 경우, 잘못된 날짜 및 시간 형식
+///   혹은 해석된 연도에 존재하지 않는 날짜(e.g. 1900-02-29)
+///
+/// # Link
+///
+/// - [local_datetime_to_utc]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::Datelike;
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::parse_two_digit_year;
+///
+/// let tz = Tz::Asia__Seoul;
+///
+/// assert_eq!(2049, parse_two_digit_year("49-11-22", "%y-%m-%d", 50, &tz).unwrap().year());
+/// assert_eq!(1950, parse_two_digit_year("50-11-22", "%y-%m-%d", 50, &tz).unwrap().year());
+/// assert_eq!(2050, parse_two_digit_year("50-11-22", "%y-%m-%d", 51, &tz).unwrap().year());
+/// ```
+pub fn parse_two_digit_year(
+    datetime: &str,
+    pattern: &str,
+    pivot: i32,
+    timezone: &Tz,
+) -> Result<DateTime<Utc>, InvalidArgumentError> {
+    if !(0..=100).contains(&pivot) {
+        return Err(InvalidArgumentError::new(
+            format!("피벗 연도는 0 ~ 100 사이여야 합니다 : {}", pivot).as_str(),
+        ));
+    }
+
+    if !StrftimeItems::new(pattern).any(|v| matches!(v, Item::Numeric(Numeric::YearMod100, _))) {
+        return Err(InvalidArgumentError::new(
+            format!("2자리 연도(%y)가 포함되지 않은 패턴입니다 : {}", pattern).as_str(),
+        ));
+    }
+
+    let naive_datetime = match NaiveDateTime::parse_from_str(datetime, pattern) {
+        Err(e) if e.kind() == ParseErrorKind::NotEnough => {
+            NaiveDate::parse_from_str(datetime, pattern).map(|v| v.and_time(NaiveTime::MIN))
+        }
+        v => v,
+    }
+    .map_err(|e| InvalidArgumentError::new(format!("{e:#?}").as_ref()))?;
+
+    let two_digit = naive_datetime.year().rem_euclid(100);
+    let year = if two_digit < pivot {
+        2000 + two_digit
+    } else {
+        1900 + two_digit
+    };
+    let naive_datetime = naive_datetime.with_year(year).ok_or_else(|| {
+        InvalidArgumentError::new(
+            format!("{}년에 존재하지 않는 날짜입니다 : {}", year, datetime).as_str(),
+        )
+    })?;
+
+    timezone
+        .from_local_datetime(&naive_datetime)
+        .earliest()
+        .map(|v| v.with_timezone(&Utc))
+        .ok_or_else(|| {
+            InvalidArgumentError::new(
+                format!("해당 시간대에 존재하지 않는 시간입니다 : {}", datetime).as_str(),
+            )
+        })
+}
+
 /// 지정된 UTC 기준 날짜 및 시간 문자열을 지정된 timezone의 시간대([NaiveDateTime])의 시간으로 변경
 ///
 /// 문자열 형태로 전달되는 UTC 기준 날짜 및 시간 정보를 인자로 전달되는 [Tz]를 이용하여 해당 지역 시간으로 변환하여 반환.
@@ -155,6 +250,49 @@ pub fn utc_datetime_to_local(
     })
 }
 
+/// 특정 시점 기준 두 timezone의 UTC 오프셋 차이(분) 반환
+///
+/// 일광 절약 시간(DST)에 따라 오프셋이 달라지므로 기준 시각 `at`에서의 오프셋을 비교한다. 반환 값은
+/// `tz1의 오프셋 - tz2의 오프셋`이므로, 양수이면 `tz1`의 현지 시각이 `tz2`보다 그만큼 앞서 있다.
+///
+/// # Arguments
+///
+/// - `tz1` - 기준 timezone
+/// - `tz2` - 비교 timezone
+/// - `at` - 기준 시각
+///
+/// # Return
+///
+/// - 오프셋 차이(분, `tz1 - tz2`)
+///
+/// # Link
+///
+/// - [Offset::fix]
+/// - [FixedOffset::local_minus_utc]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::timezone_offset_diff;
+///
+/// // 뉴욕 서머타임(EDT, UTC-4) 적용 중
+/// let summer = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+///
+/// assert_eq!(13 * 60, timezone_offset_diff(&Tz::Asia__Seoul, &Tz::America__New_York, &summer));
+/// assert_eq!(-13 * 60, timezone_offset_diff(&Tz::America__New_York, &Tz::Asia__Seoul, &summer));
+/// ```
+pub fn timezone_offset_diff(tz1: &Tz, tz2: &Tz, at: &DateTime<Utc>) -> i32 {
+    let offset_seconds = |tz: &Tz| {
+        tz.offset_from_utc_datetime(&at.naive_utc())
+            .fix()
+            .local_minus_utc()
+    };
+
+    (offset_seconds(tz1) - offset_seconds(tz2)) / 60
+}
+
 /// 지정한 날짜의 해당 월 마지막 날짜 반환
 ///
 /// # Arguments
@@ -252,83 +390,1109 @@ pub fn get_week_start_end(datetime: &NaiveDateTime) -> (NaiveDateTime, NaiveDate
     (monday, sunday)
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::date_util::{
-        get_latest_day, get_week_start_end, local_datetime_to_utc, utc_datetime_to_local,
-    };
-    use chrono::{
-        DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
-    };
-    use chrono_tz::Tz;
-
-    #[test]
-    fn local_datetime_to_utc_test() {
-        // KST 2024-11-22 09:54:45
-        // UTC 2024-11-22 00:54:45
-        let str_datetime = "20241122095445"; // 2024-11-22 09:54:45
-        let pattern = "%Y%m%d%H%M%S";
-        let timezone = Tz::Asia__Seoul;
-
-        let result = local_datetime_to_utc(str_datetime, pattern, &timezone);
+/// 지정된 날짜 및 시간 문자열이 패턴에 맞는 유효한 날짜인지 검사
+///
+/// timezone 처리 없이 [NaiveDateTime::parse_from_str]의 성공 여부만 반환한다. 존재하지 않는 날짜
+/// (e.g. 2월 30일, 13월)는 `false`를 반환한다. 패턴에 시간 정보가 없을 경우(e.g. `%Y-%m-%d`) 날짜만 검사한다.
+///
+/// # Arguments
+///
+/// - `datetime` - 날짜 및 시간 문자열 (e.g. '2024-11-27 13:23:47')
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+///
+/// # Return
+///
+/// - 유효성 검사 결과
+///
+/// # Link
+///
+/// - [NaiveDateTime::parse_from_str]
+/// - [NaiveDate::parse_from_str]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::is_valid_datetime;
+///
+/// assert!(is_valid_datetime("2024-02-29 10:00:00", "%Y-%m-%d %H:%M:%S"));
+/// assert!(!is_valid_datetime("2025-02-29 10:00:00", "%Y-%m-%d %H:%M:%S"));
+/// assert!(is_valid_datetime("20240229", "%Y%m%d"));
+/// ```
+pub fn is_valid_datetime(datetime: &str, pattern: &str) -> bool {
+    match NaiveDateTime::parse_from_str(datetime, pattern) {
+        Ok(_) => true,
+        Err(e) if e.kind() == ParseErrorKind::NotEnough => {
+            NaiveDate::parse_from_str(datetime, pattern).is_ok()
+        }
+        Err(_) => false,
+    }
+}
 
-        assert!(
-            result.is_ok(),
-            "{}",
-            format!("변환 실패 : {:#?}", result.as_ref().unwrap_err())
-        );
+/// 날짜 포맷 패턴 유효성 검사
+///
+/// 고정된 날짜(`2024-11-22 13:29:48.123456789 +09:00`)를 `pattern`으로 포맷한 후 다시 파싱하여,
+/// 파싱 결과를 재포맷한 문자열이 처음 포맷한 문자열과 같은지(왕복 성립 여부)로 유효성을 검사한다.
+/// 설정 값 등으로 전달받은 패턴을 실제 파싱 전에 미리 검증할 때 사용한다.
+///
+/// 파싱은 [DateTime], [NaiveDateTime], [NaiveDate], [NaiveTime] 순서로 시도하므로 날짜 또는 시간 중
+/// 하나는 완전하게 표현할 수 있어야 한다(e.g. `%Y-%m`, `%A`는 유효하지 않음).
+///
+/// # Arguments
+///
+/// - `pattern` - 검사 대상 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+///
+/// # Return
+///
+/// - 검사 결과 `Result<(), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 빈 패턴, 잘못된 지정자(e.g. `%Q`)가 포함되어 있거나 포맷 결과를 다시 파싱할 수 없는 경우
+///
+/// # Link
+///
+/// - [chrono::format::strftime]
+/// - [is_valid_datetime]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::validate_pattern;
+///
+/// assert!(validate_pattern("%Y-%m-%d %H:%M:%S").is_ok());
+/// assert!(validate_pattern("%Y%m%d").is_ok());
+/// assert!(validate_pattern("%Y-%m-%Q").is_err());
+/// assert!(validate_pattern("%Y-%m").is_err());
+/// ```
+pub fn validate_pattern(pattern: &str) -> Result<(), InvalidArgumentError> {
+    if pattern.is_empty() {
+        return Err(InvalidArgumentError::new("패턴이 지정되지 않았습니다."));
+    }
 
-        let result = result.unwrap();
+    if StrftimeItems::new(pattern).any(|v| matches!(v, Item::Error)) {
+        return Err(InvalidArgumentError::new(
+            format!("잘못된 지정자가 포함된 패턴입니다 : {}", pattern).as_str(),
+        ));
+    }
 
-        println!("utc result => {:#?}", result);
+    let fixed = FixedOffset::east_opt(9 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2024, 11, 22, 13, 29, 48)
+        .unwrap()
+        .with_nanosecond(123_456_789)
+        .unwrap();
+    let formatted = fixed.format(pattern).to_string();
+    let reformatted = if let Ok(v) = DateTime::parse_from_str(&formatted, pattern) {
+        Some(v.format(pattern).to_string())
+    } else if let Ok(v) = NaiveDateTime::parse_from_str(&formatted, pattern) {
+        Some(v.format(pattern).to_string())
+    } else if let Ok(v) = NaiveDate::parse_from_str(&formatted, pattern) {
+        Some(v.format(pattern).to_string())
+    } else if let Ok(v) = NaiveTime::parse_from_str(&formatted, pattern) {
+        Some(v.format(pattern).to_string())
+    } else {
+        None
+    };
 
-        assert_eq!(2024, result.year());
-        assert_eq!(11, result.month());
-        assert_eq!(22, result.day());
-        assert_eq!(0, result.hour());
-        assert_eq!(54, result.minute());
-        assert_eq!(45, result.second());
+    match reformatted {
+        Some(v) if v == formatted => Ok(()),
+        _ => Err(InvalidArgumentError::new(
+            format!("파싱할 수 없는 패턴입니다 : {}", pattern).as_str(),
+        )),
     }
+}
 
-    #[test]
-    fn utc_datetime_to_local_test() {
-        // UTC 2024-11-22 22:54:45
-        // KST 2024-11-23 07:54:45
-        let utc_datetime = "20241122225445";
-        let patter = "%Y%m%d%H%M%S";
-        let timezone = Tz::Asia__Seoul;
-        let result = utc_datetime_to_local(utc_datetime, patter, &timezone);
+/// UTC 날짜를 RFC 3339(ISO 8601) 형식 문자열로 변환
+///
+/// 오프셋은 `+00:00`으로 표기되며, 나노초가 0이 아닐 경우 소수점 이하 초가 포함된다.
+///
+/// # Arguments
+///
+/// - `dt` - 변환 대상 [DateTime]
+///
+/// # Return
+///
+/// - 변환 결과 (e.g. `2024-11-22T01:29:48+00:00`)
+///
+/// # Link
+///
+/// - [DateTime::to_rfc3339]
+/// - [to_rfc3339_with_tz]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::to_rfc3339_string;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 11, 22, 1, 29, 48).unwrap();
+///
+/// assert_eq!("2024-11-22T01:29:48+00:00", to_rfc3339_string(&dt));
+/// ```
+pub fn to_rfc3339_string(dt: &DateTime<Utc>) -> String {
+    dt.to_rfc3339()
+}
 
-        assert!(
-            result.is_ok(),
-            "{}",
-            format!("변환 실패 : {:#?}", result.as_ref().unwrap_err())
-        );
+/// UTC 날짜를 지정된 timezone으로 변환한 후 RFC 3339(ISO 8601) 형식 문자열로 변환
+///
+/// # Arguments
+///
+/// - `dt` - 변환 대상 [DateTime]
+/// - `tz` - 변환할 timezone
+///
+/// # Return
+///
+/// - 변환 결과 (e.g. `2024-11-22T10:29:48+09:00`)
+///
+/// # Link
+///
+/// - [DateTime::with_timezone]
+/// - [DateTime::to_rfc3339]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::to_rfc3339_with_tz;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 11, 22, 1, 29, 48).unwrap();
+///
+/// assert_eq!("2024-11-22T10:29:48+09:00", to_rfc3339_with_tz(&dt, &Tz::Asia__Seoul));
+/// ```
+pub fn to_rfc3339_with_tz(dt: &DateTime<Utc>, tz: &Tz) -> String {
+    dt.with_timezone(tz).to_rfc3339()
+}
 
-        let result = result.unwrap();
+/// RFC 3339(ISO 8601) 형식 문자열을 UTC 날짜로 변환
+///
+/// # Arguments
+///
+/// - `s` - 변환 대상 문자열 (e.g. `2024-11-22T10:29:48+09:00`)
+///
+/// # Return
+///
+/// - 변환 결과 `Result<DateTime<Utc>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - RFC 3339 형식이 아닐 경우
+///
+/// # Link
+///
+/// - [DateTime::parse_from_rfc3339]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::parse_rfc3339;
+///
+/// let result = parse_rfc3339("2024-11-22T10:29:48+09:00").unwrap();
+///
+/// assert_eq!(Utc.with_ymd_and_hms(2024, 11, 22, 1, 29, 48).unwrap(), result);
+/// ```
+pub fn parse_rfc3339(s: &str) -> Result<DateTime<Utc>, InvalidArgumentError> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| InvalidArgumentError::new(format!("{e:#?}").as_ref()))
+}
 
-        println!("local result => {:#?}", result);
+/// 날짜에 지정된 개월 수를 더함(음수일 경우 뺌)
+///
+/// 결과 월에 같은 날짜가 없을 경우 해당 월의 마지막 날짜로 조정(clamp)한다. 시간은 그대로 유지된다.
+///
+/// - `2024-01-31` + 1개월 -> `2024-02-29`
+/// - `2023-01-31` + 1개월 -> `2023-02-28`
+/// - `2024-03-31` - 1개월 -> `2024-02-29`
+///
+/// # Arguments
+///
+/// - `date` - 기준 날짜
+/// - `months` - 더할 개월 수. 음수일 경우 뺀다.
+///
+/// # Return
+///
+/// - 계산 결과 `Result<NaiveDateTime, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 계산 결과가 [NaiveDateTime] 표현 범위를 벗어날 경우
+///
+/// # Link
+///
+/// - [NaiveDateTime::checked_add_months]
+/// - [NaiveDateTime::checked_sub_months]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::add_months;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap().and_hms_opt(9, 0, 0).unwrap();
+/// let result = add_months(&date, 1).unwrap();
+///
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().and_hms_opt(9, 0, 0).unwrap(), result);
+/// ```
+pub fn add_months(
+    date: &NaiveDateTime,
+    months: i32,
+) -> Result<NaiveDateTime, InvalidArgumentError> {
+    let result = if months >= 0 {
+        date.checked_add_months(Months::new(months as u32))
+    } else {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))
+    };
 
-        assert_eq!(2024, result.year());
-        assert_eq!(11, result.month());
-        assert_eq!(23, result.day());
-        assert_eq!(7, result.hour());
-        assert_eq!(54, result.minute());
-        assert_eq!(45, result.second());
-    }
+    result.ok_or_else(|| {
+        InvalidArgumentError::new(
+            format!("날짜 범위를 벗어났습니다 : {} + {}개월", date, months).as_str(),
+        )
+    })
+}
 
-    #[test]
-    fn get_latest_day_test() {
-        // leap month 2024
-        // normal     2025
-        let utc_leap_datetime: DateTime<Utc> =
-            Utc.with_ymd_and_hms(2024, 2, 11, 13, 27, 0).unwrap();
-        let latest_day = get_latest_day(&utc_leap_datetime);
+/// [date_range_step]에서 사용하는 날짜 간격
+///
+/// # Link
+///
+/// - [date_range_step]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum DateStep {
+    /// 일 단위
+    Day(u32),
 
-        assert_eq!(29, latest_day);
+    /// 주 단위
+    Week(u32),
 
-        let utc_datetime = Utc.with_ymd_and_hms(2025, 2, 11, 13, 27, 0).unwrap();
-        let latest_day = get_latest_day(&utc_datetime);
+    /// 월 단위. 해당 월에 같은 날짜가 없으면 월의 마지막 날짜로 조정된다. (e.g. 1월 31일 + 1개월 -> 2월 29일)
+    Month(u32),
+}
+
+impl DateStep {
+    /// `start`로부터 `n`번째 간격의 날짜 반환. 누적 오차(월 말일 조정 등)가 생기지 않도록 항상 `start`를 기준으로 계산한다.
+    fn nth_from(&self, start: NaiveDate, n: u32) -> Option<NaiveDate> {
+        match self {
+            DateStep::Day(v) => start.checked_add_days(Days::new(*v as u64 * n as u64)),
+            DateStep::Week(v) => start.checked_add_days(Days::new(*v as u64 * n as u64 * 7)),
+            DateStep::Month(v) => start.checked_add_months(Months::new(v.checked_mul(n)?)),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        matches!(
+            self,
+            DateStep::Day(0) | DateStep::Week(0) | DateStep::Month(0)
+        )
+    }
+}
+
+/// 시작일부터 종료일까지(포함) 하루 간격으로 순회하는 반복자 반환
+///
+/// `start`가 `end`보다 이후일 경우 빈 반복자를 반환한다.
+///
+/// # Arguments
+///
+/// - `start` - 시작일
+/// - `end` - 종료일 (포함)
+///
+/// # Return
+///
+/// - 날짜 반복자
+///
+/// # Link
+///
+/// - [date_range_step]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::date_range;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+/// let result: Vec<NaiveDate> = date_range(start, end).collect();
+///
+/// assert_eq!(3, result.len());
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(), result[1]);
+/// ```
+pub fn date_range(start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    date_range_step(start, end, DateStep::Day(1))
+}
+
+/// 시작일부터 종료일까지(포함) 지정된 [DateStep] 간격으로 순회하는 반복자 반환
+///
+/// `start`가 `end`보다 이후이거나 간격이 0일 경우 빈 반복자를 반환한다.
+///
+/// # Arguments
+///
+/// - `start` - 시작일
+/// - `end` - 종료일 (포함)
+/// - `step` - 간격 [DateStep]
+///
+/// # Return
+///
+/// - 날짜 반복자
+///
+/// # Link
+///
+/// - [date_range]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::{date_range_step, DateStep};
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap();
+/// let result: Vec<NaiveDate> = date_range_step(start, end, DateStep::Month(1)).collect();
+///
+/// assert_eq!(
+///     vec![
+///         NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 4, 30).unwrap(),
+///     ],
+///     result
+/// );
+/// ```
+pub fn date_range_step(
+    start: NaiveDate,
+    end: NaiveDate,
+    step: DateStep,
+) -> impl Iterator<Item = NaiveDate> {
+    let empty = step.is_zero();
+
+    (0u32..).map_while(move |n| step.nth_from(start, n).filter(|d| !empty && *d <= end))
+}
+
+/// 날짜가 속한 분기(quarter) 반환
+///
+/// # Arguments
+///
+/// - `date` - 대상 날짜
+///
+/// # Return
+///
+/// - 분기 (`1` ~ `4`)
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::get_quarter;
+///
+/// assert_eq!(3, get_quarter(&NaiveDate::from_ymd_opt(2024, 9, 30).unwrap()));
+/// ```
+pub fn get_quarter(date: &NaiveDate) -> u32 {
+    (date.month() - 1) / 3 + 1
+}
+
+/// 날짜의 ISO 8601 주차 반환
+///
+/// ISO 주차는 월요일에 시작하며, 그 해의 첫 번째 목요일이 포함된 주가 1주차이다. 따라서 연말/연초의 날짜는
+/// 다른 연도의 주차에 속할 수 있다.
+///
+/// - `2024-12-30`(월) -> `(2025, 1)`
+/// - `2021-01-03`(일) -> `(2020, 53)`
+///
+/// # Arguments
+///
+/// - `date` - 대상 날짜
+///
+/// # Return
+///
+/// - `(ISO 연도, 주 번호)`. 주 번호는 `1` ~ `53`
+///
+/// # Link
+///
+/// - [NaiveDate::iso_week]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::get_iso_week;
+///
+/// assert_eq!((2024, 47), get_iso_week(&NaiveDate::from_ymd_opt(2024, 11, 22).unwrap()));
+/// assert_eq!((2025, 1), get_iso_week(&NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()));
+/// ```
+pub fn get_iso_week(date: &NaiveDate) -> (i32, u32) {
+    let week = date.iso_week();
+
+    (week.year(), week.week())
+}
+
+/// 날짜의 한글 요일 반환
+///
+/// # Arguments
+///
+/// - `date` - 대상 날짜
+///
+/// # Return
+///
+/// - 요일 (`월`, `화`, `수`, `목`, `금`, `토`, `일`)
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::get_weekday_korean;
+///
+/// assert_eq!("금", get_weekday_korean(&NaiveDate::from_ymd_opt(2024, 11, 22).unwrap()));
+/// ```
+pub fn get_weekday_korean(date: &NaiveDate) -> &'static str {
+    match date.weekday() {
+        Weekday::Mon => "월",
+        Weekday::Tue => "화",
+        Weekday::Wed => "수",
+        Weekday::Thu => "목",
+        Weekday::Fri => "금",
+        Weekday::Sat => "토",
+        Weekday::Sun => "일",
+    }
+}
+
+/// 음력 공휴일(설날·추석·부처님오신날)을 판별할 수 있는 연도 범위
+///
+/// 음력 공휴일은 한국천문연구원 월력요항 기준의 양력 날짜 테이블로 판별하며, 범위 밖의 연도는 고정
+/// 공휴일만 판별한다.
+pub const KOREAN_LUNAR_HOLIDAY_YEARS: std::ops::RangeInclusive<i32> = 2020..=2030;
+
+/// 양력 `(월, 일)`
+type MonthDay = (u32, u32);
+
+/// 연도별 음력 공휴일 양력 날짜 `(연도, 설날, 부처님오신날, 추석)`
+///
+/// 설날과 추석은 당일 기준이며 전날과 다음날까지 공휴일이다.
+const KOREAN_LUNAR_HOLIDAYS: [(i32, MonthDay, MonthDay, MonthDay); 11] = [
+    (2020, (1, 25), (4, 30), (10, 1)),
+    (2021, (2, 12), (5, 19), (9, 21)),
+    (2022, (2, 1), (5, 8), (9, 10)),
+    (2023, (1, 22), (5, 27), (9, 29)),
+    (2024, (2, 10), (5, 15), (9, 17)),
+    (2025, (1, 29), (5, 5), (10, 6)),
+    (2026, (2, 17), (5, 24), (9, 25)),
+    (2027, (2, 7), (5, 13), (9, 15)),
+    (2028, (1, 27), (5, 2), (10, 3)),
+    (2029, (2, 13), (5, 20), (9, 22)),
+    (2030, (2, 3), (5, 9), (9, 12)),
+];
+
+/// 날짜에 해당하는 한국 공휴일 명칭 반환
+///
+/// 「관공서의 공휴일에 관한 규정」의 공휴일 중 아래 항목을 판별한다.
+///
+/// - 고정 공휴일 : 신정(1/1), 삼일절(3/1), 어린이날(5/5), 현충일(6/6), 광복절(8/15), 개천절(10/3),
+///   한글날(10/9), 성탄절(12/25). 연도와 관계없이 판별한다.
+/// - 음력 공휴일 : 설날·추석(각 전날, 당일, 다음날), 부처님오신날. [KOREAN_LUNAR_HOLIDAY_YEARS] 범위의
+///   연도만 판별하며, 범위 밖의 연도는 `None`을 반환한다.
+///
+/// 대체공휴일, 선거일 및 임시공휴일은 매년 별도로 지정되거나 다른 공휴일과의 관계로 결정되므로 판별하지
+/// 않는다. 고정 공휴일과 음력 공휴일이 겹치는 경우(e.g. 2025-05-05 어린이날과 부처님오신날) 고정 공휴일
+/// 명칭을 반환한다.
+///
+/// # Arguments
+///
+/// - `date` - 대상 날짜
+///
+/// # Return
+///
+/// - 공휴일 명칭 (e.g. `광복절`, `설날`). 공휴일이 아닐 경우 `None`
+///
+/// # Link
+///
+/// - [is_korean_holiday]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::get_korean_holiday_name;
+///
+/// assert_eq!(Some("한글날"), get_korean_holiday_name(&NaiveDate::from_ymd_opt(2024, 10, 9).unwrap()));
+/// assert_eq!(Some("추석"), get_korean_holiday_name(&NaiveDate::from_ymd_opt(2024, 9, 16).unwrap()));
+/// assert_eq!(None, get_korean_holiday_name(&NaiveDate::from_ymd_opt(2024, 11, 22).unwrap()));
+/// ```
+pub fn get_korean_holiday_name(date: &NaiveDate) -> Option<&'static str> {
+    let fixed = match (date.month(), date.day()) {
+        (1, 1) => Some("신정"),
+        (3, 1) => Some("삼일절"),
+        (5, 5) => Some("어린이날"),
+        (6, 6) => Some("현충일"),
+        (8, 15) => Some("광복절"),
+        (10, 3) => Some("개천절"),
+        (10, 9) => Some("한글날"),
+        (12, 25) => Some("성탄절"),
+        _ => None,
+    };
+
+    if fixed.is_some() {
+        return fixed;
+    }
+
+    let (_, seollal, buddha, chuseok) = KOREAN_LUNAR_HOLIDAYS
+        .iter()
+        .find(|(year, ..)| *year == date.year())?;
+    let to_date = |(month, day): MonthDay| NaiveDate::from_ymd_opt(date.year(), month, day);
+    // 당일 기준 전날 ~ 다음날
+    let within_three_days =
+        |v: MonthDay| to_date(v).is_some_and(|d| (*date - d).num_days().abs() <= 1);
+
+    if within_three_days(*seollal) {
+        Some("설날")
+    } else if within_three_days(*chuseok) {
+        Some("추석")
+    } else if to_date(*buddha) == Some(*date) {
+        Some("부처님오신날")
+    } else {
+        None
+    }
+}
+
+/// 날짜가 한국 공휴일인지 여부 반환
+///
+/// 판별 범위(대체공휴일 미포함, 음력 공휴일 지원 연도 등)는 [get_korean_holiday_name]과 같다.
+///
+/// # Arguments
+///
+/// - `date` - 대상 날짜
+///
+/// # Return
+///
+/// - 공휴일 여부
+///
+/// # Link
+///
+/// - [get_korean_holiday_name]
+/// - [KOREAN_LUNAR_HOLIDAY_YEARS]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::is_korean_holiday;
+///
+/// assert!(is_korean_holiday(&NaiveDate::from_ymd_opt(2024, 8, 15).unwrap()));
+/// assert!(is_korean_holiday(&NaiveDate::from_ymd_opt(2025, 1, 28).unwrap())); // 설날 전날
+/// assert!(!is_korean_holiday(&NaiveDate::from_ymd_opt(2024, 8, 16).unwrap()));
+/// ```
+pub fn is_korean_holiday(date: &NaiveDate) -> bool {
+    get_korean_holiday_name(date).is_some()
+}
+
+/// 현재 시각 제공자
+///
+/// "지금"에 의존하는 함수(e.g. [humanize_relative_with_clock])에 현재 시각을 주입하기 위한 trait이다. 실제 시각은
+/// [SystemClock], 테스트 등에서 고정된 시각이 필요하면 [FixedClock]을 사용한다.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{DateTime, TimeZone, Utc};
+/// use cliff3_util::date_util::{Clock, FixedClock, SystemClock};
+///
+/// fn elapsed_seconds(clock: &dyn Clock, from: &DateTime<Utc>) -> i64 {
+///     (clock.now_utc() - *from).num_seconds()
+/// }
+///
+/// let from = Utc.with_ymd_and_hms(2024, 11, 22, 0, 0, 0).unwrap();
+/// let clock = FixedClock::new(Utc.with_ymd_and_hms(2024, 11, 22, 0, 1, 30).unwrap());
+///
+/// assert_eq!(90, elapsed_seconds(&clock, &from));
+/// assert!(elapsed_seconds(&SystemClock, &from) > 0);
+/// ```
+pub trait Clock {
+    /// 현재 UTC 시각 반환
+    fn now_utc(&self) -> DateTime<Utc>;
+}
+
+/// 시스템 시각([Utc::now])을 반환하는 [Clock]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_utc(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// 항상 지정된 시각을 반환하는 [Clock]. 시간에 의존하는 함수를 결정적으로 테스트할 때 사용한다.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct FixedClock {
+    /// 반환할 시각
+    now: DateTime<Utc>,
+}
+
+impl FixedClock {
+    /// 지정된 시각을 반환하는 [FixedClock] 생성
+    pub fn new(now: DateTime<Utc>) -> Self {
+        FixedClock { now }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now_utc(&self) -> DateTime<Utc> {
+        self.now
+    }
+}
+
+/// 현재 시각 기준의 상대 시간 표현 반환
+///
+/// [SystemClock]을 사용하는 [humanize_relative_with_clock]의 편의 함수이다.
+///
+/// # Arguments
+///
+/// - `datetime` - 대상 시각
+///
+/// # Return
+///
+/// - 상대 시간 표현 (e.g. `3분 전`, `2일 후`)
+///
+/// # Link
+///
+/// - [humanize_relative_with_clock]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{Duration, Utc};
+/// use cliff3_util::date_util::humanize_relative;
+///
+/// assert_eq!("3시간 전", humanize_relative(&(Utc::now() - Duration::minutes(190))));
+/// ```
+pub fn humanize_relative(datetime: &DateTime<Utc>) -> String {
+    humanize_relative_with_clock(datetime, &SystemClock)
+}
+
+/// `clock`의 현재 시각 기준의 상대 시간 표현 반환
+///
+/// 현재 시각과의 차이를 다음 단위 중 가장 큰 단위로 내림하여 표현한다. 과거는 `전`, 미래는 `후`를 붙인다.
+///
+/// | 차이             | 결과                      |
+/// |-----------------|--------------------------|
+/// | 1분 미만          | `방금 전` / `잠시 후`       |
+/// | 1시간 미만        | `N분 전` / `N분 후`         |
+/// | 1일 미만          | `N시간 전` / `N시간 후`      |
+/// | 30일 미만         | `N일 전` / `N일 후`         |
+/// | 365일 미만        | `N개월 전` / `N개월 후`(30일 기준) |
+/// | 365일 이상        | `N년 전` / `N년 후`(365일 기준) |
+///
+/// # Arguments
+///
+/// - `datetime` - 대상 시각
+/// - `clock` - 현재 시각 제공자
+///
+/// # Return
+///
+/// - 상대 시간 표현
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::{humanize_relative_with_clock, FixedClock};
+///
+/// let clock = FixedClock::new(Utc.with_ymd_and_hms(2024, 11, 22, 12, 0, 0).unwrap());
+///
+/// assert_eq!("방금 전", humanize_relative_with_clock(&Utc.with_ymd_and_hms(2024, 11, 22, 11, 59, 30).unwrap(), &clock));
+/// assert_eq!("2일 전", humanize_relative_with_clock(&Utc.with_ymd_and_hms(2024, 11, 20, 0, 0, 0).unwrap(), &clock));
+/// assert_eq!("1시간 후", humanize_relative_with_clock(&Utc.with_ymd_and_hms(2024, 11, 22, 13, 30, 0).unwrap(), &clock));
+/// ```
+pub fn humanize_relative_with_clock(datetime: &DateTime<Utc>, clock: &dyn Clock) -> String {
+    let diff = clock.now_utc() - *datetime;
+    let suffix = if diff.num_seconds() >= 0 {
+        "전"
+    } else {
+        "후"
+    };
+    let seconds = diff.num_seconds().unsigned_abs();
+    let (value, unit) = match seconds {
+        0..60 => {
+            return if diff.num_seconds() >= 0 {
+                "방금 전".to_owned()
+            } else {
+                "잠시 후".to_owned()
+            }
+        }
+        60..3_600 => (seconds / 60, "분"),
+        3_600..86_400 => (seconds / 3_600, "시간"),
+        86_400..2_592_000 => (seconds / 86_400, "일"),
+        2_592_000..31_536_000 => (seconds / 2_592_000, "개월"),
+        _ => (seconds / 31_536_000, "년"),
+    };
+
+    format!("{}{} {}", value, unit, suffix)
+}
+
+/// 한글 상대 시간 표현을 기준 시각에 대한 실제 시각으로 변환
+///
+/// [humanize_relative_with_clock]의 역연산으로, 아래 표현을 인식한다. 앞뒤 공백과 숫자/단위/방향 사이의 공백은
+/// 무시한다.
+///
+/// | 표현                          | 결과                                  |
+/// |------------------------------|--------------------------------------|
+/// | `오늘`, `지금`, `방금 전`, `잠시 후` | `base`                              |
+/// | `어제` / `그제`                 | `base` - 1일 / 2일                   |
+/// | `내일` / `모레`                 | `base` + 1일 / 2일                   |
+/// | `N분 전`, `N분 후`              | `base` ∓ N분                        |
+/// | `N시간 전`, `N시간 후`           | `base` ∓ N시간                      |
+/// | `N일 전`, `N일 후`              | `base` ∓ N일                        |
+/// | `N개월 전`, `N개월 후`           | `base` ∓ N × 30일                   |
+/// | `N년 전`, `N년 후`              | `base` ∓ N × 365일                  |
+///
+/// `오늘`, `어제` 등은 날짜만 이동하며 시각은 `base`와 같다. `개월`과 `년`은 [humanize_relative_with_clock]과
+/// 같이 각각 30일, 365일로 계산하므로 달력상의 월/연 이동이 필요하면 [add_months]를 사용한다.
+///
+/// # Arguments
+///
+/// - `input` - 상대 시간 표현 (e.g. `3일 전`, `내일`)
+/// - `base` - 기준 시각
+///
+/// # Return
+///
+/// - 변환 결과 `Result<DateTime<Utc>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 인식할 수 없는 표현이거나 계산 결과가 표현 범위를 벗어난 경우
+///
+/// # Link
+///
+/// - [humanize_relative_with_clock]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::parse_relative_korean;
+///
+/// let base = Utc.with_ymd_and_hms(2024, 11, 22, 12, 0, 0).unwrap();
+///
+/// assert_eq!(Utc.with_ymd_and_hms(2024, 11, 21, 12, 0, 0).unwrap(), parse_relative_korean("어제", &base).unwrap());
+/// assert_eq!(Utc.with_ymd_and_hms(2024, 11, 19, 12, 0, 0).unwrap(), parse_relative_korean("3일 전", &base).unwrap());
+/// assert_eq!(Utc.with_ymd_and_hms(2024, 11, 22, 14, 0, 0).unwrap(), parse_relative_korean("2시간 후", &base).unwrap());
+/// assert!(parse_relative_korean("언젠가", &base).is_err());
+/// ```
+pub fn parse_relative_korean(
+    input: &str,
+    base: &DateTime<Utc>,
+) -> Result<DateTime<Utc>, InvalidArgumentError> {
+    let invalid = || {
+        InvalidArgumentError::new(
+            format!("인식할 수 없는 상대 시간 표현입니다 : {:?}", input).as_str(),
+        )
+    };
+    let normalized: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let minutes: i64 = match normalized.as_str() {
+        "오늘" | "지금" | "방금전" | "잠시후" => 0,
+        "어제" => -1_440,
+        "그제" => -2_880,
+        "내일" => 1_440,
+        "모레" => 2_880,
+        _ => {
+            let (rest, sign) = if let Some(v) = normalized.strip_suffix('전') {
+                (v, -1)
+            } else if let Some(v) = normalized.strip_suffix('후') {
+                (v, 1)
+            } else {
+                return Err(invalid());
+            };
+            let digits = rest.chars().take_while(char::is_ascii_digit).count();
+            let (value, unit) = rest.split_at(digits);
+            let value: i64 = value.parse().map_err(|_| invalid())?;
+            let unit_minutes: i64 = match unit {
+                "분" => 1,
+                "시간" => 60,
+                "일" => 1_440,
+                "개월" => 1_440 * 30,
+                "년" => 1_440 * 365,
+                _ => return Err(invalid()),
+            };
+
+            value
+                .checked_mul(unit_minutes)
+                .map(|v| v * sign)
+                .ok_or_else(invalid)?
+        }
+    };
+
+    chrono::Duration::try_minutes(minutes)
+        .and_then(|v| base.checked_add_signed(v))
+        .ok_or_else(invalid)
+}
+
+/// 생년월일 기준 만 나이 계산
+///
+/// 생일이 지나지 않았으면 1을 뺀다. 2월 29일 출생자는 평년에는 3월 1일에 나이가 증가한다.
+///
+/// # Arguments
+///
+/// - `birth` - 생년월일
+/// - `today` - 기준일
+///
+/// # Return
+///
+/// - 만 나이 `Result<u32, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `birth`가 `today`보다 이후일 경우
+///
+/// # Link
+///
+/// - [age_group]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::calculate_age;
+///
+/// let birth = NaiveDate::from_ymd_opt(1990, 6, 15).unwrap();
+///
+/// assert_eq!(33, calculate_age(&birth, &NaiveDate::from_ymd_opt(2024, 6, 14).unwrap()).unwrap());
+/// assert_eq!(34, calculate_age(&birth, &NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()).unwrap());
+/// ```
+pub fn calculate_age(birth: &NaiveDate, today: &NaiveDate) -> Result<u32, InvalidArgumentError> {
+    if birth > today {
+        return Err(InvalidArgumentError::new(
+            format!("생년월일이 기준일 이후입니다 : {} > {}", birth, today).as_str(),
+        ));
+    }
+
+    let mut age = today.year() - birth.year();
+
+    if (today.month(), today.day()) < (birth.month(), birth.day()) {
+        age -= 1;
+    }
+
+    Ok(age as u32)
+}
+
+/// 만 나이 기준 10년 단위 나이대 반환
+///
+/// [calculate_age]로 계산한 만 나이를 기준으로 다음과 같이 분류한다.
+///
+/// - 10세 미만 : `"10세 미만"`
+/// - 10 ~ 89세 : `"10대"` ~ `"80대"`
+/// - 90세 이상 : `"90세 이상"`
+///
+/// # Arguments
+///
+/// - `birth` - 생년월일
+/// - `today` - 기준일
+///
+/// # Return
+///
+/// - 나이대 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `birth`가 `today`보다 이후일 경우
+///
+/// # Link
+///
+/// - [calculate_age]
+/// - [generation]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::age_group;
+///
+/// let today = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+///
+/// assert_eq!("20대", age_group(&NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(), &today).unwrap());
+/// assert_eq!("10세 미만", age_group(&NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), &today).unwrap());
+/// ```
+pub fn age_group(birth: &NaiveDate, today: &NaiveDate) -> Result<String, InvalidArgumentError> {
+    let age = calculate_age(birth, today)?;
+
+    Ok(match age {
+        0..=9 => "10세 미만".to_owned(),
+        10..=89 => format!("{}대", age / 10 * 10),
+        _ => "90세 이상".to_owned(),
+    })
+}
+
+/// 출생연도 기준 세대 구분
+///
+/// # Link
+///
+/// - [generation]
+/// - [GENERATION_BIRTH_YEARS]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Generation {
+    /// 침묵 세대 (1928 ~ 1945)
+    Silent,
+
+    /// 베이비붐 세대 (1946 ~ 1964)
+    BabyBoomer,
+
+    /// X세대 (1965 ~ 1980)
+    GenX,
+
+    /// 밀레니얼(M)세대 (1981 ~ 1996)
+    Millennial,
+
+    /// Z세대 (1997 ~ 2012)
+    GenZ,
+
+    /// 알파세대 (2013 ~ 2024)
+    GenAlpha,
+}
+
+impl Generation {
+    /// 한글 명칭 반환 (e.g. `"X세대"`)
+    pub fn korean_name(&self) -> &'static str {
+        match self {
+            Generation::Silent => "침묵 세대",
+            Generation::BabyBoomer => "베이비붐 세대",
+            Generation::GenX => "X세대",
+            Generation::Millennial => "M세대",
+            Generation::GenZ => "Z세대",
+            Generation::GenAlpha => "알파세대",
+        }
+    }
+
+    /// MZ세대([Generation::Millennial], [Generation::GenZ]) 여부
+    pub fn is_mz(&self) -> bool {
+        matches!(self, Generation::Millennial | Generation::GenZ)
+    }
+}
+
+/// 세대별 출생연도 범위 `(세대, 시작 연도, 종료 연도)`. 범위는 Pew Research Center 기준이다.
+pub const GENERATION_BIRTH_YEARS: &[(Generation, i32, i32)] = &[
+    (Generation::Silent, 1928, 1945),
+    (Generation::BabyBoomer, 1946, 1964),
+    (Generation::GenX, 1965, 1980),
+    (Generation::Millennial, 1981, 1996),
+    (Generation::GenZ, 1997, 2012),
+    (Generation::GenAlpha, 2013, 2024),
+];
+
+/// 출생연도로 세대 분류
+///
+/// [GENERATION_BIRTH_YEARS] 테이블 기준으로 분류한다.
+///
+/// # Arguments
+///
+/// - `birth_year` - 출생연도
+///
+/// # Return
+///
+/// - 세대. 테이블 범위를 벗어나면 `None`
+///
+/// # Link
+///
+/// - [Generation]
+/// - [age_group]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::{generation, Generation};
+///
+/// assert_eq!(Some(Generation::Millennial), generation(1990));
+/// assert!(generation(2000).unwrap().is_mz());
+/// assert_eq!(None, generation(1900));
+/// ```
+pub fn generation(birth_year: i32) -> Option<Generation> {
+    GENERATION_BIRTH_YEARS
+        .iter()
+        .find(|(_, start, end)| (*start..=*end).contains(&birth_year))
+        .map(|(v, _, _)| *v)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::date_util::{
+        add_months, age_group, calculate_age, date_range, date_range_step, generation,
+        get_iso_week, get_korean_holiday_name, get_latest_day, get_quarter, get_week_start_end,
+        get_weekday_korean, humanize_relative, humanize_relative_with_clock, is_korean_holiday,
+        is_valid_datetime, local_datetime_to_utc, parse_relative_korean, parse_rfc3339,
+        parse_two_digit_year, timezone_offset_diff, to_rfc3339_string, to_rfc3339_with_tz,
+        utc_datetime_to_local, validate_pattern, Clock, DateStep, FixedClock, Generation,
+        SystemClock, KOREAN_LUNAR_HOLIDAY_YEARS,
+    };
+    use crate::error::LibError;
+    use chrono::{
+        DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+    };
+    use chrono_tz::Tz;
+
+    #[test]
+    fn local_datetime_to_utc_test() {
+        // KST 2024-11-22 09:54:45
+        // UTC 2024-11-22 00:54:45
+        let str_datetime = "20241122095445"; // 2024-11-22 09:54:45
+        let pattern = "%Y%m%d%H%M%S";
+        let timezone = Tz::Asia__Seoul;
+
+        let result = local_datetime_to_utc(str_datetime, pattern, &timezone);
+
+        assert!(
+            result.is_ok(),
+            "{}",
+            format!("변환 실패 : {:#?}", result.as_ref().unwrap_err())
+        );
+
+        let result = result.unwrap();
+
+        println!("utc result => {:#?}", result);
+
+        assert_eq!(2024, result.year());
+        assert_eq!(11, result.month());
+        assert_eq!(22, result.day());
+        assert_eq!(0, result.hour());
+        assert_eq!(54, result.minute());
+        assert_eq!(45, result.second());
+    }
+
+    #[test]
+    fn utc_datetime_to_local_test() {
+        // UTC 2024-11-22 22:54:45
+        // KST 2024-11-23 07:54:45
+        let utc_datetime = "20241122225445";
+        let patter = "%Y%m%d%H%M%S";
+        let timezone = Tz::Asia__Seoul;
+        let result = utc_datetime_to_local(utc_datetime, patter, &timezone);
+
+        assert!(
+            result.is_ok(),
+            "{}",
+            format!("변환 실패 : {:#?}", result.as_ref().unwrap_err())
+        );
+
+        let result = result.unwrap();
+
+        println!("local result => {:#?}", result);
+
+        assert_eq!(2024, result.year());
+        assert_eq!(11, result.month());
+        assert_eq!(23, result.day());
+        assert_eq!(7, result.hour());
+        assert_eq!(54, result.minute());
+        assert_eq!(45, result.second());
+    }
+
+    #[test]
+    fn get_latest_day_test() {
+        // leap month 2024
+        // normal     2025
+        let utc_leap_datetime: DateTime<Utc> =
+            Utc.with_ymd_and_hms(2024, 2, 11, 13, 27, 0).unwrap();
+        let latest_day = get_latest_day(&utc_leap_datetime);
+
+        assert_eq!(29, latest_day);
+
+        let utc_datetime = Utc.with_ymd_and_hms(2025, 2, 11, 13, 27, 0).unwrap();
+        let latest_day = get_latest_day(&utc_datetime);
 
         assert_eq!(28, latest_day);
     }
@@ -351,4 +1515,566 @@ mod tests {
         assert_eq!(6, sunday.month());
         assert_eq!(25, sunday.day());
     }
+
+    #[test]
+    fn is_valid_datetime_test() {
+        let pattern = "%Y-%m-%d %H:%M:%S";
+
+        assert!(is_valid_datetime("2024-11-22 10:29:48", pattern));
+        assert!(
+            is_valid_datetime("2024-02-29 00:00:00", pattern),
+            "윤년 2월 29일"
+        );
+        assert!(
+            !is_valid_datetime("2025-02-29 00:00:00", pattern),
+            "평년 2월 29일"
+        );
+        assert!(
+            !is_valid_datetime("2024-02-30 00:00:00", pattern),
+            "2월 30일"
+        );
+        assert!(!is_valid_datetime("2024-13-01 00:00:00", pattern), "13월");
+        assert!(!is_valid_datetime("2024-12-01 24:00:00", pattern), "24시");
+        assert!(
+            !is_valid_datetime("2024/12/01 00:00:00", pattern),
+            "패턴 불일치"
+        );
+        assert!(!is_valid_datetime("", pattern));
+
+        // 시간 정보가 없는 패턴
+        assert!(is_valid_datetime("20240229", "%Y%m%d"));
+        assert!(!is_valid_datetime("20250229", "%Y%m%d"));
+        assert!(
+            !is_valid_datetime("1900-02-29", "%Y-%m-%d"),
+            "100년 단위 평년"
+        );
+        assert!(
+            is_valid_datetime("2000-02-29", "%Y-%m-%d"),
+            "400년 단위 윤년"
+        );
+    }
+
+    #[test]
+    fn rfc3339_test() {
+        let dt = Utc.with_ymd_and_hms(2024, 11, 22, 1, 29, 48).unwrap();
+
+        assert_eq!("2024-11-22T01:29:48+00:00", to_rfc3339_string(&dt));
+        assert_eq!(
+            "2024-11-22T10:29:48+09:00",
+            to_rfc3339_with_tz(&dt, &Tz::Asia__Seoul)
+        );
+        assert_eq!(
+            "2024-11-21T20:29:48-05:00",
+            to_rfc3339_with_tz(&dt, &Tz::America__New_York)
+        );
+
+        // 왕복 변환
+        assert_eq!(dt, parse_rfc3339(&to_rfc3339_string(&dt)).unwrap());
+        assert_eq!(
+            dt,
+            parse_rfc3339(&to_rfc3339_with_tz(&dt, &Tz::Asia__Seoul)).unwrap()
+        );
+        assert_eq!(dt, parse_rfc3339("2024-11-22T01:29:48Z").unwrap());
+
+        assert!(parse_rfc3339("2024-11-22 10:29:48").is_err());
+        assert!(parse_rfc3339("").is_err());
+    }
+
+    #[test]
+    fn date_range_test() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // 윤년 2월 및 월 경계
+        let result: Vec<NaiveDate> = date_range(ymd(2024, 2, 27), ymd(2024, 3, 2)).collect();
+
+        assert_eq!(
+            vec![
+                ymd(2024, 2, 27),
+                ymd(2024, 2, 28),
+                ymd(2024, 2, 29),
+                ymd(2024, 3, 1),
+                ymd(2024, 3, 2)
+            ],
+            result
+        );
+        assert_eq!(
+            4,
+            date_range(ymd(2023, 2, 27), ymd(2023, 3, 2)).count(),
+            "평년"
+        );
+        assert_eq!(366, date_range(ymd(2024, 1, 1), ymd(2024, 12, 31)).count());
+        assert_eq!(1, date_range(ymd(2024, 5, 5), ymd(2024, 5, 5)).count());
+        assert_eq!(0, date_range(ymd(2024, 5, 6), ymd(2024, 5, 5)).count());
+
+        // 주 단위
+        let result: Vec<NaiveDate> =
+            date_range_step(ymd(2024, 2, 15), ymd(2024, 3, 14), DateStep::Week(1)).collect();
+
+        assert_eq!(
+            vec![
+                ymd(2024, 2, 15),
+                ymd(2024, 2, 22),
+                ymd(2024, 2, 29),
+                ymd(2024, 3, 7),
+                ymd(2024, 3, 14)
+            ],
+            result
+        );
+
+        // 월 단위. 말일 조정 후에도 시작일 기준으로 계산
+        let result: Vec<NaiveDate> =
+            date_range_step(ymd(2023, 11, 30), ymd(2024, 5, 1), DateStep::Month(2)).collect();
+
+        assert_eq!(
+            vec![ymd(2023, 11, 30), ymd(2024, 1, 30), ymd(2024, 3, 30)],
+            result
+        );
+
+        assert_eq!(
+            4,
+            date_range_step(ymd(2024, 1, 1), ymd(2024, 1, 10), DateStep::Day(3)).count()
+        );
+        assert_eq!(
+            0,
+            date_range_step(ymd(2024, 1, 1), ymd(2024, 1, 10), DateStep::Day(0)).count()
+        );
+    }
+
+    #[test]
+    fn add_months_test() {
+        let dt = |y, m, d| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(13, 27, 5)
+                .unwrap()
+        };
+
+        // 월말 clamp
+        assert_eq!(dt(2024, 2, 29), add_months(&dt(2024, 1, 31), 1).unwrap());
+        assert_eq!(dt(2023, 2, 28), add_months(&dt(2023, 1, 31), 1).unwrap());
+        assert_eq!(dt(2024, 4, 30), add_months(&dt(2024, 3, 31), 1).unwrap());
+        assert_eq!(dt(2024, 2, 29), add_months(&dt(2024, 3, 31), -1).unwrap());
+        assert_eq!(dt(2025, 2, 28), add_months(&dt(2024, 2, 29), 12).unwrap());
+
+        // 연도 경계
+        assert_eq!(dt(2025, 2, 15), add_months(&dt(2024, 11, 15), 3).unwrap());
+        assert_eq!(dt(2023, 11, 29), add_months(&dt(2024, 2, 29), -3).unwrap());
+        assert_eq!(dt(2014, 1, 1), add_months(&dt(2024, 1, 1), -120).unwrap());
+        assert_eq!(dt(2024, 1, 1), add_months(&dt(2024, 1, 1), 0).unwrap());
+
+        assert!(add_months(&dt(2024, 1, 1), i32::MAX).is_err());
+        assert!(add_months(&dt(2024, 1, 1), i32::MIN).is_err());
+    }
+
+    #[test]
+    pub fn validate_pattern_test() {
+        let valid = [
+            "%Y-%m-%d %H:%M:%S",
+            "%Y%m%d",
+            "%Y/%m/%d",
+            "%Y-%m-%dT%H:%M:%S%.f%:z",
+            "%Y년 %m월 %d일 %H시 %M분",
+            "%y.%m.%d",
+            "%d %b %Y %H:%M",
+            "%Y-%j",
+            "%H:%M:%S",
+            "%H:%M",
+            "%+",
+        ];
+
+        for pattern in valid {
+            assert!(
+                validate_pattern(pattern).is_ok(),
+                "valid pattern: {}",
+                pattern
+            );
+        }
+
+        let invalid = [
+            "",
+            "%Q",
+            "%Y-%m-%Q",
+            "%Y-%m-%d %",
+            "%Y-%m",
+            "%A",
+            "%H",
+            "plain text",
+        ];
+
+        for pattern in invalid {
+            let result = validate_pattern(pattern);
+
+            assert!(result.is_err(), "invalid pattern: {}", pattern);
+        }
+
+        assert!(validate_pattern("%Y-%m-%Q")
+            .unwrap_err()
+            .get_message()
+            .contains("%Q"));
+    }
+
+    #[test]
+    pub fn quarter_week_weekday_test() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // 분기
+        assert_eq!(1, get_quarter(&date(2024, 1, 1)));
+        assert_eq!(1, get_quarter(&date(2024, 3, 31)));
+        assert_eq!(2, get_quarter(&date(2024, 4, 1)));
+        assert_eq!(2, get_quarter(&date(2024, 6, 30)));
+        assert_eq!(3, get_quarter(&date(2024, 7, 1)));
+        assert_eq!(3, get_quarter(&date(2024, 9, 30)));
+        assert_eq!(4, get_quarter(&date(2024, 10, 1)));
+        assert_eq!(4, get_quarter(&date(2024, 12, 31)));
+
+        // ISO 주차
+        assert_eq!((2024, 1), get_iso_week(&date(2024, 1, 1))); // 월요일
+        assert_eq!((2024, 47), get_iso_week(&date(2024, 11, 22)));
+        assert_eq!((2024, 52), get_iso_week(&date(2024, 12, 29))); // 일요일
+        assert_eq!((2025, 1), get_iso_week(&date(2024, 12, 30))); // 다음 해 1주차로 넘어감
+        assert_eq!((2025, 1), get_iso_week(&date(2025, 1, 5)));
+        assert_eq!((2020, 53), get_iso_week(&date(2020, 12, 31))); // 53주가 있는 해
+        assert_eq!((2020, 53), get_iso_week(&date(2021, 1, 3))); // 이전 해 마지막 주에 속함
+        assert_eq!((2021, 1), get_iso_week(&date(2021, 1, 4)));
+        assert_eq!((2022, 52), get_iso_week(&date(2023, 1, 1)));
+
+        // 요일
+        let expected = ["월", "화", "수", "목", "금", "토", "일"];
+
+        for (i, v) in expected.iter().enumerate() {
+            // 2024-11-18 월요일
+            assert_eq!(*v, get_weekday_korean(&date(2024, 11, 18 + i as u32)));
+        }
+
+        assert_eq!("목", get_weekday_korean(&date(2024, 2, 29)));
+    }
+
+    #[test]
+    pub fn parse_two_digit_year_test() {
+        let tz = Tz::Asia__Seoul;
+        let year = |datetime: &str, pivot: i32| {
+            parse_two_digit_year(datetime, "%y-%m-%d", pivot, &tz)
+                .unwrap()
+                .year()
+        };
+
+        // pivot 경계 값
+        assert_eq!(1950, year("50-11-22", 50));
+        assert_eq!(2049, year("49-11-22", 50));
+        assert_eq!(2050, year("50-11-22", 51));
+        assert_eq!(1951, year("51-11-22", 51));
+
+        // chrono 기본 규칙(1969 ~ 2068)과 다른 해석
+        assert_eq!(2070, year("70-06-01", 80));
+        assert_eq!(1924, year("24-11-22", 0));
+        assert_eq!(2099, year("99-11-22", 100));
+        assert_eq!(2000, year("00-06-01", 1));
+        assert_eq!(1900, year("00-06-01", 0));
+
+        // 시간 포함 및 UTC 변환
+        let result = parse_two_digit_year("241122 102948", "%y%m%d %H%M%S", 50, &tz).unwrap();
+
+        assert_eq!(
+            Utc.with_ymd_and_hms(2024, 11, 22, 1, 29, 48).unwrap(),
+            result
+        );
+
+        // 날짜만 있는 경우 자정 기준
+        let result = parse_two_digit_year("24-11-22", "%y-%m-%d", 50, &tz).unwrap();
+
+        assert_eq!(
+            Utc.with_ymd_and_hms(2024, 11, 21, 15, 0, 0).unwrap(),
+            result
+        );
+
+        // 오류
+        assert!(parse_two_digit_year("00-02-29", "%y-%m-%d", 0, &tz)
+            .unwrap_err()
+            .get_message()
+            .contains("1900"));
+        assert_eq!(2000, year("00-02-29", 50));
+        assert!(parse_two_digit_year("2024-11-22", "%Y-%m-%d", 50, &tz).is_err());
+        assert!(parse_two_digit_year("24-13-22", "%y-%m-%d", 50, &tz).is_err());
+        assert!(parse_two_digit_year("24-11-22", "%y-%m-%d", -1, &tz).is_err());
+        assert!(parse_two_digit_year("24-11-22", "%y-%m-%d", 101, &tz).is_err());
+    }
+
+    #[test]
+    pub fn clock_test() {
+        let now = Utc.with_ymd_and_hms(2024, 11, 22, 12, 0, 0).unwrap();
+        let clock = FixedClock::new(now);
+
+        assert_eq!(now, clock.now_utc());
+        assert_eq!(now, clock.now_utc(), "항상 같은 시각");
+
+        let before = Utc::now();
+        let system = SystemClock.now_utc();
+
+        assert!(system >= before && system <= Utc::now());
+
+        let cases = [
+            (chrono::Duration::zero(), "방금 전"),
+            (chrono::Duration::seconds(59), "방금 전"),
+            (chrono::Duration::seconds(60), "1분 전"),
+            (chrono::Duration::minutes(59), "59분 전"),
+            (chrono::Duration::minutes(60), "1시간 전"),
+            (chrono::Duration::minutes(190), "3시간 전"),
+            (chrono::Duration::hours(24), "1일 전"),
+            (chrono::Duration::days(29), "29일 전"),
+            (chrono::Duration::days(30), "1개월 전"),
+            (chrono::Duration::days(364), "12개월 전"),
+            (chrono::Duration::days(365), "1년 전"),
+            (chrono::Duration::days(800), "2년 전"),
+            (chrono::Duration::seconds(-1), "잠시 후"),
+            (chrono::Duration::seconds(-59), "잠시 후"),
+            (chrono::Duration::seconds(-60), "1분 후"),
+            (chrono::Duration::hours(-5), "5시간 후"),
+            (chrono::Duration::days(-3), "3일 후"),
+            (chrono::Duration::days(-400), "1년 후"),
+        ];
+
+        for (elapsed, expected) in cases {
+            assert_eq!(
+                expected,
+                humanize_relative_with_clock(&(now - elapsed), &clock),
+                "{:?}",
+                elapsed
+            );
+        }
+
+        // 기본 경로는 SystemClock 사용
+        assert_eq!(
+            "2일 전",
+            humanize_relative(&(Utc::now() - chrono::Duration::hours(50)))
+        );
+
+        // trait 객체로 전달
+        let clocks: Vec<Box<dyn Clock>> = vec![Box::new(clock), Box::new(SystemClock)];
+
+        assert_eq!(now, clocks[0].now_utc());
+        assert!(clocks[1].now_utc() > now);
+    }
+
+    #[test]
+    fn korean_holiday_test() {
+        let date = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // 고정 공휴일은 연도와 관계없이 판별
+        for year in [1999, 2024, 2050] {
+            for (m, d, name) in [
+                (1, 1, "신정"),
+                (3, 1, "삼일절"),
+                (5, 5, "어린이날"),
+                (6, 6, "현충일"),
+                (8, 15, "광복절"),
+                (10, 3, "개천절"),
+                (10, 9, "한글날"),
+                (12, 25, "성탄절"),
+            ] {
+                assert!(is_korean_holiday(&date(year, m, d)));
+                assert_eq!(Some(name), get_korean_holiday_name(&date(year, m, d)));
+            }
+        }
+
+        for (m, d) in [(1, 2), (3, 2), (5, 4), (7, 17), (8, 14), (10, 10), (12, 24)] {
+            assert!(!is_korean_holiday(&date(2024, m, d)));
+        }
+
+        // 음력 공휴일 (2024년 설날 2/9 ~ 2/11, 부처님오신날 5/15, 추석 9/16 ~ 9/18)
+        for d in 9..=11 {
+            assert_eq!(Some("설날"), get_korean_holiday_name(&date(2024, 2, d)));
+        }
+
+        for d in 16..=18 {
+            assert_eq!(Some("추석"), get_korean_holiday_name(&date(2024, 9, d)));
+        }
+
+        assert_eq!(
+            Some("부처님오신날"),
+            get_korean_holiday_name(&date(2024, 5, 15))
+        );
+        assert!(!is_korean_holiday(&date(2024, 2, 8)));
+        assert!(!is_korean_holiday(&date(2024, 2, 12))); // 대체공휴일은 미포함
+        assert!(!is_korean_holiday(&date(2024, 9, 15)));
+
+        // 연도 경계를 넘지 않는 설날 연휴 (2022년 1/31 ~ 2/2)
+        assert!(is_korean_holiday(&date(2022, 1, 31)));
+        assert!(is_korean_holiday(&date(2022, 2, 2)));
+
+        // 고정 공휴일과 겹치는 경우 고정 공휴일 명칭
+        assert_eq!(Some("어린이날"), get_korean_holiday_name(&date(2025, 5, 5)));
+        assert_eq!(Some("개천절"), get_korean_holiday_name(&date(2028, 10, 3)));
+        assert_eq!(Some("추석"), get_korean_holiday_name(&date(2028, 10, 2)));
+
+        // 지원 범위 밖의 연도는 음력 공휴일을 판별하지 않음
+        assert!(!KOREAN_LUNAR_HOLIDAY_YEARS.contains(&2019));
+        assert!(!is_korean_holiday(&date(2019, 2, 5)));
+
+        for year in KOREAN_LUNAR_HOLIDAY_YEARS {
+            let count = date_range(date(year, 1, 1), date(year, 12, 31))
+                .filter(is_korean_holiday)
+                .count();
+
+            // 고정 8일 + 설날 3일 + 추석 3일 + 부처님오신날 1일 - 겹치는 날
+            assert!((13..=15).contains(&count), "{} : {}", year, count);
+        }
+    }
+
+    #[test]
+    fn timezone_offset_diff_test() {
+        let seoul = Tz::Asia__Seoul;
+        let new_york = Tz::America__New_York;
+        let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap(); // EST(UTC-5)
+        let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap(); // EDT(UTC-4)
+
+        assert_eq!(14 * 60, timezone_offset_diff(&seoul, &new_york, &winter));
+        assert_eq!(13 * 60, timezone_offset_diff(&seoul, &new_york, &summer));
+
+        // 부호 규칙 : tz1 - tz2
+        assert_eq!(-14 * 60, timezone_offset_diff(&new_york, &seoul, &winter));
+
+        // DST 전환 직전/직후 (2024-03-10 02:00 EST => 03:00 EDT, UTC 07:00)
+        let before = Utc.with_ymd_and_hms(2024, 3, 10, 6, 59, 59).unwrap();
+        let after = Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap();
+
+        assert_eq!(14 * 60, timezone_offset_diff(&seoul, &new_york, &before));
+        assert_eq!(13 * 60, timezone_offset_diff(&seoul, &new_york, &after));
+
+        // 분 단위 오프셋 및 같은 timezone
+        assert_eq!(
+            -30,
+            timezone_offset_diff(&seoul, &Tz::Australia__Darwin, &winter)
+        );
+        assert_eq!(
+            -(3 * 60 + 15),
+            timezone_offset_diff(&Tz::Asia__Kathmandu, &seoul, &winter)
+        );
+        assert_eq!(0, timezone_offset_diff(&seoul, &seoul, &summer));
+        assert_eq!(9 * 60, timezone_offset_diff(&seoul, &Tz::UTC, &summer));
+    }
+
+    #[test]
+    fn parse_relative_korean_test() {
+        let base = Utc.with_ymd_and_hms(2024, 11, 22, 12, 30, 0).unwrap();
+        let at = |y: i32, mo: u32, d: u32, h: u32, mi: u32| {
+            Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+        };
+
+        for (input, expected) in [
+            ("오늘", base),
+            ("방금 전", base),
+            ("어제", at(2024, 11, 21, 12, 30)),
+            ("그제", at(2024, 11, 20, 12, 30)),
+            ("내일", at(2024, 11, 23, 12, 30)),
+            ("모레", at(2024, 11, 24, 12, 30)),
+            ("3일 전", at(2024, 11, 19, 12, 30)),
+            ("10일 후", at(2024, 12, 2, 12, 30)),
+            ("2시간 전", at(2024, 11, 22, 10, 30)),
+            ("36시간 후", at(2024, 11, 24, 0, 30)),
+            ("45분 전", at(2024, 11, 22, 11, 45)),
+            ("0분 후", base),
+            ("1개월 전", at(2024, 10, 23, 12, 30)),
+            ("1년 후", at(2025, 11, 22, 12, 30)),
+            ("  5 일  전 ", at(2024, 11, 17, 12, 30)),
+            ("5일전", at(2024, 11, 17, 12, 30)),
+        ] {
+            assert_eq!(
+                expected,
+                parse_relative_korean(input, &base).unwrap(),
+                "{}",
+                input
+            );
+        }
+
+        // humanize_relative_with_clock의 역연산
+        let clock = FixedClock::new(base);
+
+        for input in ["3일 전", "2시간 후", "45분 전", "5개월 후", "2년 전"] {
+            let parsed = parse_relative_korean(input, &base).unwrap();
+
+            assert_eq!(input, humanize_relative_with_clock(&parsed, &clock));
+        }
+
+        for input in [
+            "",
+            "언젠가",
+            "3일",
+            "전",
+            "일 전",
+            "-3일 전",
+            "3주 전",
+            "3.5일 전",
+            "99999999999999년 전",
+        ] {
+            let result = parse_relative_korean(input, &base);
+
+            assert!(result.is_err(), "{}", input);
+            assert!(result.unwrap_err().get_message().contains("상대 시간"));
+        }
+    }
+
+    #[test]
+    fn age_group_test() {
+        let ymd = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let today = ymd(2024, 6, 15);
+
+        // 만 나이
+        assert_eq!(0, calculate_age(&today, &today).unwrap());
+        assert_eq!(33, calculate_age(&ymd(1990, 6, 16), &today).unwrap());
+        assert_eq!(34, calculate_age(&ymd(1990, 6, 15), &today).unwrap());
+        assert_eq!(34, calculate_age(&ymd(1990, 1, 1), &today).unwrap());
+        // 2월 29일 출생자는 평년 3월 1일에 나이 증가
+        assert_eq!(
+            0,
+            calculate_age(&ymd(2020, 2, 29), &ymd(2021, 2, 28)).unwrap()
+        );
+        assert_eq!(
+            1,
+            calculate_age(&ymd(2020, 2, 29), &ymd(2021, 3, 1)).unwrap()
+        );
+
+        // 경계 나이별 나이대 (기준일 당일이 생일)
+        let group = |age: i32| age_group(&ymd(2024 - age, 6, 15), &today).unwrap();
+
+        assert_eq!("10세 미만", group(0));
+        assert_eq!("10세 미만", group(9));
+        assert_eq!("10대", group(10));
+        assert_eq!("10대", group(19));
+        assert_eq!("20대", group(20));
+        assert_eq!("20대", group(29));
+        assert_eq!("30대", group(30));
+        assert_eq!("80대", group(89));
+        assert_eq!("90세 이상", group(90));
+        assert_eq!("90세 이상", group(105));
+
+        // 생일 전날은 이전 나이대
+        assert_eq!("10대", age_group(&ymd(2004, 6, 16), &today).unwrap());
+
+        // 미래 생년월일
+        let result = age_group(&ymd(2024, 6, 16), &today);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().get_message().contains("2024-06-16"));
+
+        // 세대
+        assert_eq!(None, generation(1927));
+        assert_eq!(Some(Generation::Silent), generation(1928));
+        assert_eq!(Some(Generation::BabyBoomer), generation(1946));
+        assert_eq!(Some(Generation::BabyBoomer), generation(1964));
+        assert_eq!(Some(Generation::GenX), generation(1965));
+        assert_eq!(Some(Generation::GenX), generation(1980));
+        assert_eq!(Some(Generation::Millennial), generation(1981));
+        assert_eq!(Some(Generation::Millennial), generation(1996));
+        assert_eq!(Some(Generation::GenZ), generation(1997));
+        assert_eq!(Some(Generation::GenZ), generation(2012));
+        assert_eq!(Some(Generation::GenAlpha), generation(2013));
+        assert_eq!(Some(Generation::GenAlpha), generation(2024));
+        assert_eq!(None, generation(2025));
+
+        assert!(generation(1981).unwrap().is_mz());
+        assert!(generation(2012).unwrap().is_mz());
+        assert!(!generation(1980).unwrap().is_mz());
+        assert!(!generation(2013).unwrap().is_mz());
+        assert_eq!("X세대", Generation::GenX.korean_name());
+    }
 }
@@ -60,23 +60,64 @@ impl Display for MissingArgumentError {
 
 // InvalidArgumentError ----------------------------------------------------------------------------
 /// 잘못된 인자에 대한 오류
+///
+/// 파일 시스템 작업 실패로 생성된 경우 원래 [std::io::Error]의 [std::io::ErrorKind]를 보존하며,
+/// [InvalidArgumentError::io_error_kind]로 확인할 수 있다.
 #[derive(PartialEq, Debug)]
 pub struct InvalidArgumentError {
     message: String,
+    io_kind: Option<std::io::ErrorKind>,
 }
 
 impl InvalidArgumentError {
     pub fn new(message: &str) -> Self {
         Self {
             message: message.to_owned(),
+            io_kind: None,
         }
     }
+
+    /// [std::io::Error]의 [std::io::ErrorKind]를 보존하면서 지정된 메시지로 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `message` - 오류 메시지
+    /// - `error` - 원인 [std::io::Error]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    /// use cliff3_util::error::{InvalidArgumentError, LibError};
+    ///
+    /// let error = Error::new(ErrorKind::PermissionDenied, "denied");
+    /// let result = InvalidArgumentError::from_io("생성 실패", &error);
+    ///
+    /// assert_eq!("생성 실패", result.get_message());
+    /// assert_eq!(Some(ErrorKind::PermissionDenied), result.io_error_kind());
+    /// ```
+    pub fn from_io(message: &str, error: &std::io::Error) -> Self {
+        Self {
+            message: message.to_owned(),
+            io_kind: Some(error.kind()),
+        }
+    }
+
+    /// 원인이 된 [std::io::Error]의 [std::io::ErrorKind] 반환
+    ///
+    /// # Return
+    ///
+    /// - 파일 시스템 작업 실패로 생성된 경우 `Some(kind)`, 그 외에는 `None`
+    pub fn io_error_kind(&self) -> Option<std::io::ErrorKind> {
+        self.io_kind
+    }
 }
 
 impl Default for InvalidArgumentError {
     fn default() -> Self {
         InvalidArgumentError {
             message: "유효하지 않은 인자 입니다.".to_owned(),
+            io_kind: None,
         }
     }
 }
@@ -101,6 +142,17 @@ impl From<&str> for InvalidArgumentError {
     fn from(value: &str) -> Self {
         InvalidArgumentError {
             message: value.to_owned(),
+            io_kind: None,
+        }
+    }
+}
+
+/// [std::io::Error]의 [std::io::ErrorKind]를 보존하며 변환. 메시지는 원래 오류의 `Debug` 표현이다.
+impl From<std::io::Error> for InvalidArgumentError {
+    fn from(value: std::io::Error) -> Self {
+        InvalidArgumentError {
+            message: format!("{:?}", value),
+            io_kind: Some(value.kind()),
         }
     }
 }
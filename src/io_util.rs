@@ -1,11 +1,26 @@
 //! I/O 관련 함수 모음
 
+#[cfg(any(feature = "encrypt", feature = "default"))]
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
 use std::ops::Not;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
 
 use chrono::{DateTime, Datelike, Local};
+use fs2::FileExt;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
 
-use crate::error::InvalidArgumentError;
+#[cfg(any(feature = "date", feature = "default"))]
+use crate::date_util::Clock;
+#[cfg(any(feature = "encrypt", feature = "default"))]
+use crate::encrypt_util::{ShaHasher, SHA_TYPE};
+use crate::error::{InvalidArgumentError, LibError};
+#[cfg(any(feature = "string", feature = "default"))]
+use crate::string_util::{detect_encoding, DetectedEncoding};
 
 /// Directory 생성시 날짜 규칙
 ///
@@ -16,7 +31,7 @@ use crate::error::InvalidArgumentError;
 /// # Link
 ///
 /// [generate_path]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum DirectoryDateType {
     /// yyyyMMdd 형태
     YYYYMMDD,
@@ -101,7 +116,7 @@ impl DirectoryDateType {
 ///
 /// # Errors
 ///
-/// - [InvalidArgumentError] 부모 경로가 존재하지 않을 경우 혹은 [std::fs::create_dir_all] 실패
+/// - [InvalidArgumentError] 부모 경로가 존재하지 않을 경우(`ErrorKind::NotFound`) 혹은 [std::fs::create_dir_all] 실패
 ///
 /// # Link
 ///
@@ -139,59 +154,2727 @@ pub fn generate_path(
     parent_path: &Path,
     date_type: DirectoryDateType,
     separator: Option<&str>,
+) -> Result<Box<Path>, InvalidArgumentError> {
+    create_date_path(parent_path, &date_type, separator, None, &Local::now())
+}
+
+/// 지정된 부모 경로 하위에 날짜 형태의 경로를 지정된 권한(mode)으로 생성
+///
+/// [generate_path]와 동일하지만 새로 생성되는 directory에 `mode`를 적용한다. 이미 존재하는 directory의
+/// 권한은 변경하지 않는다.
+///
+/// - Unix : [std::os::unix::fs::DirBuilderExt::mode]로 권한을 설정한다. 실제 권한은 프로세스의 umask가
+///   적용된 값(`mode & !umask`)이다.
+/// - Windows 등 Unix가 아닌 플랫폼 : **`mode`는 무시되며** [generate_path]와 동일하게 동작한다.
+///
+/// # Arguments
+///
+/// - `parent_path` - 생성하고자 하는 경로의 부모 directory
+/// - `date_type` - [DirectoryDateType]
+/// - `separator` - 날짜 정보 사이에 입력될 문자열 (e.g. **-**, **_**)
+/// - `mode` - 권한 (e.g. `0o700`)
+///
+/// # Return
+///
+/// - 생성 결과 `Result<Box<Path>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] 부모 경로가 존재하지 않을 경우(`ErrorKind::NotFound`) 혹은 [std::fs::DirBuilder::create] 실패
+///
+/// # Link
+///
+/// - [generate_path]
+/// - [std::fs::DirBuilder]
+pub fn generate_path_with_mode(
+    parent_path: &Path,
+    date_type: DirectoryDateType,
+    separator: Option<&str>,
+    mode: u32,
+) -> Result<Box<Path>, InvalidArgumentError> {
+    create_date_path(
+        parent_path,
+        &date_type,
+        separator,
+        Some(mode),
+        &Local::now(),
+    )
+}
+
+/// [generate_path], [generate_path_with_mode] 공통 처리. `now` 기준 날짜로 경로를 생성한다.
+fn create_date_path(
+    parent_path: &Path,
+    date_type: &DirectoryDateType,
+    separator: Option<&str>,
+    #[allow(unused_variables)] mode: Option<u32>,
+    now: &DateTime<Local>,
 ) -> Result<Box<Path>, InvalidArgumentError> {
     // check exist parent path
     if parent_path.exists().not() {
         let path_str = parent_path.as_os_str();
         let message = format!("[{:?}] 경로가 존재하지 않습니다.", path_str);
+        let not_found = std::io::Error::from(std::io::ErrorKind::NotFound);
 
-        return Err(InvalidArgumentError::new(message.as_str()));
+        return Err(InvalidArgumentError::from_io(message.as_str(), &not_found));
     }
 
-    let now = Local::now();
-    let dir_string = date_type.generate_path_string(&now, separator);
+    let dir_string = date_type.generate_path_string(now, separator);
     let result = PathBuf::from(parent_path).join(dir_string);
 
     if !&result.exists() {
-        let created_result = std::fs::create_dir_all(&result);
+        let mut builder = std::fs::DirBuilder::new();
+
+        builder.recursive(true);
+
+        #[cfg(unix)]
+        if let Some(v) = mode {
+            use std::os::unix::fs::DirBuilderExt;
 
-        if created_result.is_err() {
-            let err = created_result.err();
-            // TODO(joonho): 2024-06-24 create_dir_all에서 반환되는 에러 확인
-            return Err(InvalidArgumentError::new(
-                format!("{:?}", err.unwrap()).as_str(),
-            ));
+            builder.mode(v);
         }
+
+        builder.create(&result)?;
     }
 
     return Ok(result.into_boxed_path());
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::io_util::{generate_path, DirectoryDateType};
-    use std::path::Path;
+/// 날짜가 바뀌면 새 날짜 directory의 파일로 전환하는 writer
+///
+/// 쓰기 시점의 날짜로 `{base_path}/{날짜}/{file_name}` 경로([generate_path]와 같은 규칙, 구분자 없음)의 파일을
+/// 추가(append) 모드로 열어 기록하며, 이전 쓰기와 날짜 directory가 달라지면 현재 파일을 닫고 새 파일로 전환한다.
+/// directory 감시나 별도 thread 없이 쓰기 시점에만 날짜를 확인한다.
+///
+/// [Write]를 구현하므로 [std::io::BufWriter], `writeln!` 등과 함께 사용할 수 있다.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::Write;
+/// use cliff3_util::io_util::{DirectoryDateType, RollingFileWriter};
+///
+/// let base = std::env::temp_dir().join("rolling_file_writer_doc");
+///
+/// std::fs::create_dir_all(&base).unwrap();
+///
+/// let mut writer = RollingFileWriter::new(&base, DirectoryDateType::YYYYMMDD, "app.log");
+///
+/// writeln!(writer, "started").unwrap();
+/// writer.flush().unwrap();
+///
+/// let path = writer.current_path().unwrap().to_path_buf();
+///
+/// assert!(std::fs::read_to_string(&path).unwrap().ends_with("started\n"));
+///
+/// std::fs::remove_dir_all(&base).unwrap();
+/// ```
+pub struct RollingFileWriter {
+    /// 날짜 directory를 생성할 부모 directory
+    base_path: PathBuf,
 
-    #[test]
-    fn generate_path_test() {
-        let now = chrono::Local::now();
-        let compare_dir_name = DirectoryDateType::YYYYMMDD.generate_path_string(&now, Some("_"));
-        let current_path = Path::new(env!("CARGO_MANIFEST_DIR"));
-        let result = generate_path(current_path, DirectoryDateType::YYYYMMDD, Some("_"));
+    /// 날짜 directory 규칙
+    date_type: DirectoryDateType,
 
-        // yyyy_mm_dd
-        assert!(result.is_ok());
+    /// 날짜 directory 하위의 파일명
+    file_name: String,
 
-        let created_dir = result.unwrap();
+    /// 현재 시각 제공자
+    now: Box<dyn Fn() -> DateTime<Local> + Send>,
 
-        assert!(created_dir.exists());
+    /// 현재 열려 있는 파일의 (날짜 directory 문자열, 경로, 파일)
+    current: Option<(String, PathBuf, File)>,
+}
 
-        let dir_name = created_dir.file_name().unwrap();
-        assert_eq!(dir_name.to_str().unwrap(), compare_dir_name);
+impl RollingFileWriter {
+    /// 시스템 시각([Local::now]) 기준으로 전환하는 writer 생성
+    ///
+    /// 파일은 첫 쓰기 시점에 생성된다. `base_path`는 쓰기 시점에 존재해야 한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `base_path` - 날짜 directory를 생성할 부모 directory
+    /// - `date_type` - [DirectoryDateType]
+    /// - `file_name` - 날짜 directory 하위의 파일명 (e.g. `app.log`)
+    pub fn new(base_path: &Path, date_type: DirectoryDateType, file_name: &str) -> Self {
+        Self::with_time_source(base_path, date_type, file_name, Box::new(Local::now))
+    }
 
-        // 생성된 테스트 경로 삭제
-        let deleted_dir = std::fs::remove_dir(created_dir);
+    /// 주입된 [Clock] 기준으로 전환하는 writer 생성
+    ///
+    /// 날짜 directory는 `clock`의 시각을 지역 시간대([Local])로 변환하여 결정한다. 테스트에서 날짜 경계를
+    /// 재현할 때 사용한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `base_path` - 날짜 directory를 생성할 부모 directory
+    /// - `date_type` - [DirectoryDateType]
+    /// - `file_name` - 날짜 directory 하위의 파일명 (e.g. `app.log`)
+    /// - `clock` - 현재 시각 제공자
+    #[cfg(any(feature = "date", feature = "default"))]
+    pub fn with_clock(
+        base_path: &Path,
+        date_type: DirectoryDateType,
+        file_name: &str,
+        clock: Box<dyn Clock + Send>,
+    ) -> Self {
+        Self::with_time_source(
+            base_path,
+            date_type,
+            file_name,
+            Box::new(move || clock.now_utc().with_timezone(&Local)),
+        )
+    }
 
-        assert!(deleted_dir.is_ok());
+    fn with_time_source(
+        base_path: &Path,
+        date_type: DirectoryDateType,
+        file_name: &str,
+        now: Box<dyn Fn() -> DateTime<Local> + Send>,
+    ) -> Self {
+        RollingFileWriter {
+            base_path: base_path.to_path_buf(),
+            date_type,
+            file_name: file_name.to_owned(),
+            now,
+            current: None,
+        }
+    }
+
+    /// 현재 기록 중인 파일 경로 반환. 아직 기록하지 않았으면 `None`
+    pub fn current_path(&self) -> Option<&Path> {
+        self.current.as_ref().map(|(_, path, _)| path.as_path())
+    }
+
+    /// 현재 날짜의 파일 반환. 날짜 directory가 바뀌었으면 새 파일로 전환한다.
+    fn current_file(&mut self) -> std::io::Result<&mut File> {
+        let now = (self.now)();
+        let dir_string = self.date_type.generate_path_string(&now, None);
+        let rolled = match &self.current {
+            Some((v, _, _)) => *v != dir_string,
+            None => true,
+        };
+
+        if rolled {
+            if let Some((_, _, mut file)) = self.current.take() {
+                file.flush()?;
+            }
+
+            let dir = create_date_path(&self.base_path, &self.date_type, None, None, &now)
+                .map_err(|e| {
+                    let kind = e.io_error_kind().unwrap_or(std::io::ErrorKind::Other);
+
+                    std::io::Error::new(kind, e.get_message().to_owned())
+                })?;
+            let path = dir.join(&self.file_name);
+            let file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)?;
+
+            self.current = Some((dir_string, path, file));
+        }
+
+        Ok(&mut self.current.as_mut().unwrap().2)
+    }
+}
+
+impl Write for RollingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.current_file()?.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self.current.as_mut() {
+            Some((_, _, file)) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 파일 시그니처(magic bytes)와 MIME type 목록
+const CONTENT_SIGNATURES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xFF\xD8\xFF", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"BM", "image/bmp"),
+    (b"%PDF-", "application/pdf"),
+    (b"PK\x03\x04", "application/zip"),
+    (b"PK\x05\x06", "application/zip"), // 빈 zip 파일
+    (b"\x1F\x8B", "application/gzip"),
+];
+
+/// 주어진 경로의 확장자를 소문자로 반환
+///
+/// 확장자가 없거나 UTF-8 문자열로 변환할 수 없는 경우 `None`을 반환한다.
+///
+/// # Arguments
+///
+/// - `path` - 대상 경로
+///
+/// # Return
+///
+/// - 소문자로 정규화된 확장자 (e.g. `png`)
+///
+/// # Example
+///
+/// ```rust
+/// use std::path::Path;
+/// use cliff3_util::io_util::get_extension;
+///
+/// assert_eq!(Some("png".to_owned()), get_extension(Path::new("/upload/IMAGE.PNG")));
+/// assert_eq!(None, get_extension(Path::new("/upload/README")));
+/// ```
+pub fn get_extension(path: &Path) -> Option<String> {
+    path.extension()
+        .and_then(|v| v.to_str())
+        .map(|v| v.to_lowercase())
+}
+
+/// 파일 앞부분의 시그니처(magic bytes)를 이용하여 MIME type을 추론
+///
+/// PNG, JPEG, GIF, BMP, PDF, ZIP, GZIP 등 주요 형식만 판별하며, 판별할 수 없는 경우 `None`을 반환한다.
+///
+/// # Arguments
+///
+/// - `bytes` - 파일 내용(앞부분 일부만 전달하여도 무방)
+///
+/// # Return
+///
+/// - 추론된 MIME type (e.g. `image/png`)
+///
+/// # Example
+///
+/// 업로드된 파일의 확장자와 실제 내용이 일치하는지 검사
+///
+/// ```rust
+/// use std::path::Path;
+/// use cliff3_util::io_util::{get_extension, sniff_content_type};
+///
+/// let path = Path::new("report.pdf");
+/// let content = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR";
+///
+/// let expected = match get_extension(path).as_deref() {
+///     Some("png") => Some("image/png"),
+///     Some("jpg") | Some("jpeg") => Some("image/jpeg"),
+///     Some("pdf") => Some("application/pdf"),
+///     _ => None,
+/// };
+///
+/// // 확장자는 pdf이지만 실제 내용은 png
+/// assert_ne!(expected, sniff_content_type(content));
+/// ```
+pub fn sniff_content_type(bytes: &[u8]) -> Option<&'static str> {
+    CONTENT_SIGNATURES
+        .iter()
+        .find(|(signature, _)| bytes.starts_with(signature))
+        .map(|(_, mime)| *mime)
+}
+
+/// 파일의 문자 인코딩을 자동 판별하여 UTF-8 문자열로 읽기
+///
+/// [detect_encoding]으로 UTF-8, UTF-16 LE/BE, EUC-KR 중 하나를 판별한 후
+/// 변환한다. BOM은 결과에 포함되지 않는다. `string` feature가 필요하다.
+///
+/// # Arguments
+///
+/// - `path` - 대상 파일 경로
+///
+/// # Return
+///
+/// - (변환 결과, 판별된 인코딩) `Result<(String, DetectedEncoding), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 파일 읽기 실패, 인코딩 판별 불가 혹은 변환 실패
+///
+/// # Link
+///
+/// - [DetectedEncoding::decode]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::io_util::read_file_auto_encoding;
+/// use cliff3_util::string_util::DetectedEncoding;
+///
+/// let path = std::env::temp_dir().join("read_file_auto_encoding_doc.txt");
+///
+/// std::fs::write(&path, [0xC7, 0xD1, 0xB1, 0xDB]).unwrap();
+///
+/// let (content, encoding) = read_file_auto_encoding(&path).unwrap();
+///
+/// assert_eq!("한글", content);
+/// assert_eq!(DetectedEncoding::EucKr, encoding);
+///
+/// std::fs::remove_file(&path).unwrap();
+/// ```
+#[cfg(any(feature = "string", feature = "default"))]
+pub fn read_file_auto_encoding(
+    path: &Path,
+) -> Result<(String, DetectedEncoding), InvalidArgumentError> {
+    let bytes = std::fs::read(path).map_err(|e| {
+        let message = format!("[{:?}] 파일 읽기 오류 : {:?}", path.as_os_str(), e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    })?;
+    let encoding = detect_encoding(&bytes);
+
+    Ok((encoding.decode(&bytes)?, encoding))
+}
+
+/// 지정된 directory 내에서 수정 시각이 가장 최근인 파일 반환
+///
+/// 하위 directory는 무시하며 directory 바로 아래의 파일만 대상으로 한다.
+///
+/// # Arguments
+///
+/// - `dir` - 검색 대상 directory
+///
+/// # Return
+///
+/// - 검색 결과 `Result<Option<PathBuf>, InvalidArgumentError>`. 파일이 없을 경우 `Ok(None)`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `dir`이 directory가 아니거나 목록/메타데이터 조회 실패
+///
+/// # Link
+///
+/// - [std::fs::read_dir]
+/// - [std::fs::Metadata::modified]
+pub fn find_latest_file(dir: &Path) -> Result<Option<PathBuf>, InvalidArgumentError> {
+    if !dir.is_dir() {
+        let message = format!("[{:?}] directory가 아닙니다.", dir.as_os_str());
+
+        return Err(InvalidArgumentError::new(message.as_str()));
+    }
+
+    let entries = std::fs::read_dir(dir).map_err(InvalidArgumentError::from)?;
+    let mut latest: Option<(SystemTime, PathBuf)> = None;
+
+    for entry in entries {
+        let entry = entry.map_err(InvalidArgumentError::from)?;
+        let metadata = entry.metadata().map_err(InvalidArgumentError::from)?;
+
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let modified = metadata.modified().map_err(InvalidArgumentError::from)?;
+
+        if latest.as_ref().is_none_or(|(t, _)| modified > *t) {
+            latest = Some((modified, entry.path()));
+        }
+    }
+
+    Ok(latest.map(|(_, path)| path))
+}
+
+/// [append_with_lock]의 잠금 획득 대기 최대 시간
+pub const APPEND_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 파일 배타 잠금을 획득한 후 내용을 추가(append)
+///
+/// 여러 프로세스가 같은 파일에 동시에 쓰는 경우 내용이 섞이지 않도록 [fs2::FileExt::try_lock_exclusive]로
+/// 배타 잠금을 획득한 후 `content`를 기록하고 잠금을 해제한다. 파일이 없을 경우 생성한다.
+/// 잠금은 [APPEND_LOCK_TIMEOUT] 동안 재시도하며, 그 안에 획득하지 못하면 실패로 처리한다.
+///
+/// 플랫폼별 동작 차이는 다음과 같다.
+///
+/// - Unix : `flock(2)` 기반의 권고(advisory) 잠금이다. 잠금을 사용하지 않는 다른 프로세스의 쓰기는
+///   막지 못하며, 일부 네트워크 파일 시스템(NFS 등)에서는 동작이 보장되지 않는다.
+/// - Windows : `LockFileEx` 기반의 강제(mandatory) 잠금이다. 잠금이 유지되는 동안 다른 프로세스의
+///   읽기/쓰기가 실패할 수 있다.
+///
+/// # Arguments
+///
+/// - `path` - 대상 파일 경로
+/// - `content` - 추가할 내용
+///
+/// # Return
+///
+/// - 처리 결과 `Result<(), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 파일 열기 실패, 잠금 획득 실패 혹은 쓰기 실패. 잠금 획득 시간 초과의 경우
+///   [InvalidArgumentError::io_error_kind]가 `Some(ErrorKind::TimedOut)`이며, 그 외에는 원인이 된
+///   [std::io::ErrorKind]를 반환한다.
+///
+/// # Link
+///
+/// - [fs2::FileExt]
+pub fn append_with_lock(path: &Path, content: &[u8]) -> Result<(), InvalidArgumentError> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(InvalidArgumentError::from)?;
+    let started = Instant::now();
+
+    while let Err(e) = file.try_lock_exclusive() {
+        if started.elapsed() >= APPEND_LOCK_TIMEOUT {
+            let message = format!("잠금 획득 시간 초과 [{:?}] : {:?}", path.as_os_str(), e);
+            let timed_out = std::io::Error::new(std::io::ErrorKind::TimedOut, e);
+
+            return Err(InvalidArgumentError::from_io(message.as_str(), &timed_out));
+        }
+
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let written = file.write_all(content).and_then(|_| file.flush());
+    // 잠금 해제 실패 시에도 파일이 닫히면서 잠금은 해제된다.
+    let _ = FileExt::unlock(&file);
+
+    written.map_err(|e| {
+        let message = format!("파일 쓰기 실패 [{:?}] : {:?}", path.as_os_str(), e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    })
+}
+
+/// 사용자가 입력한 상대 경로를 `base` directory에 결합하고 `base` 하위 경로인지 검증
+///
+/// `../` 등으로 `base` 상위 경로로 벗어나는 것을 방지하기 위해 다음 순서로 검증한다.
+///
+/// 1. `user_path`가 절대 경로이거나 prefix(Windows의 `C:` 등)를 포함할 경우 거부
+/// 2. `.`, `..`을 정리한 경로가 `base` 상위로 벗어날 경우 거부
+/// 3. 결합한 경로의 구성 요소를 [std::fs::symlink_metadata]로 하나씩 확인하며 심볼릭 링크를 링크 대상으로
+///    해석한 후 `base` 하위인지 확인. 대상이 존재하지 않는(dangling) 심볼릭 링크도 링크 대상 기준으로 검증하므로
+///    이후 쓰기 시 `base` 밖에 파일이 생성되는 것을 방지한다.
+///
+/// 결합한 경로가 아직 존재하지 않아도 되며, 반환되는 경로는 심볼릭 링크가 해석된 경로이다.
+///
+/// # Arguments
+///
+/// - `base` - 기준 directory (존재해야 함)
+/// - `user_path` - 사용자 입력 상대 경로
+///
+/// # Return
+///
+/// - 검증된 경로 `Result<PathBuf, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `base`가 directory가 아니거나 결합한 경로가 `base`를 벗어날 경우
+///
+/// # Link
+///
+/// - [std::fs::canonicalize]
+pub fn resolve_safe_path(base: &Path, user_path: &str) -> Result<PathBuf, InvalidArgumentError> {
+    let escaped = || {
+        let message = format!("[{}] 경로가 기준 directory를 벗어납니다.", user_path);
+
+        InvalidArgumentError::new(message.as_str())
+    };
+
+    if !base.is_dir() {
+        let message = format!("[{:?}] directory가 아닙니다.", base.as_os_str());
+
+        return Err(InvalidArgumentError::new(message.as_str()));
+    }
+
+    let base = std::fs::canonicalize(base).map_err(InvalidArgumentError::from)?;
+    let mut relative = PathBuf::new();
+
+    for component in Path::new(user_path).components() {
+        match component {
+            Component::Normal(v) => relative.push(v),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !relative.pop() {
+                    return Err(escaped());
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return Err(escaped()),
+        }
+    }
+
+    let resolved = resolve_symlinks(&base.join(&relative)).map_err(|e| {
+        let message = format!("[{}] 경로 확인 실패 : {:?}", user_path, e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    })?;
+
+    if !resolved.starts_with(&base) {
+        return Err(escaped());
+    }
+
+    Ok(resolved)
+}
+
+/// [resolve_symlinks]에서 허용하는 최대 심볼릭 링크 해석 횟수 (순환 링크 방지)
+const MAX_SYMLINK_HOPS: u32 = 40;
+
+/// 경로의 각 구성 요소를 [std::fs::symlink_metadata]로 확인하며 심볼릭 링크를 해석한 절대 경로 반환
+///
+/// [std::fs::canonicalize]와 달리 존재하지 않는 구성 요소를 허용하며, 대상이 존재하지 않는(dangling) 심볼릭
+/// 링크도 링크 대상 경로로 해석한다.
+fn resolve_symlinks(path: &Path) -> std::io::Result<PathBuf> {
+    let mut pending: Vec<std::ffi::OsString> = path
+        .components()
+        .rev()
+        .map(|v| v.as_os_str().to_owned())
+        .collect();
+    let mut resolved = PathBuf::new();
+    let mut hops = 0;
+
+    while let Some(part) = pending.pop() {
+        match Path::new(&part).components().next() {
+            Some(Component::Normal(name)) => {
+                let candidate = resolved.join(name);
+
+                match std::fs::symlink_metadata(&candidate) {
+                    Ok(meta) if meta.file_type().is_symlink() => {
+                        hops += 1;
+
+                        if hops > MAX_SYMLINK_HOPS {
+                            return Err(std::io::Error::other(
+                                "심볼릭 링크 해석 횟수를 초과하였습니다.",
+                            ));
+                        }
+
+                        // 링크 대상의 구성 요소를 남은 구성 요소 앞에 추가. 상대 경로는 링크가 위치한 directory 기준
+                        let target = std::fs::read_link(&candidate)?;
+
+                        pending.extend(target.components().rev().map(|v| v.as_os_str().to_owned()));
+                    }
+                    Ok(_) => resolved = candidate,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => resolved = candidate,
+                    Err(e) => return Err(e),
+                }
+            }
+            Some(Component::ParentDir) => {
+                resolved.pop();
+            }
+            Some(Component::CurDir) | None => {}
+            // root, prefix는 기존 경로를 대체하므로 절대 경로 링크 대상은 root부터 다시 해석된다.
+            Some(v) => resolved.push(v),
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// [create_temp_file]에서 파일명 충돌 시 재시도 횟수
+const TEMP_FILE_RETRY: u32 = 16;
+
+/// 고유한 이름의 임시 파일 생성
+///
+/// `{prefix}{랜덤 문자열 12자}{suffix}` 형식의 파일명으로 새 파일을 생성([std::fs::OpenOptions::create_new])하므로
+/// 동일한 `prefix`로 여러 번(동시에) 호출해도 같은 파일을 반환하지 않는다. 생성된 파일은 자동으로 삭제되지 않는다.
+///
+/// # Arguments
+///
+/// - `dir` - 파일을 생성할 directory. `None`일 경우 [std::env::temp_dir] 사용
+/// - `prefix` - 파일명 접두어
+/// - `suffix` - 파일명 접미어 (e.g. `.tmp`)
+///
+/// # Return
+///
+/// - 생성된 파일 경로 및 쓰기 가능한 [File] `Result<(PathBuf, File), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `prefix`/`suffix`에 경로 구분자가 포함되었거나 파일 생성 실패
+///
+/// # Link
+///
+/// - [std::env::temp_dir]
+/// - [std::fs::OpenOptions::create_new]
+pub fn create_temp_file(
+    dir: Option<&Path>,
+    prefix: &str,
+    suffix: &str,
+) -> Result<(PathBuf, File), InvalidArgumentError> {
+    if prefix.contains(std::path::is_separator) || suffix.contains(std::path::is_separator) {
+        return Err(InvalidArgumentError::from(
+            "prefix/suffix에 경로 구분자를 포함할 수 없습니다.",
+        ));
+    }
+
+    let dir = dir
+        .map(Path::to_path_buf)
+        .unwrap_or_else(std::env::temp_dir);
+    let mut rng = rand::thread_rng();
+    let mut last_error: Option<std::io::Error> = None;
+
+    for _ in 0..TEMP_FILE_RETRY {
+        let random: String = (&mut rng)
+            .sample_iter(&Alphanumeric)
+            .take(12)
+            .map(char::from)
+            .collect();
+        let path = dir.join(format!("{}{}{}", prefix, random, suffix));
+
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .read(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => last_error = Some(e),
+            Err(e) => return Err(InvalidArgumentError::from(e)),
+        }
+    }
+
+    Err(InvalidArgumentError::new(
+        format!("임시 파일 생성 실패 : {:?}", last_error).as_str(),
+    ))
+}
+
+/// 파일을 줄 단위로 변환한 후 원자적으로 교체
+///
+/// 파일을 한 줄씩 읽어 `f`를 적용하고, 결과를 같은 directory의 임시 파일([create_temp_file])에 기록한 후
+/// [std::fs::rename]으로 원본을 교체한다. 파일 전체를 메모리에 올리지 않으며, 처리 중 오류가 발생하면 임시 파일을
+/// 삭제하고 원본은 변경하지 않는다.
+///
+/// - `f`에는 줄 끝 문자(`\n`, `\r\n`)를 제외한 내용이 전달되며, 결과 줄에는 원본의 줄 끝 문자를 그대로 사용한다.
+/// - `f`가 `None`을 반환하면 해당 줄을 삭제한다.
+/// - 원본 파일의 권한을 유지한다.
+///
+/// # Arguments
+///
+/// - `path` - 대상 파일 경로(UTF-8 텍스트)
+/// - `f` - 줄 변환 함수
+///
+/// # Return
+///
+/// - 처리 결과 `Result<(), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 파일 읽기/쓰기 실패, UTF-8이 아닌 내용 혹은 교체 실패
+///
+/// # Link
+///
+/// - [create_temp_file]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::io_util::transform_file_lines;
+///
+/// let path = std::env::temp_dir().join("transform_file_lines_doc.conf");
+///
+/// std::fs::write(&path, "host=localhost\nport=8080\n# comment\n").unwrap();
+///
+/// transform_file_lines(&path, |line| match line {
+///     v if v.starts_with('#') => None,
+///     v if v.starts_with("port=") => Some("port=9090".to_owned()),
+///     v => Some(v.to_owned()),
+/// })
+/// .unwrap();
+///
+/// assert_eq!("host=localhost\nport=9090\n", std::fs::read_to_string(&path).unwrap());
+///
+/// std::fs::remove_file(&path).unwrap();
+/// ```
+pub fn transform_file_lines<F: FnMut(&str) -> Option<String>>(
+    path: &Path,
+    mut f: F,
+) -> Result<(), InvalidArgumentError> {
+    let io_error = |e: std::io::Error| {
+        let message = format!("[{:?}] 파일 변환 오류 : {:?}", path.as_os_str(), e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    };
+    let source = File::open(path).map_err(io_error)?;
+    let permissions = source.metadata().map_err(io_error)?.permissions();
+    let dir = match path.parent() {
+        Some(v) if !v.as_os_str().is_empty() => v,
+        _ => Path::new("."),
+    };
+    let file_name = path
+        .file_name()
+        .map(|v| v.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let (temp_path, temp_file) = create_temp_file(Some(dir), &format!(".{}.", file_name), ".tmp")?;
+    let result = (|| -> std::io::Result<()> {
+        let mut reader = BufReader::new(source);
+        let mut writer = BufWriter::new(temp_file);
+        let mut line = String::new();
+
+        while reader.read_line(&mut line)? > 0 {
+            let content = line.trim_end_matches('\n').trim_end_matches('\r');
+            let ending = &line[content.len()..];
+
+            if let Some(v) = f(content) {
+                writer.write_all(v.as_bytes())?;
+                writer.write_all(ending.as_bytes())?;
+            }
+
+            line.clear();
+        }
+
+        let temp_file = writer.into_inner().map_err(|e| e.into_error())?;
+
+        temp_file.set_permissions(permissions)?;
+        temp_file.sync_all()?;
+
+        std::fs::rename(&temp_path, path)
+    })();
+
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&temp_path);
+
+        return Err(io_error(e));
+    }
+
+    Ok(())
+}
+
+/// [copy_with_progress]에서 사용하는 버퍼 크기
+const COPY_BUFFER_SIZE: usize = 64 * 1024;
+
+/// 파일을 버퍼 단위로 복사하면서 진행률을 콜백으로 전달
+///
+/// 64KiB 단위로 읽고 쓸 때마다 `on_progress(복사된 bytes, 전체 bytes)`를 호출한다.
+/// 0 byte 파일의 경우 `on_progress(0, 0)`을 한 번 호출한다. `dst`가 이미 존재할 경우 덮어쓴다.
+///
+/// # Arguments
+///
+/// - `src` - 원본 파일 경로
+/// - `dst` - 대상 파일 경로
+/// - `on_progress` - 진행률 콜백 `(copied, total)`
+///
+/// # Return
+///
+/// - 복사한 전체 bytes `Result<u64, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 원본 파일 열기/읽기 실패(`원본 파일`로 시작하는 메시지) 혹은
+///   대상 파일 생성/쓰기 실패(`대상 파일`로 시작하는 메시지)
+///
+/// # Link
+///
+/// - [std::io::Read::read]
+/// - [std::io::Write::write_all]
+pub fn copy_with_progress<F: FnMut(u64, u64)>(
+    src: &Path,
+    dst: &Path,
+    mut on_progress: F,
+) -> Result<u64, InvalidArgumentError> {
+    let src_error = |e: std::io::Error| {
+        let message = format!("원본 파일 오류 [{:?}] : {:?}", src.as_os_str(), e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    };
+    let dst_error = |e: std::io::Error| {
+        let message = format!("대상 파일 오류 [{:?}] : {:?}", dst.as_os_str(), e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    };
+    let mut reader = File::open(src).map_err(src_error)?;
+    let total = reader.metadata().map_err(src_error)?.len();
+    let mut writer = File::create(dst).map_err(dst_error)?;
+    let mut buffer = vec![0u8; COPY_BUFFER_SIZE];
+    let mut copied: u64 = 0;
+
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(v) => v,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(src_error(e)),
+        };
+
+        writer.write_all(&buffer[..read]).map_err(dst_error)?;
+        copied += read as u64;
+        on_progress(copied, total);
+    }
+
+    writer.flush().map_err(dst_error)?;
+
+    if copied == 0 {
+        on_progress(0, total);
+    }
+
+    Ok(copied)
+}
+
+/// 지정된 directory 하위의 파일 목록을 재귀적으로 반환
+///
+/// 심볼릭 링크도 따라가며, 이미 방문한 directory(canonicalize 기준)는 다시 방문하지 않으므로 링크 순환(loop)이
+/// 있어도 종료된다. 결과는 경로 순으로 정렬된다.
+///
+/// # Arguments
+///
+/// - `root` - 검색 대상 directory
+/// - `extensions` - 수집할 확장자 목록(대/소문자 무시, `.` 유무 무관. e.g. `["txt", ".MD"]`). `None`일 경우 모든 파일
+///
+/// # Return
+///
+/// - 파일 경로 목록 `Result<Vec<PathBuf>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `root`가 directory가 아니거나 목록 조회 실패
+///
+/// # Link
+///
+/// - [get_extension]
+pub fn list_files_recursive(
+    root: &Path,
+    extensions: Option<&[&str]>,
+) -> Result<Vec<PathBuf>, InvalidArgumentError> {
+    if !root.is_dir() {
+        let message = format!("[{:?}] directory가 아닙니다.", root.as_os_str());
+
+        return Err(InvalidArgumentError::new(message.as_str()));
+    }
+
+    let extensions: Option<Vec<String>> = extensions.map(|v| {
+        v.iter()
+            .map(|e| e.trim_start_matches('.').to_lowercase())
+            .collect()
+    });
+    let to_error = InvalidArgumentError::from;
+    let mut visited: HashSet<PathBuf> = HashSet::new();
+    let mut pending: Vec<PathBuf> = vec![root.to_path_buf()];
+    let mut result: Vec<PathBuf> = vec![];
+
+    while let Some(dir) = pending.pop() {
+        if !visited.insert(std::fs::canonicalize(&dir).map_err(to_error)?) {
+            continue;
+        }
+
+        for entry in std::fs::read_dir(&dir).map_err(to_error)? {
+            let path = entry.map_err(to_error)?.path();
+
+            // 심볼릭 링크는 대상 기준으로 판단. 깨진 링크는 무시
+            if path.is_dir() {
+                pending.push(path);
+            } else if path.is_file() {
+                let matched = match &extensions {
+                    None => true,
+                    Some(v) => get_extension(&path).is_some_and(|e| v.contains(&e)),
+                };
+
+                if matched {
+                    result.push(path);
+                }
+            }
+        }
+    }
+
+    result.sort();
+
+    Ok(result)
+}
+
+/// [find_duplicate_files_with_option] 탐색 옵션
+#[cfg(any(feature = "encrypt", feature = "default"))]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct DuplicateSearchOption {
+    /// 빈 파일(0 byte) 포함 여부. 포함할 경우 모든 빈 파일은 하나의 그룹으로 묶인다.
+    pub include_empty: bool,
+
+    /// `true`일 경우 경로가 2개 이상인 그룹(실제 중복)만 반환
+    pub duplicates_only: bool,
+}
+
+#[cfg(any(feature = "encrypt", feature = "default"))]
+impl Default for DuplicateSearchOption {
+    /// 빈 파일 제외, 중복 그룹만 반환
+    fn default() -> Self {
+        DuplicateSearchOption {
+            include_empty: false,
+            duplicates_only: true,
+        }
+    }
+}
+
+/// 지정된 directory 하위에서 내용이 같은 중복 파일 탐지
+///
+/// [DuplicateSearchOption::default] 옵션(빈 파일 제외, 중복 그룹만 반환)으로
+/// [find_duplicate_files_with_option]을 호출한다.
+///
+/// # Arguments
+///
+/// - `root` - 검색 대상 directory
+/// - `hash_type` - 내용 비교에 사용할 [SHA_TYPE]
+///
+/// # Return
+///
+/// - 소문자 16진수 hash를 key로, 같은 hash를 가진 경로 목록을 값으로 하는 `Result<HashMap<String, Vec<PathBuf>>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `root`가 directory가 아니거나 파일 목록 조회/읽기 실패
+///
+/// # Link
+///
+/// - [find_duplicate_files_with_option]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::encrypt_util::SHA_TYPE;
+/// use cliff3_util::io_util::find_duplicate_files;
+///
+/// let dir = std::env::temp_dir().join("cliff3_util_find_duplicate_files_doc");
+///
+/// std::fs::create_dir_all(dir.join("sub")).unwrap();
+/// std::fs::write(dir.join("a.txt"), "same").unwrap();
+/// std::fs::write(dir.join("sub").join("b.txt"), "same").unwrap();
+/// std::fs::write(dir.join("c.txt"), "diff").unwrap();
+///
+/// let result = find_duplicate_files(&dir, SHA_TYPE::SHA_256).unwrap();
+///
+/// assert_eq!(1, result.len());
+/// assert_eq!(2, result.values().next().unwrap().len());
+///
+/// std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+#[cfg(any(feature = "encrypt", feature = "default"))]
+pub fn find_duplicate_files(
+    root: &Path,
+    hash_type: SHA_TYPE,
+) -> Result<HashMap<String, Vec<PathBuf>>, InvalidArgumentError> {
+    find_duplicate_files_with_option(root, hash_type, DuplicateSearchOption::default())
+}
+
+/// 지정된 directory 하위에서 내용이 같은 파일을 hash 기준으로 그룹화
+///
+/// [list_files_recursive]로 파일을 재귀 순회한 뒤, 먼저 파일 크기로 그룹화하여 크기가 같은 파일이 없는 경우
+/// hash 계산을 생략한다(`duplicates_only`인 경우). hash는 파일 전체를 메모리에 올리지 않고
+/// [ShaHasher]로 스트리밍 계산한다. 각 그룹의 경로 목록은 경로 순으로 정렬된다.
+///
+/// # Arguments
+///
+/// - `root` - 검색 대상 directory
+/// - `hash_type` - 내용 비교에 사용할 [SHA_TYPE]
+/// - `option` - [DuplicateSearchOption]
+///
+/// # Return
+///
+/// - 소문자 16진수 hash를 key로, 같은 hash를 가진 경로 목록을 값으로 하는 `Result<HashMap<String, Vec<PathBuf>>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `root`가 directory가 아니거나 파일 목록 조회/읽기 실패
+///
+/// # Link
+///
+/// - [find_duplicate_files]
+/// - [ShaHasher]
+#[cfg(any(feature = "encrypt", feature = "default"))]
+pub fn find_duplicate_files_with_option(
+    root: &Path,
+    hash_type: SHA_TYPE,
+    option: DuplicateSearchOption,
+) -> Result<HashMap<String, Vec<PathBuf>>, InvalidArgumentError> {
+    let to_error = |path: &Path, e: std::io::Error| {
+        let message = format!("[{:?}] 파일 읽기 오류 : {:?}", path.as_os_str(), e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    };
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+
+    for path in list_files_recursive(root, None)? {
+        let size = std::fs::metadata(&path)
+            .map_err(|e| to_error(&path, e))?
+            .len();
+
+        if size == 0 && !option.include_empty {
+            continue;
+        }
+
+        by_size.entry(size).or_default().push(path);
+    }
+
+    let mut result: HashMap<String, Vec<PathBuf>> = HashMap::new();
+
+    for paths in by_size.into_values() {
+        // 크기가 유일한 파일은 내용이 같은 파일이 있을 수 없음
+        if option.duplicates_only && paths.len() < 2 {
+            continue;
+        }
+
+        for path in paths {
+            let mut file = File::open(&path).map_err(|e| to_error(&path, e))?;
+            let mut hasher = ShaHasher::new(hash_type);
+
+            std::io::copy(&mut file, &mut hasher).map_err(|e| to_error(&path, e))?;
+
+            let hash: String = hasher
+                .finalize()
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect();
+
+            result.entry(hash).or_default().push(path);
+        }
+    }
+
+    if option.duplicates_only {
+        result.retain(|_, v| v.len() > 1);
+    }
+
+    result.values_mut().for_each(|v| v.sort());
+
+    Ok(result)
+}
+
+/// [tail_lines]에서 파일 끝부터 역방향으로 읽는 블록 크기
+const TAIL_BLOCK_SIZE: u64 = 8 * 1024;
+
+/// 파일의 마지막 `n`줄 반환
+///
+/// 파일 전체를 읽지 않고 끝에서부터 8KiB 블록 단위로 역방향으로 읽으며 개행(`\n`)을 세어 필요한 부분만 읽는다.
+///
+/// - 파일이 `n`줄보다 짧으면 전체 줄을, 빈 파일이거나 `n`이 `0`이면 빈 [Vec]을 반환한다.
+/// - 파일 마지막의 개행은 줄 구분자로 취급하므로 `"a\nb\n"`과 `"a\nb"`의 결과는 `["a", "b"]`로 같다.
+/// - 각 줄 끝의 `\r`은 제거되므로 CRLF 파일도 LF 파일과 같은 결과를 반환한다.
+///
+/// # Arguments
+///
+/// - `path` - 대상 파일 경로
+/// - `n` - 반환할 줄 수
+///
+/// # Return
+///
+/// - 마지막 `n`줄 `Result<Vec<String>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 파일 열기/읽기 실패 혹은 UTF-8이 아닌 내용이 포함된 경우
+///
+/// # Link
+///
+/// - [std::io::Seek::seek]
+pub fn tail_lines(path: &Path, n: usize) -> Result<Vec<String>, InvalidArgumentError> {
+    let io_error = |e: std::io::Error| {
+        let message = format!("[{:?}] 파일 읽기 오류 : {:?}", path.as_os_str(), e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    };
+    let mut file = File::open(path).map_err(io_error)?;
+    let mut pos = file.metadata().map_err(io_error)?.len();
+
+    if n == 0 || pos == 0 {
+        return Ok(vec![]);
+    }
+
+    let mut buffer: Vec<u8> = vec![];
+    let mut newline_count: usize = 0;
+    let mut trailing_newline = false;
+
+    while pos > 0 {
+        let read_size = TAIL_BLOCK_SIZE.min(pos);
+
+        pos -= read_size;
+        file.seek(SeekFrom::Start(pos)).map_err(io_error)?;
+
+        let mut block = vec![0u8; read_size as usize];
+
+        file.read_exact(&mut block).map_err(io_error)?;
+
+        if buffer.is_empty() {
+            trailing_newline = block.last() == Some(&b'\n');
+        }
+
+        newline_count += block.iter().filter(|v| **v == b'\n').count();
+        block.extend_from_slice(&buffer);
+        buffer = block;
+
+        // 첫 줄이 온전하려면 마지막 개행을 제외하고 n개보다 많은 개행이 필요
+        if newline_count - trailing_newline as usize > n {
+            break;
+        }
+    }
+
+    let content = if trailing_newline {
+        &buffer[..buffer.len() - 1]
+    } else {
+        &buffer[..]
+    };
+    let lines: Vec<&[u8]> = content.split(|v| *v == b'\n').collect();
+    let start = lines.len().saturating_sub(n);
+
+    lines[start..]
+        .iter()
+        .map(|line| {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+            String::from_utf8(line.to_vec()).map_err(|e| {
+                let message = format!("[{:?}] UTF-8 변환 오류 : {:?}", path.as_os_str(), e);
+
+                InvalidArgumentError::new(message.as_str())
+            })
+        })
+        .collect()
+}
+
+/// 파일의 수정 시각(mtime) 설정
+///
+/// 접근 시각(atime)은 변경하지 않는다. 심볼릭 링크일 경우 링크가 가리키는 파일의 수정 시각을 설정한다.
+/// 파일 시스템에 따라 저장되는 정밀도가 다를 수 있으므로(e.g. FAT32는 2초 단위) 다시 읽은 값과 비교할 때는
+/// 초 단위로 비교하는 것이 안전하다.
+///
+/// # Arguments
+///
+/// - `path` - 대상 파일 경로
+/// - `time` - 설정할 수정 시각
+///
+/// # Return
+///
+/// - 처리 결과 `Result<(), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 파일이 없거나 권한이 없는 등 수정 시각 설정 실패
+///
+/// # Link
+///
+/// - [filetime::set_file_mtime]
+/// - [find_latest_file]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::io_util::set_file_mtime;
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// let dir = std::env::temp_dir().join("cliff3_set_file_mtime_doc");
+/// let path = dir.join("file.txt");
+/// let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+///
+/// std::fs::create_dir_all(&dir).unwrap();
+/// std::fs::write(&path, "test").unwrap();
+/// set_file_mtime(&path, time).unwrap();
+///
+/// assert_eq!(time, std::fs::metadata(&path).unwrap().modified().unwrap());
+///
+/// std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub fn set_file_mtime(path: &Path, time: SystemTime) -> Result<(), InvalidArgumentError> {
+    filetime::set_file_mtime(path, filetime::FileTime::from_system_time(time)).map_err(|e| {
+        let message = format!("[{:?}] 수정 시각 설정 실패 : {:?}", path.as_os_str(), e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    })
+}
+
+/// directory 이동(이름 변경)
+///
+/// 같은 파일 시스템일 경우 [std::fs::rename]으로 이동하며, 다른 파일 시스템(장치)이어서 `rename`이 실패할 경우
+/// 하위 내용 전체를 복사한 후 원본을 삭제한다. 복사 중 오류가 발생하면 복사된 대상을 삭제하고 원본은 유지한다.
+///
+/// - `dst`가 이미 존재하고 `overwrite`가 `false`이면 오류를 반환한다.
+/// - `dst`가 이미 존재하고 `overwrite`가 `true`이면 기존 `dst`(파일 혹은 directory)를 같은 directory에 백업한 후
+///   이동하고, 이동이 성공하면 백업을 삭제한다. 이동에 실패하면 백업을 원래 위치로 복원한다. 병합하지 않는다.
+/// - `dst`가 `src` 자신, `src`의 하위 혹은 상위 경로이면 오류를 반환한다.
+/// - `dst`의 상위 directory는 미리 존재해야 한다.
+///
+/// # Arguments
+///
+/// - `src` - 원본 directory
+/// - `dst` - 대상 경로
+/// - `overwrite` - 대상이 존재할 경우 덮어쓰기 여부
+///
+/// # Return
+///
+/// - 처리 결과 `Result<(), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `src`가 directory가 아니거나, `dst`가 `src`의 하위/상위 경로이거나, `dst`가 존재하는데
+///   `overwrite`가 `false`인 경우 혹은 이동/복사/삭제 실패
+///
+/// # Link
+///
+/// - [std::fs::rename]
+pub fn move_directory(src: &Path, dst: &Path, overwrite: bool) -> Result<(), InvalidArgumentError> {
+    let io_error = |action: &str, path: &Path, e: std::io::Error| {
+        let message = format!("[{:?}] {} 실패 : {:?}", path.as_os_str(), action, e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    };
+
+    if !src.is_dir() {
+        let message = format!("[{:?}] directory가 아닙니다.", src.as_os_str());
+
+        return Err(InvalidArgumentError::new(message.as_str()));
+    }
+
+    let canonical_src = src
+        .canonicalize()
+        .map_err(|e| io_error("경로 확인", src, e))?;
+    let dst_name = match dst.file_name() {
+        Some(v) => v,
+        None => {
+            let message = format!("[{:?}] 올바른 대상 경로가 아닙니다.", dst.as_os_str());
+
+            return Err(InvalidArgumentError::new(message.as_str()));
+        }
+    };
+    let canonical_dst = dst
+        .parent()
+        .filter(|v| !v.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+        .canonicalize()
+        .map_err(|e| io_error("상위 경로 확인", dst, e))?
+        .join(dst_name);
+
+    // 원본 자신 혹은 원본 하위로는 이동할 수 없음
+    if canonical_dst.starts_with(&canonical_src) {
+        let message = format!(
+            "[{:?}] 원본 directory 혹은 그 하위로 이동할 수 없습니다.",
+            dst.as_os_str()
+        );
+
+        return Err(InvalidArgumentError::new(message.as_str()));
+    }
+
+    // 원본의 상위 directory로는 이동할 수 없음(overwrite 시 원본이 함께 삭제되는 것 방지)
+    if canonical_src.starts_with(&canonical_dst) {
+        let message = format!(
+            "[{:?}] 원본 directory의 상위 경로로 이동할 수 없습니다.",
+            dst.as_os_str()
+        );
+
+        return Err(InvalidArgumentError::new(message.as_str()));
+    }
+
+    // 기존 대상은 이동이 성공한 후에 삭제하도록 같은 directory에 백업
+    let backup = match std::fs::symlink_metadata(dst) {
+        Ok(_) if !overwrite => {
+            let message = format!("[{:?}] 대상이 이미 존재합니다.", dst.as_os_str());
+
+            return Err(InvalidArgumentError::new(message.as_str()));
+        }
+        Ok(_) => {
+            let backup = backup_path(&canonical_dst);
+
+            std::fs::rename(dst, &backup).map_err(|e| io_error("기존 대상 백업", dst, e))?;
+
+            Some(backup)
+        }
+        Err(_) => None,
+    };
+
+    let moved = match std::fs::rename(src, dst) {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => move_by_copy(src, dst),
+        Err(e) => Err(io_error("이동", src, e)),
+    };
+
+    match (moved, backup) {
+        (Ok(_), Some(backup)) => match std::fs::symlink_metadata(&backup) {
+            Ok(metadata) if metadata.is_dir() => std::fs::remove_dir_all(&backup),
+            _ => std::fs::remove_file(&backup),
+        }
+        .map_err(|e| io_error("기존 대상(백업) 삭제", &backup, e)),
+        (Err(e), Some(backup)) => {
+            // 이동 실패 시 기존 대상 복원
+            let _ = std::fs::rename(&backup, dst);
+
+            Err(e)
+        }
+        (moved, None) => moved,
+    }
+}
+
+/// [move_directory]에서 기존 대상을 백업할 경로. `path`와 같은 directory의 존재하지 않는 숨김 경로를 반환한다.
+fn backup_path(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let mut rng = rand::thread_rng();
+
+    loop {
+        let random: String = (&mut rng)
+            .sample_iter(&Alphanumeric)
+            .take(8)
+            .map(char::from)
+            .collect();
+        let backup = path.with_file_name(format!(".{}.{}.bak", name, random));
+
+        if std::fs::symlink_metadata(&backup).is_err() {
+            return backup;
+        }
+    }
+}
+
+/// directory를 복사한 후 원본 삭제. 복사 실패 시 복사된 대상을 삭제한다.
+fn move_by_copy(src: &Path, dst: &Path) -> Result<(), InvalidArgumentError> {
+    if let Err(e) = copy_dir_recursive(src, dst) {
+        let _ = std::fs::remove_dir_all(dst);
+
+        let message = format!("[{:?}] 복사 실패 : {:?}", src.as_os_str(), e);
+
+        return Err(InvalidArgumentError::from_io(message.as_str(), &e));
+    }
+
+    std::fs::remove_dir_all(src).map_err(|e| {
+        let message = format!("[{:?}] 원본 삭제 실패 : {:?}", src.as_os_str(), e);
+
+        InvalidArgumentError::from_io(message.as_str(), &e)
+    })
+}
+
+/// directory 하위 내용을 재귀적으로 복사. 심볼릭 링크는 링크 자체를 복사한다(unix).
+fn copy_dir_recursive(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::fs::create_dir(dst)?;
+    std::fs::set_permissions(dst, std::fs::metadata(src)?.permissions())?;
+
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = dst.join(entry.file_name());
+
+        if file_type.is_dir() {
+            copy_dir_recursive(&entry.path(), &target)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            std::os::unix::fs::symlink(std::fs::read_link(entry.path())?, &target)?;
+
+            #[cfg(not(unix))]
+            std::fs::copy(entry.path(), &target).map(|_| ())?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// [inspect_path] 결과
+///
+/// 경로가 존재하지 않으면 `exists`는 `false`이며 나머지 필드는 기본값(`false`, `0`)이다. 단, 깨진 심볼릭 링크는
+/// `exists`가 `false`이지만 `is_symlink`는 `true`이다.
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct PathInfo {
+    /// 존재 여부(심볼릭 링크는 대상 기준)
+    pub exists: bool,
+
+    /// 파일 여부(심볼릭 링크는 대상 기준)
+    pub is_file: bool,
+
+    /// Directory 여부(심볼릭 링크는 대상 기준)
+    pub is_dir: bool,
+
+    /// 경로 자체가 심볼릭 링크인지 여부
+    pub is_symlink: bool,
+
+    /// 현재 프로세스의 읽기 권한 여부
+    pub readable: bool,
+
+    /// 현재 프로세스의 쓰기 권한 여부
+    pub writable: bool,
+
+    /// 크기(bytes). Directory는 파일 시스템에 따라 다르다.
+    pub size: u64,
+}
+
+/// 경로의 존재 여부, 종류, 권한 및 크기를 한 번에 조회
+///
+/// [std::fs::symlink_metadata]와 [std::fs::metadata]를 한 번씩만 호출하여 [PathInfo]로 반환한다.
+///
+/// 권한은 Unix에서는 `faccessat(2)`(`AT_EACCESS`)로 현재 프로세스의 유효(effective) uid/gid 기준 접근 가능 여부를
+/// 판단하므로 소유자/그룹/기타 권한 비트, root 및 ACL이 반영된다. 그 외 플랫폼에서는 읽기 전용 속성으로 판단한다.
+/// 조회 이후 권한이 변경될 수 있으므로(TOCTOU) 최종 판단은 실제 파일 작업 결과로 해야 한다.
+///
+/// # Arguments
+///
+/// - `path` - 대상 경로
+///
+/// # Return
+///
+/// - [PathInfo]
+///
+/// # Link
+///
+/// - [std::fs::metadata]
+/// - [std::fs::symlink_metadata]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::io_util::inspect_path;
+///
+/// let info = inspect_path(std::env::temp_dir().as_path());
+///
+/// assert!(info.exists && info.is_dir && !info.is_file);
+///
+/// let info = inspect_path(std::path::Path::new("/not/exists/path"));
+///
+/// assert!(!info.exists);
+/// assert_eq!(0, info.size);
+/// ```
+pub fn inspect_path(path: &Path) -> PathInfo {
+    let is_symlink = std::fs::symlink_metadata(path).is_ok_and(|v| v.file_type().is_symlink());
+    let metadata = match std::fs::metadata(path) {
+        Ok(v) => v,
+        Err(_) => {
+            return PathInfo {
+                is_symlink,
+                ..Default::default()
+            };
+        }
+    };
+
+    #[cfg(unix)]
+    let (readable, writable) = (has_access(path, libc::R_OK), has_access(path, libc::W_OK));
+
+    #[cfg(not(unix))]
+    let (readable, writable) = (true, !metadata.permissions().readonly());
+
+    PathInfo {
+        exists: true,
+        is_file: metadata.is_file(),
+        is_dir: metadata.is_dir(),
+        is_symlink,
+        readable,
+        writable,
+        size: metadata.len(),
+    }
+}
+
+/// 현재 프로세스의 유효 uid/gid 기준으로 `path`에 `mode`(`R_OK`, `W_OK` 등) 접근이 가능한지 확인
+#[cfg(unix)]
+fn has_access(path: &Path, mode: libc::c_int) -> bool {
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = match std::ffi::CString::new(path.as_os_str().as_bytes()) {
+        Ok(v) => v,
+        Err(_) => return false,
+    };
+
+    // SAFETY: `c_path`는 NUL로 끝나는 유효한 문자열이며 호출 동안 유지된다.
+    unsafe { libc::faccessat(libc::AT_FDCWD, c_path.as_ptr(), mode, libc::AT_EACCESS) == 0 }
+}
+
+/// 경로가 속한 파일 시스템에서 현재 사용자가 사용할 수 있는 여유 공간(bytes) 반환
+///
+/// [fs2::available_space]를 사용한다. 플랫폼별 동작은 다음과 같다.
+///
+/// - Unix : `statvfs`로 `path`가 속한 mount 지점의 파일 시스템을 조회한다. 일반 사용자에게 허용된 블록(`f_bavail`)
+///   기준이므로 root 예약 공간은 포함하지 않는다.
+/// - Windows : `GetDiskFreeSpaceExW`로 `path`가 속한 드라이브(혹은 mount된 볼륨)를 조회하며, 사용자별 할당량
+///   (disk quota)이 적용된 값이다.
+///
+/// 여유 공간은 다른 프로세스에 의해 언제든 변할 수 있으므로 저장 전 사전 감지 용도로만 사용하고 쓰기 오류는
+/// 별도로 처리해야 한다.
+///
+/// # Arguments
+///
+/// - `path` - 조회 대상 경로 (파일 혹은 directory)
+///
+/// # Return
+///
+/// - 여유 공간 `Result<u64, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 경로가 존재하지 않거나 조회 실패. [InvalidArgumentError::io_error_kind]로 원인을
+///   확인할 수 있다.
+///
+/// # Link
+///
+/// - [total_space]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::io_util::available_space;
+///
+/// let dir = std::env::temp_dir();
+/// let required: u64 = 10 * 1024 * 1024; // 저장할 파일 크기
+///
+/// if available_space(&dir).unwrap() < required {
+///     panic!("디스크 공간이 부족합니다.");
+/// }
+///
+/// assert!(available_space(&dir.join("not-exists-directory")).is_err());
+/// ```
+pub fn available_space(path: &Path) -> Result<u64, InvalidArgumentError> {
+    fs2::available_space(path).map_err(|e| disk_space_error(path, e))
+}
+
+/// 경로가 속한 파일 시스템의 전체 크기(bytes) 반환
+///
+/// [fs2::total_space]를 사용하며, 플랫폼별 동작은 [available_space]와 같다. (Unix : mount 지점의 파일 시스템,
+/// Windows : 드라이브 혹은 mount된 볼륨)
+///
+/// # Arguments
+///
+/// - `path` - 조회 대상 경로 (파일 혹은 directory)
+///
+/// # Return
+///
+/// - 전체 크기 `Result<u64, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 경로가 존재하지 않거나 조회 실패
+///
+/// # Link
+///
+/// - [available_space]
+pub fn total_space(path: &Path) -> Result<u64, InvalidArgumentError> {
+    fs2::total_space(path).map_err(|e| disk_space_error(path, e))
+}
+
+/// [available_space], [total_space] 조회 오류 변환
+fn disk_space_error(path: &Path, e: std::io::Error) -> InvalidArgumentError {
+    let message = format!("[{:?}] 디스크 공간 조회 오류 : {:?}", path.as_os_str(), e);
+
+    InvalidArgumentError::from_io(message.as_str(), &e)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::LibError;
+    #[cfg(any(feature = "string", feature = "default"))]
+    use crate::io_util::read_file_auto_encoding;
+    #[cfg(any(feature = "date", feature = "default"))]
+    use crate::io_util::RollingFileWriter;
+    use crate::io_util::{
+        append_with_lock, available_space, copy_with_progress, create_temp_file, find_latest_file,
+        generate_path, generate_path_with_mode, get_extension, inspect_path, list_files_recursive,
+        move_by_copy, move_directory, resolve_safe_path, set_file_mtime, sniff_content_type,
+        tail_lines, total_space, transform_file_lines, DirectoryDateType, PathInfo,
+        APPEND_LOCK_TIMEOUT,
+    };
+    #[cfg(any(feature = "string", feature = "default"))]
+    use crate::string_util::DetectedEncoding;
+    use fs2::FileExt;
+    use std::fs::File;
+    use std::path::Path;
+    use std::time::{Duration, Instant, SystemTime};
+
+    #[test]
+    fn generate_path_test() {
+        let now = chrono::Local::now();
+        let compare_dir_name = DirectoryDateType::YYYYMMDD.generate_path_string(&now, Some("_"));
+        let current_path = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let result = generate_path(current_path, DirectoryDateType::YYYYMMDD, Some("_"));
+
+        // yyyy_mm_dd
+        assert!(result.is_ok());
+
+        let created_dir = result.unwrap();
+
+        assert!(created_dir.exists());
+
+        let dir_name = created_dir.file_name().unwrap();
+        assert_eq!(dir_name.to_str().unwrap(), compare_dir_name);
+
+        // 생성된 테스트 경로 삭제
+        let deleted_dir = std::fs::remove_dir(created_dir);
+
+        assert!(deleted_dir.is_ok());
+    }
+
+    #[test]
+    fn get_extension_test() {
+        assert_eq!(
+            Some("png".to_owned()),
+            get_extension(Path::new("a/b/c.PNG"))
+        );
+        assert_eq!(
+            Some("gz".to_owned()),
+            get_extension(Path::new("archive.tar.gz"))
+        );
+        assert_eq!(Some("txt".to_owned()), get_extension(Path::new("한글.Txt")));
+        assert_eq!(None, get_extension(Path::new("Makefile")));
+        assert_eq!(None, get_extension(Path::new(".gitignore")));
+    }
+
+    #[test]
+    fn sniff_content_type_test() {
+        let cases: Vec<(&[u8], &str)> = vec![
+            (b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR", "image/png"),
+            (b"\xFF\xD8\xFF\xE0\x00\x10JFIF", "image/jpeg"),
+            (b"GIF89a\x01\x00", "image/gif"),
+            (b"GIF87a\x01\x00", "image/gif"),
+            (b"BM\x36\x00", "image/bmp"),
+            (b"%PDF-1.7\n", "application/pdf"),
+            (b"PK\x03\x04\x14\x00", "application/zip"),
+            (b"PK\x05\x06\x00\x00", "application/zip"),
+            (b"\x1F\x8B\x08\x00", "application/gzip"),
+        ];
+
+        for (bytes, expected) in cases {
+            assert_eq!(
+                Some(expected),
+                sniff_content_type(bytes),
+                "{} 판별 실패",
+                expected
+            );
+        }
+
+        assert_eq!(None, sniff_content_type(b""));
+        assert_eq!(None, sniff_content_type(b"plain text"));
+        assert_eq!(
+            None,
+            sniff_content_type(b"\x89PN"),
+            "불완전한 시그니처 판별 오류"
+        );
+    }
+
+    #[test]
+    fn find_latest_file_test() {
+        let dir = tempfile::tempdir().unwrap();
+
+        assert_eq!(None, find_latest_file(dir.path()).unwrap(), "빈 directory");
+
+        let now = SystemTime::now();
+        let names = ["old.log", "latest.log", "middle.log"];
+        let offsets = [300u64, 10, 120];
+
+        for (name, offset) in names.iter().zip(offsets) {
+            let file = File::create(dir.path().join(name)).unwrap();
+
+            file.set_modified(now - Duration::from_secs(offset))
+                .unwrap();
+        }
+
+        // 하위 directory는 무시
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+
+        let result = find_latest_file(dir.path()).unwrap();
+
+        assert_eq!(Some(dir.path().join("latest.log")), result);
+
+        // directory가 아닌 경우
+        assert!(find_latest_file(&dir.path().join("old.log")).is_err());
+        assert!(find_latest_file(&dir.path().join("not_exists")).is_err());
+    }
+
+    #[test]
+    fn append_with_lock_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lock.log");
+        let line_a = "A".repeat(4096) + "\n";
+        let line_b = "B".repeat(4096) + "\n";
+        let handles: Vec<_> = [line_a.clone(), line_b.clone()]
+            .into_iter()
+            .map(|line| {
+                let path = path.clone();
+
+                std::thread::spawn(move || {
+                    for _ in 0..50 {
+                        append_with_lock(&path, line.as_bytes()).unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = written.lines().collect();
+
+        assert_eq!(100, lines.len());
+        assert_eq!(
+            50,
+            lines.iter().filter(|l| **l == line_a.trim_end()).count()
+        );
+        assert_eq!(
+            50,
+            lines.iter().filter(|l| **l == line_b.trim_end()).count()
+        );
+
+        // directory에는 쓸 수 없음
+        let result = append_with_lock(dir.path(), b"x");
+
+        assert!(result.is_err());
+        assert_ne!(
+            Some(std::io::ErrorKind::TimedOut),
+            result.unwrap_err().io_error_kind()
+        );
+
+        // 다른 handle이 잠금을 보유한 경우 시간 초과
+        let holder = File::open(&path).unwrap();
+
+        holder.lock_exclusive().unwrap();
+
+        let started = Instant::now();
+        let result = append_with_lock(&path, b"x");
+
+        assert!(started.elapsed() >= APPEND_LOCK_TIMEOUT);
+        assert_eq!(
+            Some(std::io::ErrorKind::TimedOut),
+            result.unwrap_err().io_error_kind()
+        );
+
+        holder.unlock().unwrap();
+
+        assert_eq!(100, std::fs::read_to_string(&path).unwrap().lines().count());
+    }
+
+    #[test]
+    fn resolve_safe_path_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("base");
+
+        std::fs::create_dir_all(base.join("sub")).unwrap();
+        File::create(base.join("sub/file.txt")).unwrap();
+
+        let canonical_base = std::fs::canonicalize(&base).unwrap();
+
+        // 정상 하위 경로
+        let result = resolve_safe_path(&base, "sub/file.txt").unwrap();
+
+        assert_eq!(canonical_base.join("sub/file.txt"), result);
+
+        let result = resolve_safe_path(&base, "./sub/../sub/new/file.txt").unwrap();
+
+        assert_eq!(
+            canonical_base.join("sub/new/file.txt"),
+            result,
+            "존재하지 않는 경로"
+        );
+
+        // 탈출 시도
+        for user_path in ["../../etc/passwd", "..", "sub/../../x", "/etc/passwd"] {
+            assert!(
+                resolve_safe_path(&base, user_path).is_err(),
+                "'{}' 검증 실패",
+                user_path
+            );
+        }
+
+        assert!(resolve_safe_path(&base.join("sub/file.txt"), "a").is_err());
+
+        // 심볼릭 링크를 통한 탈출
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(dir.path(), base.join("link")).unwrap();
+
+            assert!(resolve_safe_path(&base, "link").is_err());
+            assert!(resolve_safe_path(&base, "link/outside.txt").is_err());
+
+            // 대상이 존재하지 않는(dangling) 심볼릭 링크를 통한 탈출
+            let outside = dir.path().join("outside.txt");
+
+            std::os::unix::fs::symlink(&outside, base.join("dangling")).unwrap();
+
+            assert!(!base.join("dangling").exists());
+            assert!(resolve_safe_path(&base, "dangling").is_err());
+            assert!(resolve_safe_path(&base, "sub/../dangling").is_err());
+
+            // 상대 경로 dangling 링크
+            std::os::unix::fs::symlink("../../outside.txt", base.join("sub/relative")).unwrap();
+
+            assert!(resolve_safe_path(&base, "sub/relative").is_err());
+
+            // base 내부를 가리키는 링크는 링크 대상으로 해석
+            std::os::unix::fs::symlink("sub/new.txt", base.join("inner")).unwrap();
+
+            assert_eq!(
+                canonical_base.join("sub/new.txt"),
+                resolve_safe_path(&base, "inner").unwrap()
+            );
+
+            // 순환 링크
+            std::os::unix::fs::symlink("loop_b", base.join("loop_a")).unwrap();
+            std::os::unix::fs::symlink("loop_a", base.join("loop_b")).unwrap();
+
+            assert!(resolve_safe_path(&base, "loop_a").is_err());
+            assert!(!outside.exists());
+        }
+    }
+
+    #[test]
+    fn create_temp_file_test() {
+        use std::collections::HashSet;
+        use std::io::{Read, Seek, Write};
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut paths = HashSet::new();
+
+        for _ in 0..100 {
+            let (path, _) = create_temp_file(Some(dir.path()), "work_", ".tmp").unwrap();
+            let name = path.file_name().unwrap().to_str().unwrap();
+
+            assert!(name.starts_with("work_") && name.ends_with(".tmp"));
+            assert_eq!(dir.path(), path.parent().unwrap());
+            assert!(paths.insert(path), "중복된 경로 생성");
+        }
+
+        // 쓰기 가능
+        let (path, mut file) = create_temp_file(None, "cliff3_", "").unwrap();
+
+        assert!(path.starts_with(std::env::temp_dir()));
+        assert!(path.exists());
+
+        file.write_all(b"temp").unwrap();
+        file.rewind().unwrap();
+
+        let mut content = String::new();
+
+        file.read_to_string(&mut content).unwrap();
+
+        assert_eq!("temp", content);
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(create_temp_file(Some(dir.path()), "a/b", "").is_err());
+        assert!(create_temp_file(Some(&dir.path().join("none")), "a", "").is_err());
+    }
+
+    #[test]
+    fn generate_path_with_mode_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let result =
+            generate_path_with_mode(dir.path(), DirectoryDateType::YYYYMMDD, Some("-"), 0o700);
+
+        assert!(result.is_ok());
+
+        let created_dir = result.unwrap();
+
+        assert!(created_dir.is_dir());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = std::fs::metadata(&created_dir)
+                .unwrap()
+                .permissions()
+                .mode();
+
+            assert_eq!(0o700, mode & 0o777);
+        }
+
+        assert!(generate_path_with_mode(
+            &dir.path().join("none"),
+            DirectoryDateType::YYYYMMDD,
+            None,
+            0o700
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn copy_with_progress_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src.bin");
+        let dst = dir.path().join("dst.bin");
+        let content: Vec<u8> = (0..200_000u32).map(|v| (v % 251) as u8).collect();
+
+        std::fs::write(&src, &content).unwrap();
+
+        let mut reports: Vec<(u64, u64)> = vec![];
+        let copied = copy_with_progress(&src, &dst, |c, t| reports.push((c, t))).unwrap();
+
+        assert_eq!(content.len() as u64, copied);
+        assert_eq!(content, std::fs::read(&dst).unwrap());
+        assert_eq!(4, reports.len(), "64KiB 단위 호출"); // 65536 * 3 < 200000
+        assert!(reports.iter().all(|(_, t)| *t == copied));
+        assert!(reports.windows(2).all(|v| v[0].0 < v[1].0));
+        assert_eq!((copied, copied), *reports.last().unwrap());
+
+        // 0 byte 파일
+        let empty = dir.path().join("empty.bin");
+
+        File::create(&empty).unwrap();
+        reports.clear();
+
+        let copied = copy_with_progress(&empty, &dst, |c, t| reports.push((c, t))).unwrap();
+
+        assert_eq!(0, copied);
+        assert_eq!(vec![(0, 0)], reports);
+        assert_eq!(0, std::fs::metadata(&dst).unwrap().len());
+
+        // 원본 없음 / 대상 쓰기 실패
+        let err = copy_with_progress(&dir.path().join("none"), &dst, |_, _| {}).unwrap_err();
+
+        assert!(
+            err.get_message().starts_with("원본 파일"),
+            "{}",
+            err.get_message()
+        );
+
+        let err = copy_with_progress(&src, &dir.path().join("none/dst"), |_, _| {}).unwrap_err();
+
+        assert!(
+            err.get_message().starts_with("대상 파일"),
+            "{}",
+            err.get_message()
+        );
+    }
+
+    #[test]
+    fn list_files_recursive_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        std::fs::create_dir_all(root.join("a/b/c")).unwrap();
+        std::fs::create_dir_all(root.join("d")).unwrap();
+
+        for name in [
+            "1.txt",
+            "a/2.TXT",
+            "a/b/3.md",
+            "a/b/c/4.txt",
+            "d/5",
+            "d/6.txt.bak",
+        ] {
+            File::create(root.join(name)).unwrap();
+        }
+
+        let result = list_files_recursive(root, Some(&["txt"])).unwrap();
+
+        assert_eq!(
+            vec![
+                root.join("1.txt"),
+                root.join("a/2.TXT"),
+                root.join("a/b/c/4.txt")
+            ],
+            result
+        );
+
+        let result = list_files_recursive(root, Some(&[".md", "TXT"])).unwrap();
+
+        assert_eq!(4, result.len());
+        assert_eq!(6, list_files_recursive(root, None).unwrap().len());
+        assert!(list_files_recursive(root, Some(&[])).unwrap().is_empty());
+
+        // 심볼릭 링크 순환
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(root, root.join("a/b/loop")).unwrap();
+
+            // 이미 방문한 root는 다시 방문하지 않음
+            assert_eq!(3, list_files_recursive(root, Some(&["txt"])).unwrap().len());
+        }
+
+        assert!(list_files_recursive(&root.join("1.txt"), None).is_err());
+        assert!(list_files_recursive(&root.join("none"), None).is_err());
+    }
+
+    #[test]
+    fn tail_lines_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tail.log");
+
+        // 빈 파일 / n = 0
+        File::create(&path).unwrap();
+
+        assert!(tail_lines(&path, 3).unwrap().is_empty());
+
+        std::fs::write(&path, "a\nb\nc\n").unwrap();
+
+        assert!(tail_lines(&path, 0).unwrap().is_empty());
+
+        // 마지막 줄 개행 유무와 관계없이 같은 결과
+        assert_eq!(vec!["b", "c"], tail_lines(&path, 2).unwrap());
+
+        std::fs::write(&path, "a\nb\nc").unwrap();
+
+        assert_eq!(vec!["b", "c"], tail_lines(&path, 2).unwrap());
+
+        // 파일이 n줄보다 짧으면 전체
+        assert_eq!(vec!["a", "b", "c"], tail_lines(&path, 10).unwrap());
+
+        // CRLF
+        std::fs::write(&path, "첫째\r\n둘째\r\n셋째\r\n").unwrap();
+
+        assert_eq!(vec!["둘째", "셋째"], tail_lines(&path, 2).unwrap());
+
+        // 빈 줄 유지
+        std::fs::write(&path, "a\n\nb\n\n").unwrap();
+
+        assert_eq!(vec!["", "b", ""], tail_lines(&path, 3).unwrap());
+
+        // 블록 경계를 넘는 큰 파일
+        let content: String = (0..5000).map(|v| format!("line-{:05} 한글\n", v)).collect();
+
+        std::fs::write(&path, &content).unwrap();
+
+        let result = tail_lines(&path, 1200).unwrap();
+
+        assert_eq!(1200, result.len());
+        assert_eq!("line-03800 한글", result[0]);
+        assert_eq!("line-04999 한글", result[1199]);
+        assert_eq!(5000, tail_lines(&path, 5000).unwrap().len());
+        assert_eq!("line-00000 한글", tail_lines(&path, 6000).unwrap()[0]);
+
+        // 파일 없음
+        assert!(tail_lines(&dir.path().join("none.log"), 1).is_err());
+    }
+
+    #[test]
+    fn set_file_mtime_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("archive.log");
+        let seconds = |t: SystemTime| t.duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
+
+        std::fs::write(&path, "content").unwrap();
+
+        let before_accessed = std::fs::metadata(&path).unwrap().accessed().unwrap();
+        let time = std::time::UNIX_EPOCH + Duration::new(1_732_240_800, 500_000_000); // 2024-11-22 02:00:00.5 UTC
+
+        set_file_mtime(&path, time).unwrap();
+
+        let metadata = std::fs::metadata(&path).unwrap();
+
+        assert_eq!(seconds(time), seconds(metadata.modified().unwrap()));
+        assert_eq!(before_accessed, metadata.accessed().unwrap(), "atime 유지");
+
+        // 과거 시각으로 설정하여 find_latest_file 결과를 결정적으로 만듦
+        let other = dir.path().join("other.log");
+
+        std::fs::write(&other, "other").unwrap();
+        set_file_mtime(&other, time - Duration::from_secs(86_400)).unwrap();
+
+        assert_eq!(Some(path.clone()), find_latest_file(dir.path()).unwrap());
+
+        set_file_mtime(&other, time + Duration::from_secs(1)).unwrap();
+
+        assert_eq!(Some(other), find_latest_file(dir.path()).unwrap());
+
+        // 파일 없음
+        let result = set_file_mtime(&dir.path().join("not_exists.log"), time);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().get_message().contains("not_exists.log"));
+    }
+
+    #[test]
+    fn move_directory_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let make_tree = |root: &Path| {
+            std::fs::create_dir_all(root.join("sub/deep")).unwrap();
+            std::fs::write(root.join("a.txt"), "a").unwrap();
+            std::fs::write(root.join("sub/deep/b.txt"), "b").unwrap();
+        };
+        let assert_tree = |root: &Path| {
+            assert_eq!("a", std::fs::read_to_string(root.join("a.txt")).unwrap());
+            assert_eq!(
+                "b",
+                std::fs::read_to_string(root.join("sub/deep/b.txt")).unwrap()
+            );
+        };
+        let src = dir.path().join("2024-11-22");
+        let dst = dir.path().join("archive");
+
+        // 같은 파일 시스템(rename)
+        make_tree(&src);
+        move_directory(&src, &dst, false).unwrap();
+
+        assert!(!src.exists());
+        assert_tree(&dst);
+
+        // 대상이 존재하고 overwrite = false
+        make_tree(&src);
+
+        let result = move_directory(&src, &dst, false);
+
+        assert!(result.unwrap_err().get_message().contains("이미 존재"));
+        assert!(src.exists(), "원본 유지");
+
+        // overwrite = true 이면 기존 대상을 삭제 후 이동(병합하지 않음)
+        std::fs::write(dst.join("old.txt"), "old").unwrap();
+        std::fs::write(src.join("a.txt"), "new a").unwrap();
+        move_directory(&src, &dst, true).unwrap();
+
+        assert!(!src.exists());
+        assert!(!dst.join("old.txt").exists());
+        assert_eq!("new a", std::fs::read_to_string(dst.join("a.txt")).unwrap());
+
+        // 대상이 파일인 경우
+        let file_dst = dir.path().join("file_dst");
+
+        make_tree(&src);
+        std::fs::write(&file_dst, "file").unwrap();
+        move_directory(&src, &file_dst, true).unwrap();
+
+        assert_tree(&file_dst);
+
+        // 크로스 디바이스 폴백(복사 후 삭제)
+        let copy_src = dir.path().join("copy_src");
+        let copy_dst = dir.path().join("copy_dst");
+
+        make_tree(&copy_src);
+
+        #[cfg(unix)]
+        std::os::unix::fs::symlink("a.txt", copy_src.join("link")).unwrap();
+
+        move_by_copy(&copy_src, &copy_dst).unwrap();
+
+        assert!(!copy_src.exists());
+        assert_tree(&copy_dst);
+
+        #[cfg(unix)]
+        assert_eq!(
+            Path::new("a.txt"),
+            std::fs::read_link(copy_dst.join("link")).unwrap()
+        );
+
+        // 복사 실패 시 원본 유지, 복사된 대상 삭제
+        make_tree(&copy_src);
+
+        assert!(move_by_copy(&copy_src, &dir.path().join("none/copy_dst")).is_err());
+        assert_tree(&copy_src);
+
+        // 실제 다른 장치로 이동(/dev/shm이 있는 경우)
+        #[cfg(target_os = "linux")]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            let shm = Path::new("/dev/shm");
+
+            if shm.is_dir() && shm.metadata().unwrap().dev() != dir.path().metadata().unwrap().dev()
+            {
+                let shm_dir = tempfile::tempdir_in(shm).unwrap();
+                let shm_dst = shm_dir.path().join("moved");
+
+                move_directory(&copy_src, &shm_dst, false).unwrap();
+
+                assert!(!copy_src.exists());
+                assert_tree(&shm_dst);
+            }
+        }
+
+        // 원본 오류, 하위 경로로 이동
+        assert!(move_directory(&dir.path().join("none"), &dst, true).is_err());
+        assert!(move_directory(&dst, &dst.join("sub/inner"), true).is_err());
+        assert!(move_directory(&dst, &dst, true).is_err());
+        assert!(move_directory(&dst, &dir.path().join("archive/."), true).is_err());
+        assert!(move_directory(&dst, &dir.path().join("archive/.."), true).is_err());
+        assert_eq!("new a", std::fs::read_to_string(dst.join("a.txt")).unwrap());
+        assert!(dst.join("sub/deep/b.txt").exists());
+
+        // 원본의 상위 경로로 이동 : 원본과 대상 모두 유지
+        let parent = dir.path().join("parent");
+        let child = parent.join("child");
+
+        make_tree(&child);
+        std::fs::write(parent.join("p.txt"), "p").unwrap();
+
+        for target in [parent.clone(), dir.path().to_path_buf()] {
+            let result = move_directory(&child, &target, true);
+
+            assert!(result.unwrap_err().get_message().contains("상위 경로"));
+            assert_tree(&child);
+            assert_eq!("p", std::fs::read_to_string(parent.join("p.txt")).unwrap());
+        }
+
+        // 덮어쓰기 후 백업이 남지 않음
+        let entries: Vec<String> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|v| v.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+
+        assert!(
+            entries.iter().all(|v| !v.ends_with(".bak")),
+            "백업 잔존 : {:?}",
+            entries
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn read_file_auto_encoding_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("encoded.txt");
+        let cases: Vec<(Vec<u8>, DetectedEncoding)> = vec![
+            (b"\xEF\xBB\xBFplain".to_vec(), DetectedEncoding::Utf8),
+            ("plain".as_bytes().to_vec(), DetectedEncoding::Utf8),
+            (
+                [
+                    &[0xFFu8, 0xFE][..],
+                    &[b'p', 0, b'l', 0, b'a', 0, b'i', 0, b'n', 0][..],
+                ]
+                .concat(),
+                DetectedEncoding::Utf16Le,
+            ),
+            (
+                [
+                    &[0xFEu8, 0xFF][..],
+                    &[0, b'p', 0, b'l', 0, b'a', 0, b'i', 0, b'n'][..],
+                ]
+                .concat(),
+                DetectedEncoding::Utf16Be,
+            ),
+        ];
+
+        for (bytes, expected) in cases {
+            std::fs::write(&path, bytes).unwrap();
+
+            let (content, encoding) = read_file_auto_encoding(&path).unwrap();
+
+            assert_eq!("plain", content);
+            assert_eq!(expected, encoding);
+        }
+
+        // EUC-KR "한글"
+        std::fs::write(&path, [0xC7, 0xD1, 0xB1, 0xDB]).unwrap();
+        assert_eq!(
+            ("한글".to_owned(), DetectedEncoding::EucKr),
+            read_file_auto_encoding(&path).unwrap()
+        );
+
+        // 판별 불가
+        std::fs::write(&path, [0x00, 0x00, 0xFF, 0x01, 0xC7]).unwrap();
+        assert!(read_file_auto_encoding(&path).is_err());
+        assert!(read_file_auto_encoding(&dir.path().join("none.txt"))
+            .unwrap_err()
+            .get_message()
+            .contains("파일 읽기 오류"));
+    }
+
+    #[test]
+    fn transform_file_lines_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.conf");
+
+        std::fs::write(
+            &path,
+            "host=localhost\r\nport=8080\n# 주석\ntimeout=30\nlast=없음",
+        )
+        .unwrap();
+
+        // 수정 및 삭제, 줄 끝 문자 유지
+        let mut lines: Vec<String> = vec![];
+
+        transform_file_lines(&path, |line| {
+            lines.push(line.to_owned());
+
+            match line {
+                v if v.starts_with('#') => None,
+                v if v.starts_with("port=") => Some("port=9090".to_owned()),
+                v if v.starts_with("last=") => Some("last=변경".to_owned()),
+                v => Some(v.to_owned()),
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                "host=localhost",
+                "port=8080",
+                "# 주석",
+                "timeout=30",
+                "last=없음"
+            ],
+            lines
+        );
+        assert_eq!(
+            "host=localhost\r\nport=9090\ntimeout=30\nlast=변경",
+            std::fs::read_to_string(&path).unwrap()
+        );
+
+        // 모든 줄 삭제
+        transform_file_lines(&path, |_| None).unwrap();
+        assert_eq!("", std::fs::read_to_string(&path).unwrap());
+
+        // 임시 파일이 남지 않음
+        assert_eq!(1, std::fs::read_dir(dir.path()).unwrap().count());
+
+        // UTF-8이 아닌 경우 원본 보존
+        let original = [b'a', b'\n', 0xC7, 0xD1, b'\n'];
+
+        std::fs::write(&path, original).unwrap();
+        assert!(transform_file_lines(&path, |v| Some(v.to_uppercase())).is_err());
+        assert_eq!(original.to_vec(), std::fs::read(&path).unwrap());
+        assert_eq!(1, std::fs::read_dir(dir.path()).unwrap().count());
+
+        // 존재하지 않는 파일
+        assert!(
+            transform_file_lines(&dir.path().join("none"), |v| Some(v.to_owned()))
+                .unwrap_err()
+                .get_message()
+                .contains("파일 변환 오류")
+        );
+
+        // 권한 유지
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            std::fs::write(&path, "a\n").unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o640)).unwrap();
+            transform_file_lines(&path, |v| Some(v.repeat(2))).unwrap();
+
+            assert_eq!("aa\n", std::fs::read_to_string(&path).unwrap());
+            assert_eq!(
+                0o640,
+                std::fs::metadata(&path).unwrap().permissions().mode() & 0o777
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "date", feature = "default"))]
+    fn rolling_file_writer_test() {
+        use crate::date_util::Clock;
+        use chrono::{DateTime, Local, TimeZone, Utc};
+        use std::io::Write;
+        use std::sync::{Arc, Mutex};
+
+        /// 테스트에서 시각을 변경할 수 있는 Clock
+        #[derive(Clone)]
+        struct ManualClock(Arc<Mutex<DateTime<Utc>>>);
+
+        impl Clock for ManualClock {
+            fn now_utc(&self) -> DateTime<Utc> {
+                *self.0.lock().unwrap()
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let day1 = Utc.with_ymd_and_hms(2024, 11, 22, 12, 0, 0).unwrap();
+        let day2 = Utc.with_ymd_and_hms(2024, 11, 23, 12, 0, 0).unwrap();
+        let clock = ManualClock(Arc::new(Mutex::new(day1)));
+        let expected_path = |v: &DateTime<Utc>| {
+            dir.path()
+                .join(
+                    DirectoryDateType::YYYYMMDD
+                        .generate_path_string(&v.with_timezone(&Local), None),
+                )
+                .join("app.log")
+        };
+        let mut writer = RollingFileWriter::with_clock(
+            dir.path(),
+            DirectoryDateType::YYYYMMDD,
+            "app.log",
+            Box::new(clock.clone()),
+        );
+
+        assert!(writer.current_path().is_none());
+        writer.flush().unwrap();
+
+        writeln!(writer, "first").unwrap();
+        writeln!(writer, "second").unwrap();
+        assert_eq!(
+            expected_path(&day1).as_path(),
+            writer.current_path().unwrap()
+        );
+
+        // 날짜 경계를 넘기면 새 directory/파일로 전환
+        *clock.0.lock().unwrap() = day2;
+        writeln!(writer, "third").unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(
+            expected_path(&day2).as_path(),
+            writer.current_path().unwrap()
+        );
+        assert_eq!(
+            "first\nsecond\n",
+            std::fs::read_to_string(expected_path(&day1)).unwrap()
+        );
+        assert_eq!(
+            "third\n",
+            std::fs::read_to_string(expected_path(&day2)).unwrap()
+        );
+
+        // 이전 날짜로 돌아가면 기존 파일에 추가
+        *clock.0.lock().unwrap() = day1;
+        writer.write_all(b"fourth\n").unwrap();
+        assert_eq!(
+            "first\nsecond\nfourth\n",
+            std::fs::read_to_string(expected_path(&day1)).unwrap()
+        );
+
+        // 같은 월이면 YYYYMM은 전환되지 않음
+        let mut monthly = RollingFileWriter::with_clock(
+            dir.path(),
+            DirectoryDateType::YYYYMM,
+            "monthly.log",
+            Box::new(clock.clone()),
+        );
+
+        monthly.write_all(b"a").unwrap();
+
+        let first_path = monthly.current_path().unwrap().to_path_buf();
+
+        *clock.0.lock().unwrap() = day2;
+        monthly.write_all(b"b").unwrap();
+        assert_eq!(first_path.as_path(), monthly.current_path().unwrap());
+        assert_eq!("ab", std::fs::read_to_string(&first_path).unwrap());
+
+        // 부모 경로가 없으면 오류
+        let mut invalid =
+            RollingFileWriter::new(&dir.path().join("none"), DirectoryDateType::YYYY, "app.log");
+
+        assert!(invalid.write_all(b"a").is_err());
+        assert!(invalid.current_path().is_none());
+    }
+
+    #[cfg(any(feature = "encrypt", feature = "default"))]
+    #[test]
+    fn find_duplicate_files_test() {
+        use crate::encrypt_util::SHA_TYPE;
+        use crate::io_util::{
+            find_duplicate_files, find_duplicate_files_with_option, DuplicateSearchOption,
+        };
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        std::fs::create_dir_all(root.join("a/b")).unwrap();
+        std::fs::write(root.join("1.txt"), "same content").unwrap();
+        std::fs::write(root.join("a/2.txt"), "same content").unwrap();
+        std::fs::write(root.join("a/b/3.bak"), "same content").unwrap();
+        std::fs::write(root.join("4.txt"), "diff content").unwrap(); // 크기는 같으나 내용이 다름
+        std::fs::write(root.join("5.txt"), "unique").unwrap();
+        std::fs::write(root.join("a/6.txt"), "pair").unwrap();
+        std::fs::write(root.join("a/b/7.txt"), "pair").unwrap();
+        File::create(root.join("empty1")).unwrap();
+        File::create(root.join("a/empty2")).unwrap();
+
+        let result = find_duplicate_files(root, SHA_TYPE::SHA_256).unwrap();
+
+        assert_eq!(2, result.len());
+
+        let mut groups: Vec<&Vec<std::path::PathBuf>> = result.values().collect();
+
+        groups.sort_by_key(|v| v.len());
+
+        assert_eq!(
+            &vec![root.join("a/6.txt"), root.join("a/b/7.txt")],
+            groups[0]
+        );
+        assert_eq!(
+            &vec![
+                root.join("1.txt"),
+                root.join("a/2.txt"),
+                root.join("a/b/3.bak")
+            ],
+            groups[1]
+        );
+
+        let key: String =
+            crate::encrypt_util::make_sha_hash_string(SHA_TYPE::SHA_512, "pair", None).unwrap();
+
+        assert!(find_duplicate_files(root, SHA_TYPE::SHA_512)
+            .unwrap()
+            .contains_key(&key));
+
+        // 빈 파일 포함
+        let option = DuplicateSearchOption {
+            include_empty: true,
+            ..Default::default()
+        };
+        let result = find_duplicate_files_with_option(root, SHA_TYPE::SHA_256, option).unwrap();
+
+        assert_eq!(3, result.len());
+        assert!(result
+            .values()
+            .any(|v| v == &vec![root.join("a/empty2"), root.join("empty1")]));
+
+        // 단일 파일 그룹 포함
+        let option = DuplicateSearchOption {
+            include_empty: false,
+            duplicates_only: false,
+        };
+        let result = find_duplicate_files_with_option(root, SHA_TYPE::SHA_256, option).unwrap();
+
+        assert_eq!(4, result.len());
+        assert_eq!(7, result.values().map(|v| v.len()).sum::<usize>());
+
+        assert!(find_duplicate_files(&root.join("1.txt"), SHA_TYPE::SHA_256).is_err());
+    }
+
+    #[test]
+    fn inspect_path_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("file.txt");
+
+        std::fs::write(&file, "12345").unwrap();
+
+        // 파일
+        let info = inspect_path(&file);
+
+        assert!(info.exists && info.is_file && !info.is_dir && !info.is_symlink);
+        assert!(info.readable && info.writable);
+        assert_eq!(5, info.size);
+
+        // directory
+        let info = inspect_path(dir.path());
+
+        assert!(info.exists && info.is_dir && !info.is_file && !info.is_symlink);
+        assert!(info.readable && info.writable);
+
+        // 없는 경로
+        assert_eq!(PathInfo::default(), inspect_path(&dir.path().join("none")));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            // root는 권한 비트와 관계없이 읽기/쓰기가 가능하다.
+            // SAFETY: `geteuid`는 인자가 없고 실패하지 않는다.
+            let is_root = unsafe { libc::geteuid() } == 0;
+
+            // 읽기 전용
+            std::fs::set_permissions(&file, std::fs::Permissions::from_mode(0o444)).unwrap();
+
+            let info = inspect_path(&file);
+
+            assert!(info.readable);
+            assert_eq!(is_root, info.writable);
+
+            std::fs::set_permissions(&file, std::fs::Permissions::from_mode(0o200)).unwrap();
+
+            let info = inspect_path(&file);
+
+            assert_eq!(is_root, info.readable);
+            assert!(info.writable);
+
+            // 그룹/기타 비트만 있고 소유자 비트가 없으면 소유자는 접근할 수 없음
+            std::fs::set_permissions(&file, std::fs::Permissions::from_mode(0o066)).unwrap();
+
+            let info = inspect_path(&file);
+
+            assert_eq!(is_root, info.readable);
+            assert_eq!(is_root, info.writable);
+
+            std::fs::set_permissions(&file, std::fs::Permissions::from_mode(0o600)).unwrap();
+
+            // 심볼릭 링크 및 깨진 링크
+            let link = dir.path().join("link");
+            let broken = dir.path().join("broken");
+
+            std::os::unix::fs::symlink(&file, &link).unwrap();
+            std::os::unix::fs::symlink(dir.path().join("none"), &broken).unwrap();
+
+            let info = inspect_path(&link);
+
+            assert!(info.exists && info.is_file && info.is_symlink);
+            assert_eq!(5, info.size);
+
+            let info = inspect_path(&broken);
+
+            assert!(!info.exists && !info.is_file && info.is_symlink);
+        }
+    }
+
+    #[test]
+    fn io_error_kind_test() {
+        use crate::error::InvalidArgumentError;
+        use std::io::ErrorKind;
+
+        let converted = InvalidArgumentError::from(std::io::Error::from(ErrorKind::NotFound));
+
+        assert_eq!(Some(ErrorKind::NotFound), converted.io_error_kind());
+        assert_eq!(None, InvalidArgumentError::new("a").io_error_kind());
+
+        // 존재하지 않는 부모 경로
+        let dir = tempfile::tempdir().unwrap();
+        let result = generate_path(&dir.path().join("none"), DirectoryDateType::YYYY, None);
+
+        assert_eq!(
+            Some(ErrorKind::NotFound),
+            result.unwrap_err().io_error_kind()
+        );
+
+        #[cfg(unix)]
+        {
+            // 부모 경로가 파일인 경우
+            let file_path = dir.path().join("file");
+
+            File::create(&file_path).unwrap();
+
+            let result = generate_path(&file_path, DirectoryDateType::YYYY, None);
+
+            assert_eq!(
+                Some(ErrorKind::NotADirectory),
+                result.unwrap_err().io_error_kind()
+            );
+        }
+
+        // 권한 오류 kind 보존 (root 여부와 관계없이 변환 경로 검증)
+        let denied = std::io::Error::from(ErrorKind::PermissionDenied);
+
+        assert_eq!(
+            Some(ErrorKind::PermissionDenied),
+            InvalidArgumentError::from_io("생성 실패", &denied).io_error_kind()
+        );
+        assert_eq!(
+            Some(ErrorKind::PermissionDenied),
+            InvalidArgumentError::from(denied).io_error_kind()
+        );
+
+        // 그 외 io 함수의 kind 보존
+        let missing = dir.path().join("missing.txt");
+
+        assert_eq!(
+            Some(ErrorKind::NotFound),
+            set_file_mtime(&missing, SystemTime::now())
+                .unwrap_err()
+                .io_error_kind()
+        );
+        assert_eq!(
+            Some(ErrorKind::NotFound),
+            tail_lines(&missing, 1).unwrap_err().io_error_kind()
+        );
+
+        #[cfg(any(feature = "string", feature = "default"))]
+        assert_eq!(
+            Some(ErrorKind::NotFound),
+            read_file_auto_encoding(&missing)
+                .unwrap_err()
+                .io_error_kind()
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn io_error_kind_permission_denied_test() {
+        use std::io::ErrorKind;
+        use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+        let dir = tempfile::tempdir().unwrap();
+        let locked = dir.path().join("locked");
+
+        std::fs::create_dir(&locked).unwrap();
+
+        // root는 권한과 관계없이 directory 생성에 성공하므로 이 검증은 일반 사용자로 실행할 때만 유효하다.
+        // 테스트가 생성한 directory의 소유자로 root 권한 실행 여부를 확인한다.
+        if std::fs::metadata(&locked).unwrap().uid() == 0 {
+            return;
+        }
+
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o500)).unwrap();
+
+        let result = generate_path(&locked, DirectoryDateType::YYYYMMDD, None);
+
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o700)).unwrap();
+
+        assert_eq!(
+            Some(ErrorKind::PermissionDenied),
+            result.unwrap_err().io_error_kind()
+        );
+    }
+
+    #[test]
+    fn disk_space_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+
+        File::create(&file).unwrap();
+
+        let available = available_space(dir.path()).unwrap();
+        let total = total_space(dir.path()).unwrap();
+
+        assert!(available > 0);
+        assert!(total > 0);
+        assert!(total >= available);
+
+        // 파일 경로는 해당 파일이 속한 파일 시스템 기준
+        assert_eq!(total, total_space(&file).unwrap());
+
+        // 존재하지 않는 경로
+        let missing = dir.path().join("none");
+
+        assert_eq!(
+            Some(std::io::ErrorKind::NotFound),
+            available_space(&missing).unwrap_err().io_error_kind()
+        );
+        assert!(total_space(&missing).is_err());
     }
 }
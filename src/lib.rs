@@ -7,6 +7,7 @@
 //! - `io` - I/O 유틸리티 관련 함수 활성화
 //! - `date` - 날짜(chrono) 관련 함수 활성화
 //! - `default` - 위 함수 모두 포함
+//! - `gcm-siv` - AES-GCM-SIV 암복호화 함수 활성화(`encrypt` 포함, `default`에 포함되지 않음)
 
 pub mod error;
 
@@ -2,45 +2,138 @@
 //!
 //! 한글 초/중/종성 분리 관련 소스 출처는 [가사시니](https://gs.saro.me/2018/10/01/백업-가리사니-자바-한글분해-Stream-API,-StringBuilder,-raw-속도-테스트.html)님 블로그 입니다.
 
-use crate::error::MissingArgumentError;
-use lazy_static::lazy_static;
+use crate::error::{InvalidArgumentError, MissingArgumentError};
+use encoding_rs::{Encoding, EUC_KR, UTF_16BE, UTF_16LE, UTF_8};
+use once_cell::sync::Lazy;
 use rand::Rng;
 use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 // 마스킹 처리용 문자
 // const APPLY_MASK: &str = "*";
 
-lazy_static! {
-    /// 이메일 정규식
-    static ref EMAIL_REGEX: Regex = Regex::new(r"^[\w\-]+(\.[\w\-]+)*@([A-Za-z0-9-]+\.)+[A-Za-z]{2,4}$").unwrap();
+/// Title case 변환시 소문자를 유지하는 단어 목록(관사, 전치사, 접속사)
+pub const TITLE_CASE_EXCEPTIONS: &[&str] = &[
+    "a", "an", "the", "and", "but", "or", "nor", "for", "so", "yet", "as", "at", "by", "in", "of",
+    "off", "on", "per", "to", "up", "via", "from", "into", "onto", "with",
+];
 
-    static ref RANDOM_SOURCE: Vec<&'static str> = vec![
+/// [apply_dueum]에서 두음법칙을 적용하지 않는 단어 목록(외래어 및 고유어). 어두가 이 단어로 시작하면 변환하지 않는다.
+pub const DUEUM_EXCEPTIONS: &[&str] = &[
+    "라디오",
+    "라면",
+    "라이터",
+    "라인",
+    "라틴",
+    "러시아",
+    "로그",
+    "로마",
+    "로봇",
+    "루머",
+    "리더",
+    "리듬",
+    "리스트",
+    "뉴스",
+    "뉴욕",
+    "뉴질랜드",
+    "니켈",
+    "니트",
+    "녀석",
+];
+
+/// 무작위 문자열 생성시 제외할 혼동하기 쉬운 문자 목록
+pub const AMBIGUOUS_CHARACTERS: &[&str] = &["0", "O", "o", "1", "l", "I"];
+
+/// [is_balanced_brackets]에서 사용하는 기본 괄호 쌍
+pub const DEFAULT_BRACKET_PAIRS: &[(char, char)] = &[('(', ')'), ('[', ']'), ('{', '}')];
+
+/// Base62 인코딩 문자 집합. [RANDOM_SOURCE]와 동일한 순서(숫자, 소문자, 대문자)를 사용한다.
+const BASE62_ALPHABET: &[u8; 62] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Base32(RFC 4648) 인코딩 문자 집합
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// 이메일 정규식 패턴. 검증/추출 정규식에서 공통으로 사용
+///
+/// - 로컬 파트 : 영문/숫자/`_`/`-`와 `.`으로 구성되며 `.`으로 시작/끝나거나 연속될 수 없음
+/// - 도메인 : 각 label은 영문/숫자로 시작하고 끝나며 최대 63자
+/// - TLD : 영문 2 ~ 63자
+const EMAIL_PATTERN: &str =
+    r"[\w\-]+(\.[\w\-]+)*@([A-Za-z0-9]([A-Za-z0-9-]{0,61}[A-Za-z0-9])?\.)+[A-Za-z]{2,63}";
+
+/// 이메일 로컬 파트 최대 길이 (RFC 5321)
+const EMAIL_LOCAL_PART_MAX: usize = 64;
+
+/// 이메일 주소 최대 길이 (RFC 5321 경로 길이 제한 기준)
+const EMAIL_MAX: usize = 254;
+
+/// 이메일 정규식
+static EMAIL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(format!("^{}$", EMAIL_PATTERN).as_str()).unwrap());
+
+/// 문자열 내 이메일 추출용 정규식. TLD 뒤에 붙은 한글 조사(e.g. `으로`, `로`)는 경계로 보도록 ASCII 단어 경계를 사용한다.
+static EMAIL_FIND_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(format!(r"{}(?-u:\b)", EMAIL_PATTERN).as_str()).unwrap());
+
+/// 문자열 내 URL 추출용 정규식. 공백, 따옴표, 괄호 및 꺾쇠는 URL에 포함하지 않는다.
+static URL_FIND_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)\bhttps?://[^\s<>"'()\[\]{}]+"#).unwrap());
+
+static RANDOM_SOURCE: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    vec![
         "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "0", "a", "b", "c", "d", "e", "f", "g",
         "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y",
         "z", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q",
         "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
-    ];
+    ]
+});
 
-    static ref RANDOM_SOURCE_SPEC: Vec<&'static str> = vec![
+static RANDOM_SOURCE_SPEC: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    vec![
         "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "0", "a", "b", "c", "d", "e", "f", "g",
         "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y",
         "z", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q",
         "R", "S", "T", "U", "V", "W", "X", "Y", "Z", "~", "`", "!", "@", "#", "$", "%", "^", "&",
         "*", "(", ")", "-", "_", "=", "+", "[", "{", "]", "}", ";", ":", "'", "\"", ",", "<", ".",
-        ">", "/", "?", "\\"
-    ];
-
-    // -----------------------------------------------------------------------------------------------------------------
-    // 한글 관련
-    // -----------------------------------------------------------------------------------------------------------------
-    /// 한글 자음(초성)
-    static ref KO_CONSONANTS: Vec<char> = vec![
-        'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
-        'ㅌ', 'ㅍ', 'ㅎ',
-    ];
-
-    /// 한글 자음 분해(된소리 포함)
-    static ref KO_SEPARATED_CONSONANTS: Vec<Vec<char>> = vec![
+        ">", "/", "?", "\\",
+    ]
+});
+
+/// [RANDOM_SOURCE]에서 [AMBIGUOUS_CHARACTERS]를 제외한 문자 집합
+static RANDOM_SOURCE_UNAMBIGUOUS: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    RANDOM_SOURCE
+        .iter()
+        .filter(|v| !AMBIGUOUS_CHARACTERS.contains(v))
+        .copied()
+        .collect()
+});
+
+/// [RANDOM_SOURCE_UNAMBIGUOUS]에서 숫자와 대문자만 포함하는 문자 집합
+static RANDOM_SOURCE_UNAMBIGUOUS_UPPER: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    RANDOM_SOURCE_UNAMBIGUOUS
+        .iter()
+        .filter(|v| !v.chars().any(|c| c.is_ascii_lowercase()))
+        .copied()
+        .collect()
+});
+
+// -----------------------------------------------------------------------------------------------------------------
+// 한글 관련
+// -----------------------------------------------------------------------------------------------------------------
+/// 한글 자음(초성)
+static KO_CONSONANTS: Lazy<Vec<char>> = Lazy::new(|| {
+    vec![
+        'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ',
+        'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+    ]
+});
+
+/// 한글 자음 분해(된소리 포함)
+static KO_SEPARATED_CONSONANTS: Lazy<Vec<Vec<char>>> = Lazy::new(|| {
+    vec![
         vec!['ㄱ'],
         vec!['ㄱ', 'ㄱ'],
         vec!['ㄴ'],
@@ -60,16 +153,20 @@ lazy_static! {
         vec!['ㅌ'],
         vec!['ㅍ'],
         vec!['ㅎ'],
-    ];
+    ]
+});
 
-    /// 한글 모음
-    static ref KO_VOWELS: Vec<char> = vec![
-        'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
-        'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
-    ];
+/// 한글 모음
+static KO_VOWELS: Lazy<Vec<char>> = Lazy::new(|| {
+    vec![
+        'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ',
+        'ㅞ', 'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+    ]
+});
 
-    /// 한글 모음 분해
-    static ref KO_SEPARATED_VOWELS: Vec<Vec<char>> = vec![
+/// 한글 모음 분해
+static KO_SEPARATED_VOWELS: Lazy<Vec<Vec<char>>> = Lazy::new(|| {
+    vec![
         vec!['ㅏ'],
         vec!['ㅐ'],
         vec!['ㅑ'],
@@ -91,16 +188,20 @@ lazy_static! {
         vec!['ㅡ'],
         vec!['ㅡ', 'ㅣ'],
         vec!['ㅣ'],
-    ];
+    ]
+});
 
-    /// 한글 받침
-    static ref KO_FINAL_CONSONANTS: Vec<char> = vec![
-        0 as char, 'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
-        'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
-    ];
+/// 한글 받침
+static KO_FINAL_CONSONANTS: Lazy<Vec<char>> = Lazy::new(|| {
+    vec![
+        0 as char, 'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ',
+        'ㄿ', 'ㅀ', 'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+    ]
+});
 
-    /// 한글 받침 분해
-    static ref KO_SEPARATED_FINAL_CONSONANTS: Vec<Vec<char>> = vec![
+/// 한글 받침 분해
+static KO_SEPARATED_FINAL_CONSONANTS: Lazy<Vec<Vec<char>>> = Lazy::new(|| {
+    vec![
         vec![],
         vec!['ㄱ'],
         vec!['ㄱ', 'ㄱ'],
@@ -129,10 +230,12 @@ lazy_static! {
         vec!['ㅌ'],
         vec!['ㅍ'],
         vec!['ㅎ'],
-    ];
+    ]
+});
 
-    /// 한글 쌍자음/이중 모음 분해
-    static ref KO_SEPARATED_FORTES_VOWELS: Vec<Vec<char>> = vec![
+/// 한글 쌍자음/이중 모음 분해
+static KO_SEPARATED_FORTES_VOWELS: Lazy<Vec<Vec<char>>> = Lazy::new(|| {
+    vec![
         vec!['ㄱ'],
         vec!['ㄱ', 'ㄱ'],
         vec!['ㄱ', 'ㅅ'],
@@ -184,17 +287,25 @@ lazy_static! {
         vec!['ㅡ'],
         vec!['ㅡ', 'ㅣ'],
         vec!['ㅣ'],
-    ];
-}
+    ]
+});
 
 /// 주어진 이메일 주소의 유효성 검사 결과를 반환한다.
 ///
+/// 형식 외에 로컬 파트 최대 64자, 전체 최대 254자 제한을 함께 검사한다.
+///
 /// 만약 대상 문자열이 `None`일 경우 [`MissingArgumentError`]를 반환한다.
 pub fn validate_email(target: Option<&str>) -> Result<bool, MissingArgumentError> {
     // TODO(joonho): 2023-10-03 한글 도메인 및 ID 포함
     match target {
         None => Err(MissingArgumentError::default()),
-        Some(v) => Ok(EMAIL_REGEX.is_match(v)),
+        Some(v) => {
+            let local_part_len = v.rfind('@').unwrap_or(v.len());
+
+            Ok(v.len() <= EMAIL_MAX
+                && local_part_len <= EMAIL_LOCAL_PART_MAX
+                && EMAIL_REGEX.is_match(v))
+        }
     }
 }
 
@@ -243,6 +354,80 @@ pub fn extract_initial_consonant(target: Option<&str>) -> Result<String, Missing
     }
 }
 
+/// 주어진 문자열에서 한글 중성만 추출.
+///
+/// 한글이 아닌 다른 문자(한자, 알파벳, 이모티콘, 특수 문자 등)는 그대로 반환한다. 이중 모음은 분리하지 않는다.
+///
+/// ```
+/// use cliff3_util::string_util::extract_medial_vowel;
+///
+/// let result = extract_medial_vowel(Some("강물, 과자")).unwrap();
+///
+/// assert_eq!("ㅏㅜ, ㅘㅏ", result.as_str());
+/// ```
+///
+/// # Arguments
+///
+/// - `target` 추출 대상 문자열
+///
+/// # Return
+///
+/// - 추출 결과. `Result<String, MissingArgumentError>`
+pub fn extract_medial_vowel(target: Option<&str>) -> Result<String, MissingArgumentError> {
+    match target {
+        None => Err(MissingArgumentError::default()),
+        Some(v) => Ok(v
+            .chars()
+            .map(|t| {
+                if ('가'..='힣').contains(&t) {
+                    KO_VOWELS[((t as u32 - '가' as u32) % 588 / 28) as usize]
+                } else {
+                    t
+                }
+            })
+            .collect()),
+    }
+}
+
+/// 주어진 문자열에서 한글 종성(받침)만 추출.
+///
+/// 받침이 없는 음절은 **생략**하며, 한글이 아닌 다른 문자(한자, 알파벳, 이모티콘, 특수 문자 등)는 그대로 반환한다.
+/// 겹받침은 분리하지 않는다.
+///
+/// ```
+/// use cliff3_util::string_util::extract_final_consonant;
+///
+/// let result = extract_final_consonant(Some("강물, 많이")).unwrap();
+///
+/// assert_eq!("ㅇㄹ, ㄶ", result.as_str());
+/// ```
+///
+/// # Arguments
+///
+/// - `target` 추출 대상 문자열
+///
+/// # Return
+///
+/// - 추출 결과. `Result<String, MissingArgumentError>`
+pub fn extract_final_consonant(target: Option<&str>) -> Result<String, MissingArgumentError> {
+    match target {
+        None => Err(MissingArgumentError::default()),
+        Some(v) => Ok(v
+            .chars()
+            .filter_map(|t| {
+                if ('가'..='힣').contains(&t) {
+                    match (t as u32 - '가' as u32) % 28 {
+                        0 => None,
+                        i => Some(KO_FINAL_CONSONANTS[i as usize]),
+                    }
+                } else {
+                    Some(t)
+                }
+            })
+            .collect()),
+    }
+}
+
 /// 주어진 문자열에서 한글을 초/중/종성으로 분리.
 ///
 /// 초성의 된소리, 중성의 이중모음 및 종성의 겹받침은 분리하지 않는다.
@@ -408,6 +593,90 @@ pub fn separate_consonant_vowel_completely(
     }
 }
 
+/// 한글 분리시 출력할 자모 집합
+///
+/// - `Compatibility` 호환 자모(U+3131 ~ U+318E). 단독으로 표시 가능한 자모 (e.g. `ㄱ`, `ㅏ`)
+/// - `Conjoining` 첫가끝 자모(U+1100 ~ U+11FF). 초성/중성/종성이 구분되며 조합형 표시가 가능
+///
+/// # Link
+///
+/// - [separate_consonant_vowel_with_mode]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum JamoMode {
+    /// 호환 자모 (기본값)
+    #[default]
+    Compatibility,
+
+    /// 첫가끝(조합형) 자모
+    Conjoining,
+}
+
+/// 주어진 문자열에서 한글을 지정된 [JamoMode]의 자모로 분리.
+///
+/// - [JamoMode::Compatibility] [separate_consonant_vowel_completely]와 동일하게 모든 자음/모음을 완전히 분리
+/// - [JamoMode::Conjoining] 초성은 U+1100, 중성은 U+1161, 종성은 U+11A8 계열로 변환한다. 실제 조합 가능한
+///   시퀀스를 유지하기 위해 된소리, 이중 모음 및 겹받침은 분해하지 않으며, 입력에 포함된 호환 자모는 그대로 반환한다.
+///
+/// ```
+/// use cliff3_util::string_util::{separate_consonant_vowel_with_mode, JamoMode};
+///
+/// let result = separate_consonant_vowel_with_mode(Some("한글"), JamoMode::Conjoining).unwrap();
+///
+/// assert_eq!("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}", result.as_str());
+///
+/// let result = separate_consonant_vowel_with_mode(Some("한글"), JamoMode::default()).unwrap();
+///
+/// assert_eq!("ㅎㅏㄴㄱㅡㄹ", result.as_str());
+/// ```
+///
+/// # Arguments
+///
+/// - `target` 추출 대상 문자열
+/// - `mode` 출력 자모 집합 [JamoMode]
+///
+/// # Return
+///
+/// - 추출 결과. `Result<String, MissingArgumentError>`
+pub fn separate_consonant_vowel_with_mode(
+    target: Option<&str>,
+    mode: JamoMode,
+) -> Result<String, MissingArgumentError> {
+    if mode == JamoMode::Compatibility {
+        return separate_consonant_vowel_completely(target);
+    }
+
+    match target {
+        None => Err(MissingArgumentError::default()),
+        Some(v) => {
+            let mut result = String::with_capacity(v.len() * 3);
+            let start = '가' as u32;
+
+            for t in v.chars() {
+                if ('가'..='힣').contains(&t) {
+                    let mut consonant = (t as u32) - start;
+
+                    // 초성
+                    result.push(char::from_u32(0x1100 + consonant / 588).unwrap());
+                    consonant %= 588;
+
+                    // 중성
+                    result.push(char::from_u32(0x1161 + consonant / 28).unwrap());
+                    consonant %= 28;
+
+                    if consonant != 0 {
+                        // 종성
+                        result.push(char::from_u32(0x11A7 + consonant).unwrap());
+                    }
+                } else {
+                    result.push(t);
+                }
+            }
+
+            Ok(result)
+        }
+    }
+}
+
 /// 대상 슬라이스를 16진수 형태 문자열로 반환.
 ///
 /// # Arguments
@@ -494,262 +763,6486 @@ pub fn generate_random_string_with_spec(length: u32) -> Option<String> {
     Some(result.join(""))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn validate_email_test() {
-        let mut email = "joonho.son@me.com";
-        let result = validate_email(Some(email));
-
-        assert!(!result.is_err());
-        assert!(
-            validate_email(Some(email)).unwrap(),
-            "정상적인 이메일 유효성 검사 실패"
-        );
+/// 혼동하기 쉬운 문자를 제외하고 지정된 길이만큼의 무작위 문자열을 생성
+///
+/// 사용자가 직접 읽고 입력해야 하는 인증 코드 등에 사용한다. 문자열 원본은 숫자와 알파벳 대/소문자에서
+/// [AMBIGUOUS_CHARACTERS](`0`, `O`, `o`, `1`, `l`, `I`)를 제외한 문자이다.
+///
+/// # Arguments
+///
+/// - `length` - 생성하고자 하는 문자열의 길이
+///
+/// # Return
+///
+/// - 생성된 문자열
+///
+/// # Link
+///
+/// - [generate_random_string_unambiguous_upper]
+pub fn generate_random_string_unambiguous(length: u32) -> Option<String> {
+    Some(generate_from_source(&RANDOM_SOURCE_UNAMBIGUOUS, length))
+}
 
-        email = "test@test";
+/// 혼동하기 쉬운 문자를 제외하고 숫자와 대문자만으로 지정된 길이만큼의 무작위 문자열을 생성
+///
+/// 대/소문자 구분이 어려운 환경(전화 안내, 수기 입력 등)을 위한 [generate_random_string_unambiguous]의 변형이다.
+///
+/// # Arguments
+///
+/// - `length` - 생성하고자 하는 문자열의 길이
+///
+/// # Return
+///
+/// - 생성된 문자열
+pub fn generate_random_string_unambiguous_upper(length: u32) -> Option<String> {
+    Some(generate_from_source(
+        &RANDOM_SOURCE_UNAMBIGUOUS_UPPER,
+        length,
+    ))
+}
 
-        assert!(!validate_email(Some(email)).unwrap());
+/// 지정된 문자열 원본에서 무작위로 `length`만큼 선택하여 문자열 생성
+fn generate_from_source(source: &[&str], length: u32) -> String {
+    let mut random = rand::thread_rng();
 
-        email = "test@test.";
+    (0..length)
+        .map(|_| source[random.gen_range(0..source.len())])
+        .collect()
+}
 
-        assert!(!validate_email(Some(email)).unwrap());
+/// 무작위 문자열 생성에 사용할 문자 집합
+///
+/// # Link
+///
+/// - [generate_grouped_key]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum CharsetOption {
+    /// 숫자와 알파벳 대/소문자 ([generate_random_string]과 동일)
+    #[default]
+    Alphanumeric,
 
-        email = "";
+    /// 숫자, 알파벳 대/소문자 및 특수문자 ([generate_random_string_with_spec]과 동일)
+    AlphanumericWithSpec,
 
-        assert!(!validate_email(Some(email)).unwrap());
+    /// 숫자와 알파벳 대/소문자에서 [AMBIGUOUS_CHARACTERS]를 제외 ([generate_random_string_unambiguous]와 동일)
+    Unambiguous,
 
-        assert!(validate_email(None).is_err());
+    /// 숫자와 대문자에서 [AMBIGUOUS_CHARACTERS]를 제외 ([generate_random_string_unambiguous_upper]와 동일)
+    UnambiguousUpper,
+}
 
-        // 반환되는 에러가 ValidateError인제 확인
-        assert_eq!(
-            validate_email(None).unwrap_err(),
-            MissingArgumentError::default(),
-            "에러 불일치"
-        );
+impl CharsetOption {
+    /// 문자 집합에 해당하는 문자열 원본 반환
+    fn source(&self) -> &'static [&'static str] {
+        match self {
+            CharsetOption::Alphanumeric => &RANDOM_SOURCE,
+            CharsetOption::AlphanumericWithSpec => &RANDOM_SOURCE_SPEC,
+            CharsetOption::Unambiguous => &RANDOM_SOURCE_UNAMBIGUOUS,
+            CharsetOption::UnambiguousUpper => &RANDOM_SOURCE_UNAMBIGUOUS_UPPER,
+        }
     }
+}
 
-    #[test]
-    #[should_panic]
-    fn invalid_email_should_panic_test() {
-        validate_email(None).unwrap();
+/// 그룹 단위로 구분된 무작위 키 생성 (e.g. 라이선스 키 `XXXX-XXXX-XXXX`)
+///
+/// `group_len` 길이의 무작위 문자열 `groups`개를 `separator`로 연결한다. 생성된 키의 전체 길이(문자 수)는
+/// `groups * group_len + (groups - 1)`이다.
+///
+/// # Arguments
+///
+/// - `groups` - 그룹 수
+/// - `group_len` - 그룹별 문자 수
+/// - `separator` - 그룹 구분자
+/// - `charset` - 사용할 문자 집합 ([CharsetOption])
+///
+/// # Return
+///
+/// - 생성된 키. `groups` 또는 `group_len`이 `0`일 경우 `None`
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{generate_grouped_key, CharsetOption};
+///
+/// let key = generate_grouped_key(3, 4, '-', CharsetOption::UnambiguousUpper).unwrap();
+///
+/// assert_eq!(14, key.len());
+/// assert_eq!(3, key.split('-').count());
+/// assert!(generate_grouped_key(0, 4, '-', CharsetOption::default()).is_none());
+/// ```
+pub fn generate_grouped_key(
+    groups: usize,
+    group_len: usize,
+    separator: char,
+    charset: CharsetOption,
+) -> Option<String> {
+    if groups == 0 || group_len == 0 {
+        return None;
     }
 
-    #[test]
-    fn korean_domain_fail_test() {
-        let mut email = "한글ID@test.com";
+    let source = charset.source();
+    let mut random = rand::thread_rng();
+    let mut result = String::with_capacity(groups * (group_len + separator.len_utf8()));
 
-        assert!(
-            !validate_email(Some(email)).is_err(),
-            "한글 ID를 포함하는 이메일 검사 실패"
-        );
+    for i in 0..groups {
+        if i > 0 {
+            result.push(separator);
+        }
 
-        email = "test@한글도메인.com";
+        for _ in 0..group_len {
+            result.push_str(source[random.gen_range(0..source.len())]);
+        }
+    }
 
-        assert!(
-            !validate_email(Some(email)).is_err(),
-            "한글 도메인을 포함하는 이메일 검사 실패"
-        );
+    Some(result)
+}
 
-        email = "홍길동@한글도메인.com";
+/// 주어진 문자열을 영문 제목 표기(Title Case) 형태로 변환
+///
+/// 공백(` `)으로 구분된 각 단어의 첫 글자를 대문자로 변환하며, 나머지 글자는 원본을 유지한다.
+/// 단, [TITLE_CASE_EXCEPTIONS]에 포함된 관사/전치사/접속사는 소문자로 변환한다.
+/// 첫 단어와 마지막 단어는 예외 목록에 포함되어도 항상 대문자로 시작한다.
+///
+/// 한글 등 대/소문자 구분이 없는 문자는 그대로 반환한다.
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::to_title_case;
+///
+/// assert_eq!("The Lord of the Rings", to_title_case("the lord of the rings"));
+/// assert_eq!("Of Mice and Men", to_title_case("of mice and men"));
+/// ```
+pub fn to_title_case(target: &str) -> String {
+    let words: Vec<&str> = target.split(' ').collect();
+    let first = words.iter().position(|w| !w.is_empty());
+    let last = words.iter().rposition(|w| !w.is_empty());
 
-        assert!(
-            !validate_email(Some(email)).is_err(),
-            "한글 ID 및 한글 도메인을 포함하는 이메일 검사 실패"
-        );
-    }
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, w)| {
+            let lower = w.to_lowercase();
 
-    #[test]
-    fn extract_initial_consonant_test() {
-        let mut target = "한글만 있습니다.";
-        let mut result = extract_initial_consonant(Some(target)).unwrap();
+            if Some(i) != first
+                && Some(i) != last
+                && TITLE_CASE_EXCEPTIONS.contains(&lower.as_str())
+            {
+                return lower;
+            }
 
-        println!("extract result : {}", result);
+            let mut chars = w.chars();
 
-        assert_eq!(
-            "ㅎㄱㅁ ㅇㅅㄴㄷ.",
-            result.as_str(),
-            "한글만 있을 경우 초성 추출 실패"
-        );
+            match chars.next() {
+                None => String::new(),
+                Some(c) => c.to_uppercase().chain(chars).collect(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
 
-        target = "한글과 English가 함께 있습니다.";
-        result = extract_initial_consonant(Some(target)).unwrap();
+/// 주어진 숫자를 base62(`0-9a-zA-Z`) 문자열로 인코딩
+///
+/// URL 단축이나 짧은 공유 코드 생성에 사용할 수 있다. `0`은 `"0"`으로 인코딩된다.
+///
+/// # Arguments
+///
+/// - `num` - 인코딩 대상 숫자
+///
+/// # Return
+///
+/// - 인코딩 결과
+///
+/// # Link
+///
+/// - [decode_base62]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{decode_base62, encode_base62};
+///
+/// let encoded = encode_base62(1_234_567_890);
+///
+/// assert_eq!("1ly7vk", encoded);
+/// assert_eq!(1_234_567_890, decode_base62(&encoded).unwrap());
+/// ```
+pub fn encode_base62(num: u64) -> String {
+    if num == 0 {
+        return "0".to_owned();
+    }
 
-        println!("extract result : {}", result);
+    let mut value = num;
+    let mut result: Vec<u8> = Vec::with_capacity(11); // u64::MAX는 11자리
 
-        assert_eq!(
-            "ㅎㄱㄱ Englishㄱ ㅎㄲ ㅇㅅㄴㄷ.",
-            result.as_str(),
-            "한글과 영어가 혼재되어 있을 경우 추출 실패"
-        );
+    while value > 0 {
+        result.push(BASE62_ALPHABET[(value % 62) as usize]);
+        value /= 62;
+    }
 
-        target = "세종대왕(世宗大王)";
-        result = extract_initial_consonant(Some(target)).unwrap();
+    result.reverse();
 
-        println!("extract result : {}", result);
+    String::from_utf8(result).unwrap()
+}
+
+/// base62(`0-9a-zA-Z`) 문자열을 숫자로 디코딩
+///
+/// # Arguments
+///
+/// - `s` - [encode_base62]로 인코딩된 문자열
+///
+/// # Return
+///
+/// - 디코딩 결과 `Result<u64, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 빈 문자열, 허용되지 않는 문자 포함 혹은 `u64` 범위 초과
+///
+/// # Link
+///
+/// - [encode_base62]
+pub fn decode_base62(s: &str) -> Result<u64, InvalidArgumentError> {
+    if s.is_empty() {
+        return Err(InvalidArgumentError::from(
+            "디코딩 대상이 빈 문자열 입니다.",
+        ));
+    }
+
+    let mut result: u64 = 0;
+
+    for c in s.bytes() {
+        let digit = match BASE62_ALPHABET.iter().position(|b| *b == c) {
+            Some(v) => v as u64,
+            None => {
+                return Err(InvalidArgumentError::new(
+                    format!("base62 문자가 아닙니다 : {}", c as char).as_str(),
+                ))
+            }
+        };
+
+        result = result
+            .checked_mul(62)
+            .and_then(|v| v.checked_add(digit))
+            .ok_or_else(|| InvalidArgumentError::from("u64 범위를 초과하였습니다."))?;
+    }
+
+    Ok(result)
+}
+
+/// 바이트 배열을 Base32(RFC 4648, `A-Z2-7`) 문자열로 인코딩
+///
+/// 결과 길이가 8의 배수가 되도록 `=` padding을 추가한다. padding 없이 인코딩하려면
+/// [encode_base32_with_option]을 사용한다.
+///
+/// # Arguments
+///
+/// - `data` - 인코딩 대상
+///
+/// # Return
+///
+/// - 인코딩 결과. 빈 배열은 빈 문자열
+///
+/// # Link
+///
+/// - [encode_base32_with_option]
+/// - [decode_base32]
+///
+/// # Example
+///
+/// TOTP 시크릿을 Base32로 인코딩(인증 앱 등록용 값은 보통 padding을 생략한다)
+///
+/// ```rust
+/// use cliff3_util::string_util::{decode_base32, encode_base32, encode_base32_with_option};
+///
+/// let secret = b"12345678901234567890"; // RFC 6238 테스트 시크릿
+///
+/// assert_eq!("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ", encode_base32(secret));
+/// assert_eq!("MZXW6YQ=", encode_base32(b"foob"));
+/// assert_eq!("MZXW6YQ", encode_base32_with_option(b"foob", false));
+/// assert_eq!(secret.to_vec(), decode_base32("gezdgnbvgy3tqojqgezdgnbvgy3tqojq").unwrap());
+/// ```
+pub fn encode_base32(data: &[u8]) -> String {
+    encode_base32_with_option(data, true)
+}
+
+/// 바이트 배열을 Base32(RFC 4648, `A-Z2-7`) 문자열로 인코딩
+///
+/// # Arguments
+///
+/// - `data` - 인코딩 대상
+/// - `padding` - 결과 길이가 8의 배수가 되도록 `=` padding 추가 여부
+///
+/// # Return
+///
+/// - 인코딩 결과. 빈 배열은 빈 문자열
+///
+/// # Link
+///
+/// - [encode_base32]
+/// - [decode_base32]
+pub fn encode_base32_with_option(data: &[u8], padding: bool) -> String {
+    let mut result = String::with_capacity(data.len().div_ceil(5) * 8);
+
+    for chunk in data.chunks(5) {
+        let mut block = [0u8; 5];
+
+        block[..chunk.len()].copy_from_slice(chunk);
+
+        let value = block.iter().fold(0u64, |acc, v| (acc << 8) | *v as u64);
+        let char_count = (chunk.len() * 8).div_ceil(5);
+
+        for i in 0..char_count {
+            let index = (value >> (35 - i * 5)) & 0x1f;
+
+            result.push(BASE32_ALPHABET[index as usize] as char);
+        }
+
+        if padding {
+            (char_count..8).for_each(|_| result.push('='));
+        }
+    }
+
+    result
+}
+
+/// Base32(RFC 4648, `A-Z2-7`) 문자열을 바이트 배열로 디코딩
+///
+/// 대소문자를 구분하지 않으며, `=` padding은 있어도 되고 없어도 된다. 단, padding이 있을 경우 전체 길이는
+/// 8의 배수여야 하고 padding 뒤에 다른 문자가 올 수 없다.
+///
+/// # Arguments
+///
+/// - `s` - Base32 문자열
+///
+/// # Return
+///
+/// - 디코딩 결과 `Result<Vec<u8>, InvalidArgumentError>`. 빈 문자열은 빈 배열
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - Base32 알파벳이 아닌 문자 포함, 잘못된 padding 혹은 유효하지 않은 길이
+///
+/// # Link
+///
+/// - [encode_base32]
+pub fn decode_base32(s: &str) -> Result<Vec<u8>, InvalidArgumentError> {
+    let data = s.trim_end_matches('=');
+    let pad_len = s.len() - data.len();
+
+    if pad_len > 0 && (!s.len().is_multiple_of(8) || pad_len > 6) {
+        return Err(InvalidArgumentError::from("잘못된 base32 padding 입니다."));
+    }
+
+    // 마지막 블록의 문자 수가 1, 3, 6이면 온전한 바이트를 구성할 수 없음
+    if matches!(data.len() % 8, 1 | 3 | 6) {
+        return Err(InvalidArgumentError::new(
+            format!("유효하지 않은 base32 길이 입니다 : {}", data.len()).as_str(),
+        ));
+    }
+
+    let mut result: Vec<u8> = Vec::with_capacity(data.len() * 5 / 8);
+    let mut buffer: u64 = 0;
+    let mut bits: u32 = 0;
+
+    for c in data.chars() {
+        let upper = c.to_ascii_uppercase();
+        let digit = match BASE32_ALPHABET.iter().position(|b| *b as char == upper) {
+            Some(v) => v as u64,
+            None => {
+                return Err(InvalidArgumentError::new(
+                    format!("base32 문자가 아닙니다 : {}", c).as_str(),
+                ))
+            }
+        };
+
+        buffer = (buffer << 5) | digit;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Ok(result)
+}
+
+/// 대상 문자열을 지정된 횟수만큼 반복한 문자열 반환
+///
+/// [str::repeat]과 달리 결과의 byte 길이가 `max_bytes`를 초과하거나 길이 계산 중 overflow가 발생할 경우
+/// 할당 전에 오류를 반환한다. 외부 입력으로 반복 횟수를 받는 경우 과도한 메모리 할당을 방지할 수 있다.
+///
+/// # Arguments
+///
+/// - `target` - 반복 대상 문자열
+/// - `count` - 반복 횟수
+/// - `max_bytes` - 결과 문자열의 최대 byte 길이
+///
+/// # Return
+///
+/// - 반복 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 결과 byte 길이가 `max_bytes`를 초과할 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::safe_repeat;
+///
+/// assert_eq!("====", safe_repeat("=", 4, 10).unwrap());
+/// assert!(safe_repeat("한글", 2, 11).is_err()); // 12 bytes
+/// ```
+pub fn safe_repeat(
+    target: &str,
+    count: usize,
+    max_bytes: usize,
+) -> Result<String, InvalidArgumentError> {
+    match target.len().checked_mul(count) {
+        Some(v) if v <= max_bytes => Ok(target.repeat(count)),
+        _ => Err(InvalidArgumentError::new(
+            format!("반복 결과가 최대 길이({} bytes)를 초과합니다.", max_bytes).as_str(),
+        )),
+    }
+}
+
+/// 문자열의 화면 표시폭 반환
+///
+/// 터미널이나 고정폭 글꼴로 출력할 때의 열(column) 수를 계산한다.
+///
+/// - 한글 등 CJK 전각 문자와 이모지는 `2`
+/// - 일반 문자는 `1`
+/// - 제어 문자(`\n`, `\t` 등)와 결합 문자는 `0`
+///
+/// 이모지 ZWJ 시퀀스(e.g. `👨‍👩‍👧`)는 하나의 이모지(`2`)로 계산하지만, 실제 표시폭은 터미널과 글꼴의
+/// 지원 여부에 따라 달라질 수 있다. 또한 폭이 모호한(ambiguous) 문자는 `1`로 계산한다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 표시폭
+///
+/// # Link
+///
+/// - [unicode_width::UnicodeWidthStr::width]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::display_width;
+///
+/// assert_eq!(7, display_width("한글ABC"));
+/// ```
+pub fn display_width(target: &str) -> usize {
+    if target.chars().any(|c| c.is_control()) {
+        return target
+            .chars()
+            .filter(|c| !c.is_control())
+            .collect::<String>()
+            .width();
+    }
+
+    target.width()
+}
+
+/// 문자열의 각 줄에 `f`를 적용한 결과를 반환
+///
+/// `f`가 `None`을 반환한 줄은 제거하며, 나머지 줄은 개행 문자로 다시 연결한다.
+///
+/// - 개행 문자는 `\n`과 `\r\n`을 모두 인식하며, 결과는 `\n`으로 정규화한다.
+/// - 입력이 개행 문자로 끝날 경우 결과도 개행 문자로 끝난다(남은 줄이 없을 경우 제외).
+/// - 빈 입력은 빈 문자열을 반환한다.
+///
+/// # Arguments
+///
+/// - `input` - 대상 문자열
+/// - `f` - 각 줄에 적용할 closure
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// 각 줄의 공백 제거 후 빈 줄 제거
+///
+/// ```rust
+/// use cliff3_util::string_util::transform_lines;
+///
+/// let input = "  first  \r\n\n   \n second\n";
+/// let result = transform_lines(input, |l| Some(l.trim()).filter(|v| !v.is_empty()).map(String::from));
+///
+/// assert_eq!("first\nsecond\n", result);
+/// ```
+pub fn transform_lines<F: Fn(&str) -> Option<String>>(input: &str, f: F) -> String {
+    let lines: Vec<String> = input.lines().filter_map(f).collect();
+
+    if lines.is_empty() {
+        return String::new();
+    }
+
+    let mut result = lines.join("\n");
+
+    if input.ends_with('\n') {
+        result.push('\n');
+    }
+
+    result
+}
+
+/// 한글 숫자 (`영`은 0 단독 표기에만 사용)
+const KO_NUMBERS: [char; 10] = ['영', '일', '이', '삼', '사', '오', '육', '칠', '팔', '구'];
+
+/// 한글 숫자 단위(십/백/천)
+const KO_SMALL_UNITS: [&str; 4] = ["", "십", "백", "천"];
+
+/// 한글 숫자 단위(만/억/조/경). `u64` 최대값은 `경` 단위 이내이다.
+const KO_LARGE_UNITS: [&str; 5] = ["", "만", "억", "조", "경"];
+
+/// 숫자를 한글 표기로 변환
+///
+/// 다음 규칙을 따른다.
+///
+/// - `0`은 `영`으로 표기한다.
+/// - `십`, `백`, `천` 앞의 `일`은 생략한다. (`10` -> `십`, `11` -> `십일`, `1100` -> `천백`)
+/// - `만` 앞의 `일`은 생략하며(`10000` -> `만`), `억`, `조`, `경` 앞의 `일`은 생략하지 않는다(`100000000` -> `일억`).
+/// - 단위 사이에 공백을 넣지 않는다.
+///
+/// # Arguments
+///
+/// - `num` - 변환 대상 숫자
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Link
+///
+/// - [korean_to_number]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number_to_korean;
+///
+/// assert_eq!("천이백삼십사", number_to_korean(1234));
+/// assert_eq!("삼백이십만", number_to_korean(3_200_000));
+/// assert_eq!("일억만십", number_to_korean(100_010_010));
+/// ```
+pub fn number_to_korean(num: u64) -> String {
+    if num == 0 {
+        return KO_NUMBERS[0].to_string();
+    }
+
+    let mut groups: Vec<String> = vec![];
+    let mut value = num;
+    let mut large_unit = 0;
+
+    while value > 0 {
+        let group = (value % 10_000) as usize;
+
+        if group > 0 {
+            let mut text = String::new();
+
+            // 만 앞의 일은 생략
+            if !(large_unit == 1 && group == 1) {
+                for (pos, unit) in KO_SMALL_UNITS.iter().enumerate().rev() {
+                    let digit = group / 10usize.pow(pos as u32) % 10;
+
+                    if digit == 0 {
+                        continue;
+                    }
+
+                    // 십/백/천 앞의 일은 생략
+                    if !(digit == 1 && pos > 0) {
+                        text.push(KO_NUMBERS[digit]);
+                    }
+
+                    text += unit;
+                }
+            }
+
+            text += KO_LARGE_UNITS[large_unit];
+            groups.push(text);
+        }
+
+        value /= 10_000;
+        large_unit += 1;
+    }
+
+    groups.reverse();
+    groups.join("")
+}
+
+/// 한글로 표기된 숫자를 변환
+///
+/// [number_to_korean]의 역변환이며 `일십`, `일만`과 같이 `일`을 생략하지 않은 표기와 공백도 허용한다.
+///
+/// # Arguments
+///
+/// - `s` - 한글 숫자 문자열 (e.g. `천이백삼십사`)
+///
+/// # Return
+///
+/// - 변환 결과 `Result<u64, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 빈 문자열, 한글 숫자가 아닌 문자 포함, 단위 순서 오류 혹은 `u64` 범위 초과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::korean_to_number;
+///
+/// assert_eq!(1234, korean_to_number("천이백삼십사").unwrap());
+/// assert_eq!(3_200_000, korean_to_number("삼백이십만").unwrap());
+/// assert!(korean_to_number("백십만원").is_err());
+/// ```
+pub fn korean_to_number(s: &str) -> Result<u64, InvalidArgumentError> {
+    let invalid =
+        || InvalidArgumentError::new(format!("한글 숫자 형식이 아닙니다 : {}", s).as_str());
+    let overflow = || InvalidArgumentError::from("u64 범위를 초과하였습니다.");
+    let chars: Vec<char> = s.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if chars.is_empty() {
+        return Err(invalid());
+    }
+
+    if chars == [KO_NUMBERS[0]] {
+        return Ok(0);
+    }
+
+    let mut total: u64 = 0;
+    let mut section: u64 = 0; // 만 미만 단위 합계
+    let mut digit: Option<u64> = None;
+    let mut last_small_unit = usize::MAX;
+    let mut last_large_unit = usize::MAX;
+
+    for c in chars {
+        if let Some(d) = KO_NUMBERS.iter().skip(1).position(|v| *v == c) {
+            if digit.is_some() {
+                return Err(invalid());
+            }
+
+            digit = Some(d as u64 + 1);
+        } else if let Some(pos) = KO_SMALL_UNITS.iter().skip(1).position(|v| v.starts_with(c)) {
+            let pos = pos + 1;
+
+            if pos >= last_small_unit {
+                return Err(invalid());
+            }
+
+            section += digit.take().unwrap_or(1) * 10u64.pow(pos as u32);
+            last_small_unit = pos;
+        } else if let Some(pos) = KO_LARGE_UNITS.iter().skip(1).position(|v| v.starts_with(c)) {
+            let pos = pos + 1;
+
+            if pos >= last_large_unit {
+                return Err(invalid());
+            }
+
+            section += digit.take().unwrap_or(0);
+
+            let value = if section == 0 { 1 } else { section };
+
+            total = value
+                .checked_mul(10_000u64.pow(pos as u32))
+                .and_then(|v| v.checked_add(total))
+                .ok_or_else(overflow)?;
+            section = 0;
+            last_small_unit = usize::MAX;
+            last_large_unit = pos;
+        } else {
+            return Err(invalid());
+        }
+    }
+
+    total
+        .checked_add(section + digit.unwrap_or(0))
+        .ok_or_else(overflow)
+}
+
+/// 두 문자열 간 레벤슈타인(편집) 거리 계산
+///
+/// 삽입/삭제/치환을 각 1회로 계산하며, byte가 아닌 `char` 단위로 비교한다.
+///
+/// # Arguments
+///
+/// - `a` - 비교 대상 문자열
+/// - `b` - 비교 대상 문자열
+///
+/// # Return
+///
+/// - 편집 거리
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::levenshtein_distance;
+///
+/// assert_eq!(3, levenshtein_distance("kitten", "sitting"));
+/// assert_eq!(1, levenshtein_distance("한글", "한국"));
+/// ```
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+
+            current[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut prev, &mut current);
+    }
+
+    prev[b.len()]
+}
+
+/// 두 문자열의 유사도를 `0.0 ~ 1.0` 범위로 반환
+///
+/// `1.0 - (편집 거리 / max(a 문자 수, b 문자 수))`로 계산하며, 두 문자열이 모두 비어 있을 경우 `1.0`을 반환한다.
+///
+/// # Arguments
+///
+/// - `a` - 비교 대상 문자열
+/// - `b` - 비교 대상 문자열
+///
+/// # Return
+///
+/// - 유사도 (`1.0` : 동일, `0.0` : 완전히 다름)
+///
+/// # Link
+///
+/// - [levenshtein_distance]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::similarity_ratio;
+///
+/// assert_eq!(1.0, similarity_ratio("한글", "한글"));
+/// assert_eq!(0.5, similarity_ratio("한글", "한국"));
+/// ```
+pub fn similarity_ratio(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    1.0 - (levenshtein_distance(a, b) as f64 / max_len as f64)
+}
+
+/// 자주 사용되어 강도와 관계없이 [StrengthLevel::Weak]로 평가하는 비밀번호 목록
+const COMMON_PASSWORDS: &[&str] = &[
+    "password",
+    "passw0rd",
+    "p@ssw0rd",
+    "123456",
+    "12345678",
+    "123456789",
+    "1234567890",
+    "qwerty",
+    "qwerty123",
+    "abc123",
+    "111111",
+    "000000",
+    "iloveyou",
+    "admin",
+    "letmein",
+    "welcome",
+];
+
+/// 비밀번호 강도 등급
+///
+/// # Link
+///
+/// - [password_strength]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum StrengthLevel {
+    /// 점수 40 미만
+    Weak,
+
+    /// 점수 40 이상 70 미만
+    Fair,
+
+    /// 점수 70 이상
+    Strong,
+}
+
+/// 비밀번호 강도 평가 결과
+///
+/// # Link
+///
+/// - [password_strength]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct PasswordStrength {
+    /// 점수 (`0 ~ 100`)
+    pub score: u8,
+
+    /// 점수에 따른 등급
+    pub level: StrengthLevel,
+}
+
+/// 비밀번호 강도 평가
+///
+/// 다음 규칙으로 `0 ~ 100` 범위의 점수를 계산한다.
+///
+/// - 길이 : 문자 1개당 4점 (최대 40점). 한글은 [separate_consonant_vowel_completely]로 자모 분해한 길이를 기준으로 한다.
+/// - 문자 종류 : 영문 소문자/영문 대문자/숫자/특수 문자/한글 중 포함된 종류 1개당 12점
+/// - 반복 : 같은 문자가 연속될 경우 1회당 8점 감점 (e.g. `aaa`, `가가`)
+/// - 연속 : 영문/숫자가 순서대로 이어질 경우 1회당 5점 감점 (e.g. `abc`, `321`)
+/// - 자주 사용되는 비밀번호(`password`, `123456` 등)는 점수와 관계없이 [StrengthLevel::Weak]로 평가
+///
+/// # Arguments
+///
+/// - `password` - 평가 대상 비밀번호
+///
+/// # Return
+///
+/// - 평가 결과 [PasswordStrength]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{password_strength, StrengthLevel};
+///
+/// assert_eq!(StrengthLevel::Weak, password_strength("password").level);
+/// assert_eq!(StrengthLevel::Strong, password_strength("aX9!mQ2#zL").level);
+/// ```
+pub fn password_strength(password: &str) -> PasswordStrength {
+    let chars: Vec<char> = password.chars().collect();
+    let separated = separate_consonant_vowel_completely(Some(password)).unwrap();
+    let mut kinds = [false; 5]; // 소문자, 대문자, 숫자, 특수 문자, 한글
+
+    for c in chars.iter() {
+        let index = match c {
+            'a'..='z' => 0,
+            'A'..='Z' => 1,
+            '0'..='9' => 2,
+            '가'..='힣' | 'ㄱ'..='ㅣ' => 4,
+            _ => 3,
+        };
+
+        kinds[index] = true;
+    }
+
+    let mut score = (separated.chars().count() as i32 * 4).min(40);
+
+    score += kinds.iter().filter(|v| **v).count() as i32 * 12;
+
+    for pair in chars.windows(2) {
+        if pair[0] == pair[1] {
+            score -= 8;
+        } else if pair[0].is_ascii_alphanumeric()
+            && pair[1].is_ascii_alphanumeric()
+            && (pair[0] as i32 - pair[1] as i32).abs() == 1
+        {
+            score -= 5;
+        }
+    }
+
+    let score = score.clamp(0, 100) as u8;
+    let common = COMMON_PASSWORDS.contains(&password.to_lowercase().as_str());
+    let level = match score {
+        _ if common => StrengthLevel::Weak,
+        0..=39 => StrengthLevel::Weak,
+        40..=69 => StrengthLevel::Fair,
+        _ => StrengthLevel::Strong,
+    };
+
+    PasswordStrength { score, level }
+}
+
+/// 문자열의 ASCII 알파벳을 지정된 값만큼 이동(Caesar cipher)
+///
+/// **보안 목적으로 사용해서는 안 된다.** 교육/게임 등의 단순 치환 용도로만 사용한다.
+///
+/// - ASCII 알파벳(`a-z`, `A-Z`)만 대/소문자를 유지한 채 이동하며, 숫자/한글/기호 등은 그대로 둔다.
+/// - `shift`는 음수 및 26 이상의 값도 26으로 나눈 나머지로 정규화한다. (`-1` == `25`, `27` == `1`)
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+/// - `shift` - 이동할 값
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Link
+///
+/// - [rot13]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::caesar_cipher;
+///
+/// assert_eq!("Def, 한글 123", caesar_cipher("Abc, 한글 123", 3));
+/// assert_eq!("Zab", caesar_cipher("Abc", -1));
+/// ```
+pub fn caesar_cipher(target: &str, shift: i32) -> String {
+    let shift = shift.rem_euclid(26) as u8;
+
+    target
+        .chars()
+        .map(|c| {
+            let base = match c {
+                'a'..='z' => b'a',
+                'A'..='Z' => b'A',
+                _ => return c,
+            };
+
+            (base + (c as u8 - base + shift) % 26) as char
+        })
+        .collect()
+}
+
+/// 문자열의 ASCII 알파벳을 13만큼 이동(ROT13)
+///
+/// **보안 목적으로 사용해서는 안 된다.** 두 번 적용하면 원문이 된다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Link
+///
+/// - [caesar_cipher]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::rot13;
+///
+/// assert_eq!("Uryyb, 세계!", rot13("Hello, 세계!"));
+/// assert_eq!("Hello, 세계!", rot13(&rot13("Hello, 세계!")));
+/// ```
+pub fn rot13(target: &str) -> String {
+    caesar_cipher(target, 13)
+}
+
+/// 정규식 검색 결과를 수집. `dedup`이 `true`일 경우 처음 나온 순서를 유지하며 중복을 제거한다.
+fn collect_matches<'a>(matches: impl Iterator<Item = &'a str>, dedup: bool) -> Vec<String> {
+    let mut result: Vec<String> = vec![];
+
+    for v in matches {
+        if !dedup || !result.iter().any(|r| r == v) {
+            result.push(v.to_owned());
+        }
+    }
+
+    result
+}
+
+/// 문자열에서 이메일 주소를 모두 추출
+///
+/// [validate_email]과 동일한 패턴을 사용한다. 이메일 뒤에 붙은 문장 부호(`.`, `,` 등)나 한글 조사(e.g. `으로`)는
+/// 포함하지 않는다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `dedup` - 중복 제거 여부. 제거할 경우 처음 나온 순서를 유지한다.
+///
+/// # Return
+///
+/// - 추출된 이메일 목록
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::extract_emails;
+///
+/// let text = "문의: help@example.com, sales@example.co.kr. 재문의: help@example.com";
+///
+/// assert_eq!(vec!["help@example.com", "sales@example.co.kr", "help@example.com"], extract_emails(text, false));
+/// assert_eq!(vec!["help@example.com", "sales@example.co.kr"], extract_emails(text, true));
+/// ```
+pub fn extract_emails(text: &str, dedup: bool) -> Vec<String> {
+    collect_matches(EMAIL_FIND_REGEX.find_iter(text).map(|m| m.as_str()), dedup)
+}
+
+/// 문자열에서 `http`/`https` URL을 모두 추출
+///
+/// 경계는 다음과 같이 처리한다.
+///
+/// - 공백, 따옴표, 괄호(`()`, `[]`, `{}`), 꺾쇠(`<>`)를 만나면 URL이 끝난 것으로 본다.
+/// - URL 끝에 붙은 문장 부호(`.`, `,`, `;`, `:`, `!`, `?`)는 제외한다. (`https://x.com.` -> `https://x.com`)
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `dedup` - 중복 제거 여부. 제거할 경우 처음 나온 순서를 유지한다.
+///
+/// # Return
+///
+/// - 추출된 URL 목록
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::extract_urls;
+///
+/// let text = "참고: https://x.com/a?b=1. (자세한 내용은 http://y.kr/docs)";
+///
+/// assert_eq!(vec!["https://x.com/a?b=1", "http://y.kr/docs"], extract_urls(text, false));
+/// ```
+pub fn extract_urls(text: &str, dedup: bool) -> Vec<String> {
+    let matches = URL_FIND_REGEX
+        .find_iter(text)
+        .map(|m| m.as_str().trim_end_matches(['.', ',', ';', ':', '!', '?']))
+        .filter(|v| !v.ends_with("://"));
+
+    collect_matches(matches, dedup)
+}
+
+/// 관대한 숫자 파싱을 위해 문자열을 정리하여 숫자 부분만 반환
+///
+/// - 앞뒤 공백 제거, 전각 숫자/부호(`０-９`, `＋`, `－`, `，`, `．`)를 반각으로 변환
+/// - 숫자 뒤의 단위 문자열(e.g. `원`, `kg`)은 버린다. 단, 단위 문자열에 숫자가 포함되면 실패
+/// - 천 단위 콤마(`,`) 제거
+fn normalize_lenient_number(s: &str, allow_decimal: bool) -> Option<String> {
+    let normalized: String = s
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '０'..='９' | '＋' | '－' | '，' | '．' => {
+                char::from_u32(c as u32 - 0xFEE0).unwrap_or(c)
+            }
+            _ => c,
+        })
+        .collect();
+    let is_number_char = |c: char| {
+        c.is_ascii_digit() || c == ',' || c == '+' || c == '-' || (allow_decimal && c == '.')
+    };
+    let split = normalized
+        .find(|c: char| !is_number_char(c))
+        .unwrap_or(normalized.len());
+    let (number, unit) = normalized.split_at(split);
+
+    if number.starts_with(',') || unit.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(number.replace(',', ""))
+}
+
+/// 사용자 입력 문자열을 관대하게 정수로 파싱
+///
+/// 다음 순서로 정리한 후 파싱한다.
+///
+/// 1. 앞뒤 공백 제거 (`" 42 "` -> `42`)
+/// 1. 전각 숫자 및 부호를 반각으로 변환 (`"３"` -> `3`)
+/// 1. 숫자 뒤의 단위 문자열 제거 (`"300원"` -> `300`). 단위 문자열에 숫자가 포함되면 실패
+/// 1. 천 단위 콤마 제거 (`"1,234"` -> `1234`)
+///
+/// 소수점이 포함된 경우(`"1.5원"`)는 정수가 아니므로 실패한다.
+///
+/// # Arguments
+///
+/// - `s` - 대상 문자열
+///
+/// # Return
+///
+/// - 파싱 결과 `Result<i64, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 파싱 실패. 메시지에 원본 입력을 포함한다.
+///
+/// # Link
+///
+/// - [parse_lenient_f64]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::parse_lenient_i64;
+///
+/// assert_eq!(1234, parse_lenient_i64("1,234").unwrap());
+/// assert_eq!(42, parse_lenient_i64(" 42 ").unwrap());
+/// assert_eq!(3, parse_lenient_i64("３").unwrap());
+/// assert_eq!(-5000, parse_lenient_i64("-5,000원").unwrap());
+/// assert!(parse_lenient_i64("1.5원").is_err());
+/// ```
+pub fn parse_lenient_i64(s: &str) -> Result<i64, InvalidArgumentError> {
+    normalize_lenient_number(s, false)
+        .and_then(|v| v.parse::<i64>().ok())
+        .ok_or_else(|| {
+            InvalidArgumentError::new(format!("정수로 변환할 수 없습니다 : {:?}", s).as_str())
+        })
+}
+
+/// 사용자 입력 문자열을 관대하게 실수로 파싱
+///
+/// [parse_lenient_i64]와 동일한 규칙으로 정리하며 소수점을 허용한다. 지수 표기(`1e3`)는 지원하지 않는다.
+///
+/// # Arguments
+///
+/// - `s` - 대상 문자열
+///
+/// # Return
+///
+/// - 파싱 결과 `Result<f64, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 파싱 실패. 메시지에 원본 입력을 포함한다.
+///
+/// # Link
+///
+/// - [parse_lenient_i64]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::parse_lenient_f64;
+///
+/// assert_eq!(1.5, parse_lenient_f64("1.5원").unwrap());
+/// assert_eq!(1234.5, parse_lenient_f64(" 1,234.5 kg").unwrap());
+/// ```
+pub fn parse_lenient_f64(s: &str) -> Result<f64, InvalidArgumentError> {
+    normalize_lenient_number(s, true)
+        .and_then(|v| v.parse::<f64>().ok())
+        .ok_or_else(|| {
+            InvalidArgumentError::new(format!("실수로 변환할 수 없습니다 : {:?}", s).as_str())
+        })
+}
+
+/// 문자열 통계
+///
+/// # Link
+///
+/// - [text_stats]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct TextStats {
+    /// UTF-8 byte 수
+    pub bytes: usize,
+
+    /// 문자(`char`) 수
+    pub chars: usize,
+
+    /// 공백(유니코드 공백 포함)으로 구분한 단어 수
+    pub words: usize,
+
+    /// 줄 수
+    pub lines: usize,
+
+    /// 완성형 한글 음절(`가` ~ `힣`) 수
+    pub hangul_syllables: usize,
+}
+
+/// 문자열의 byte/문자/단어/줄/한글 음절 수를 한 번에 집계
+///
+/// - 단어는 [char::is_whitespace] 기준으로 구분한다.
+/// - 줄 수는 [str::lines] 기준이다. 빈 문자열은 `0`줄이며, 마지막 개행 뒤에 내용이 없으면 줄로 세지 않는다.
+///   (`"a"` -> 1, `"a\n"` -> 1, `"a\nb"` -> 2, `"\n"` -> 1)
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 집계 결과 [TextStats]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::text_stats;
+///
+/// let stats = text_stats("안녕 hello\n😊");
+///
+/// assert_eq!(17, stats.bytes);
+/// assert_eq!(10, stats.chars);
+/// assert_eq!(3, stats.words);
+/// assert_eq!(2, stats.lines);
+/// assert_eq!(2, stats.hangul_syllables);
+/// ```
+pub fn text_stats(target: &str) -> TextStats {
+    TextStats {
+        bytes: target.len(),
+        chars: target.chars().count(),
+        words: target.split_whitespace().count(),
+        lines: target.lines().count(),
+        hangul_syllables: target.chars().filter(|c| ('가'..='힣').contains(c)).count(),
+    }
+}
+
+/// 앞/뒤 일부만 노출하고 가운데를 고정된 마스크 문자열로 대체
+///
+/// 가운데 글자 수와 관계없이 항상 `mask`로 대체하므로 원본의 길이가 노출되지 않는다. 글자 수는 `char` 기준이다.
+/// 대상의 글자 수가 `visible_prefix + visible_suffix` 이하일 경우 원본이 모두 노출되지 않도록 `mask`만 반환한다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+/// - `visible_prefix` - 앞쪽 노출 글자 수
+/// - `visible_suffix` - 뒤쪽 노출 글자 수
+/// - `mask` - 가운데를 대체할 문자열 (e.g. `****`)
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask_string_fixed;
+///
+/// assert_eq!("12****89", mask_string_fixed("123456789", 2, 2, "****"));
+/// assert_eq!("홍****동", mask_string_fixed("홍길동", 1, 1, "****"));
+/// assert_eq!("****", mask_string_fixed("1234", 2, 2, "****"));
+/// ```
+pub fn mask_string_fixed(
+    target: &str,
+    visible_prefix: usize,
+    visible_suffix: usize,
+    mask: &str,
+) -> String {
+    let chars: Vec<char> = target.chars().collect();
+
+    if chars.len() <= visible_prefix.saturating_add(visible_suffix) {
+        return mask.to_owned();
+    }
+
+    let mut result: String = chars[..visible_prefix].iter().collect();
+
+    result += mask;
+    result.extend(&chars[chars.len() - visible_suffix..]);
+
+    result
+}
+
+/// 문자열의 일부를 제자리(in-place)에서 마스킹
+///
+/// `buf`의 `start`부터 `end` 이전까지의 글자(`char` 기준)를 `mask`로 대체한다. 새 문자열을 만들지 않고 `buf`를
+/// 직접 수정한다.
+///
+/// - 대상 구간의 모든 글자가 `mask`와 UTF-8 바이트 길이가 같으면(e.g. ASCII 원문과 `*`, 한글 원문과 `●`)
+///   바이트만 덮어쓰므로 메모리 할당이 발생하지 않는다.
+/// - 바이트 길이가 다른 글자가 섞여 있으면 구간 전체를 한 번에 교체한다. 구간이 짧아지는 경우(e.g. 한글 원문과
+///   `*`)에는 `buf`가 재할당되지 않으며, 길어지는 경우에는 `buf`의 용량에 따라 재할당될 수 있다.
+///
+/// # Arguments
+///
+/// - `buf` - 대상 문자열
+/// - `start` - 시작 위치(`char` 기준, 포함)
+/// - `end` - 종료 위치(`char` 기준, 미포함)
+/// - `mask` - 마스크 문자
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `start`가 `end`보다 크거나 `end`가 글자 수를 넘는 경우
+///
+/// # Link
+///
+/// - [mask_string_fixed]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask_in_place;
+///
+/// let mut phone = String::from("010-1234-5678");
+///
+/// mask_in_place(&mut phone, 4, 8, '*').unwrap();
+///
+/// assert_eq!("010-****-5678", phone);
+///
+/// let mut name = String::from("홍길동");
+///
+/// mask_in_place(&mut name, 1, 2, '*').unwrap();
+///
+/// assert_eq!("홍*동", name);
+/// assert!(mask_in_place(&mut name, 2, 4, '*').is_err());
+/// ```
+pub fn mask_in_place(
+    buf: &mut String,
+    start: usize,
+    end: usize,
+    mask: char,
+) -> Result<(), InvalidArgumentError> {
+    if start > end {
+        return Err(InvalidArgumentError::new(
+            format!("시작 위치가 종료 위치보다 큽니다 : {} > {}", start, end).as_str(),
+        ));
+    }
+
+    // char 위치를 byte 위치로 변환. 글자 수와 같은 위치는 문자열 끝을 의미한다.
+    let byte_offset = |index: usize| {
+        buf.char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(buf.len()))
+            .nth(index)
+    };
+    let (Some(byte_start), Some(byte_end)) = (byte_offset(start), byte_offset(end)) else {
+        return Err(InvalidArgumentError::new(
+            format!(
+                "종료 위치가 글자 수를 초과합니다 : {} > {}",
+                end,
+                buf.chars().count()
+            )
+            .as_str(),
+        ));
+    };
+
+    let mut encoded = [0u8; 4];
+    let mask_str: &str = mask.encode_utf8(&mut encoded);
+
+    if buf[byte_start..byte_end]
+        .chars()
+        .all(|c| c.len_utf8() == mask_str.len())
+    {
+        // 같은 길이의 교체는 바이트 덮어쓰기만 발생
+        for pos in (byte_start..byte_end).step_by(mask_str.len()) {
+            buf.replace_range(pos..pos + mask_str.len(), mask_str);
+        }
+    } else {
+        let replacement: String = std::iter::repeat_n(mask, end - start).collect();
+
+        buf.replace_range(byte_start..byte_end, replacement.as_str());
+    }
+
+    Ok(())
+}
+
+/// 여러 치환 쌍을 한 번에 적용
+///
+/// 대상을 앞에서부터 한 번만 순회하며 치환하므로 치환 결과가 다시 치환되지 않는다.
+/// (`a` -> `b`, `b` -> `c` 적용 시 `ab` -> `bc`)
+///
+/// - 같은 위치에서 여러 패턴이 일치할 경우 가장 긴 패턴을 우선한다. 길이가 같으면 `pairs`에서 앞선 쌍을 사용한다.
+/// - 빈 패턴은 무시한다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+/// - `pairs` - (찾을 문자열, 바꿀 문자열) 목록
+///
+/// # Return
+///
+/// - 치환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::replace_all_pairs;
+///
+/// assert_eq!("bc", replace_all_pairs("ab", &[("a", "b"), ("b", "c")]));
+/// assert_eq!("안녕, 홍길동님", replace_all_pairs("{greet}, {name}님", &[("{greet}", "안녕"), ("{name}", "홍길동")]));
+/// ```
+pub fn replace_all_pairs(target: &str, pairs: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(target.len());
+    let mut rest = target;
+
+    while let Some(c) = rest.chars().next() {
+        let matched = pairs
+            .iter()
+            .filter(|(from, _)| !from.is_empty() && rest.starts_with(from))
+            .fold(None::<&(&str, &str)>, |acc, pair| match acc {
+                Some(v) if v.0.len() >= pair.0.len() => Some(v),
+                _ => Some(pair),
+            });
+
+        match matched {
+            Some((from, to)) => {
+                result += to;
+                rest = &rest[from.len()..];
+            }
+            None => {
+                result.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    result
+}
+
+/// [slugify_with]의 한글 처리 방식
+///
+/// # Link
+///
+/// - [slugify_with]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum SlugHangul {
+    /// 한글 제거 (기본값)
+    #[default]
+    Remove,
+
+    /// 한글 유지
+    Keep,
+}
+
+/// 문자열을 URL 등에 사용할 수 있는 slug로 변환
+///
+/// 한글은 제거하며, 한글을 유지하려면 [slugify_with]를 사용한다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Link
+///
+/// - [slugify_with]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::slugify;
+///
+/// assert_eq!("hello-world-2024", slugify("Hello, World! 2024"));
+/// assert_eq!("my-post", slugify("제목 My Post!"));
+/// ```
+pub fn slugify(target: &str) -> String {
+    slugify_with(target, SlugHangul::Remove)
+}
+
+/// 지정된 [SlugHangul] 방식으로 문자열을 slug로 변환
+///
+/// 다음 순서로 변환한다.
+///
+/// 1. 소문자로 변환
+/// 1. 공백, `-`, `_`는 하이픈(`-`)으로 변환
+/// 1. ASCII 영문/숫자(및 [SlugHangul::Keep]일 경우 한글) 외의 문자는 제거
+/// 1. 연속된 하이픈은 하나로 축약하고 양 끝의 하이픈은 제거
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+/// - `hangul` - 한글 처리 방식 [SlugHangul]
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{slugify_with, SlugHangul};
+///
+/// assert_eq!("제목-my-post", slugify_with("제목 My Post!", SlugHangul::Keep));
+/// assert_eq!("my-post", slugify_with("제목 My Post!", SlugHangul::Remove));
+/// ```
+pub fn slugify_with(target: &str, hangul: SlugHangul) -> String {
+    let mut result = String::with_capacity(target.len());
+    let mut pending_hyphen = false;
+
+    for c in target.chars().flat_map(char::to_lowercase) {
+        let keep = c.is_ascii_alphanumeric()
+            || (hangul == SlugHangul::Keep
+                && (('가'..='힣').contains(&c) || ('ㄱ'..='ㅣ').contains(&c)));
+
+        if keep {
+            if pending_hyphen && !result.is_empty() {
+                result.push('-');
+            }
+
+            pending_hyphen = false;
+            result.push(c);
+        } else if c.is_whitespace() || c == '-' || c == '_' {
+            pending_hyphen = true;
+        }
+    }
+
+    result
+}
+
+/// 정규식으로 문자열을 분할
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+/// - `pattern` - 구분자 정규식
+/// - `keep_empty` - 빈 조각 유지 여부. `false`일 경우 빈 문자열 조각을 제거한다.
+///
+/// # Return
+///
+/// - 분할 결과 `Result<Vec<String>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 정규식이 올바르지 않을 경우
+///
+/// # Link
+///
+/// - [Regex::split]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::split_regex;
+///
+/// assert_eq!(vec!["a", "", "b", ""], split_regex("a,,b,", ",", true).unwrap());
+/// assert_eq!(vec!["a", "b"], split_regex("a,,b,", ",", false).unwrap());
+/// ```
+pub fn split_regex(
+    target: &str,
+    pattern: &str,
+    keep_empty: bool,
+) -> Result<Vec<String>, InvalidArgumentError> {
+    let regex =
+        Regex::new(pattern).map_err(|e| InvalidArgumentError::new(format!("{}", e).as_str()))?;
+
+    Ok(regex
+        .split(target)
+        .filter(|v| keep_empty || !v.is_empty())
+        .map(str::to_owned)
+        .collect())
+}
+
+/// 문자열을 grapheme cluster 단위로 역순 변환
+///
+/// `chars().rev()`와 달리 결합 문자(e.g. `"e\u{0301}"`)나 이모지 ZWJ 시퀀스(e.g. 가족 이모지)를
+/// 하나의 단위로 유지한 채 순서를 뒤집는다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 역순 변환 결과
+///
+/// # Link
+///
+/// - [UnicodeSegmentation::graphemes]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::reverse_graphemes;
+///
+/// assert_eq!("cba", reverse_graphemes("abc"));
+/// assert_eq!("나가\u{0301}", reverse_graphemes("가\u{0301}나"));
+/// ```
+pub fn reverse_graphemes(target: &str) -> String {
+    target.graphemes(true).rev().collect()
+}
+
+/// 사용자 인식 문자(extended grapheme cluster) 수 반환
+///
+/// `chars().count()`는 유니코드 스칼라 값의 수를 세므로 화면에 한 글자로 보이는 문자를 여러 개로 셀 수 있다.
+/// 입력 길이 제한 검증 등 사용자가 인식하는 글자 수가 필요한 경우 사용한다.
+///
+/// | 문자열                                   | `chars().count()` | `grapheme_count` |
+/// |-----------------------------------------|-------------------|------------------|
+/// | 가족 이모지(`👨‍👩‍👧`, ZWJ 시퀀스)              | 5                 | 1                |
+/// | 결합 악센트(`"e\u{0301}"`)                | 2                 | 1                |
+/// | 한글 완성형(`"한"`, U+D55C)                | 1                 | 1                |
+/// | 한글 조합형 자모(`"\u{1112}\u{1161}\u{11AB}"`) | 3                 | 1                |
+/// | 한글 호환 자모(`"ㅎㅏㄴ"`)                  | 3                 | 3                |
+///
+/// 조합형(첫가끝) 자모 시퀀스는 한 음절로 렌더링되므로 1 grapheme이지만, 호환 자모(U+3131 ~ U+318E)는 각각 독립된
+/// 문자로 취급된다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - grapheme cluster 수
+///
+/// # Link
+///
+/// - [UnicodeSegmentation::graphemes]
+/// - [reverse_graphemes]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::grapheme_count;
+///
+/// assert_eq!(1, grapheme_count("👨\u{200D}👩\u{200D}👧"));
+/// assert_eq!(1, grapheme_count("e\u{0301}"));
+/// assert_eq!(2, grapheme_count("한글"));
+/// ```
+pub fn grapheme_count(target: &str) -> usize {
+    target.graphemes(true).count()
+}
+
+/// EUC-KR(CP949) 바이트를 UTF-8 문자열로 변환
+///
+/// 레거시 시스템에서 생성된 EUC-KR/CP949 데이터를 읽을 때 사용한다. 변환 규칙은 WHATWG 표준의 `EUC-KR`
+/// (CP949 확장 포함)을 따르며, 잘못된 바이트 시퀀스를 대체 문자(`U+FFFD`)로 바꾸지 않고 오류로 처리한다.
+///
+/// # Arguments
+///
+/// - `bytes` - EUC-KR 바이트
+///
+/// # Return
+///
+/// - 변환 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - EUC-KR로 해석할 수 없는 바이트가 포함된 경우
+///
+/// # Link
+///
+/// - [utf8_to_euckr]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::euckr_to_utf8;
+///
+/// assert_eq!("한글", euckr_to_utf8(&[0xC7, 0xD1, 0xB1, 0xDB]).unwrap());
+/// assert!(euckr_to_utf8(&[0xC7]).is_err());
+/// ```
+pub fn euckr_to_utf8(bytes: &[u8]) -> Result<String, InvalidArgumentError> {
+    EUC_KR
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .map(|v| v.into_owned())
+        .ok_or_else(|| {
+            InvalidArgumentError::new("EUC-KR로 변환할 수 없는 바이트가 포함되어 있습니다.")
+        })
+}
+
+/// UTF-8 문자열을 EUC-KR(CP949) 바이트로 변환
+///
+/// EUC-KR에 없는 문자(e.g. 이모지, 일부 한자)가 포함된 경우 HTML 문자 참조(`&#...;`)나 `?`로 대체하지 않고
+/// 오류로 처리한다. 오류 메시지에는 변환할 수 없는 첫 번째 문자가 포함된다.
+///
+/// # Arguments
+///
+/// - `s` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과 `Result<Vec<u8>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - EUC-KR로 표현할 수 없는 문자가 포함된 경우
+///
+/// # Link
+///
+/// - [euckr_to_utf8]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::utf8_to_euckr;
+///
+/// assert_eq!(vec![0xC7, 0xD1, 0xB1, 0xDB], utf8_to_euckr("한글").unwrap());
+/// assert!(utf8_to_euckr("한글😀").is_err());
+/// ```
+pub fn utf8_to_euckr(s: &str) -> Result<Vec<u8>, InvalidArgumentError> {
+    let (result, _, had_errors) = EUC_KR.encode(s);
+
+    if had_errors {
+        let unmappable = s
+            .chars()
+            .find(|c| EUC_KR.encode(c.encode_utf8(&mut [0; 4])).2)
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+        return Err(InvalidArgumentError::new(
+            format!(
+                "EUC-KR로 변환할 수 없는 문자가 포함되어 있습니다 : {} (U+{:04X})",
+                unmappable, unmappable as u32
+            )
+            .as_str(),
+        ));
+    }
+
+    Ok(result.into_owned())
+}
+
+/// [detect_encoding]으로 판별한 문자 인코딩
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum DetectedEncoding {
+    /// UTF-8(BOM `EF BB BF` 포함 여부 무관). 순수 ASCII 및 빈 데이터 포함
+    Utf8,
+
+    /// UTF-16 little endian(BOM `FF FE`)
+    Utf16Le,
+
+    /// UTF-16 big endian(BOM `FE FF`)
+    Utf16Be,
+
+    /// EUC-KR(CP949)
+    EucKr,
+
+    /// 판별 불가(e.g. binary)
+    Unknown,
+}
+
+impl DetectedEncoding {
+    /// 판별된 인코딩으로 `bytes`를 UTF-8 문자열로 변환
+    ///
+    /// 인코딩에 해당하는 BOM이 있으면 제거한 후 변환하며, 잘못된 바이트 시퀀스는 대체 문자(`U+FFFD`)로 바꾸지 않고
+    /// 오류로 처리한다.
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - [DetectedEncoding::Unknown]이거나 변환할 수 없는 바이트가 포함된 경우
+    pub fn decode(&self, bytes: &[u8]) -> Result<String, InvalidArgumentError> {
+        let encoding = match self {
+            DetectedEncoding::Utf8 => UTF_8,
+            DetectedEncoding::Utf16Le => UTF_16LE,
+            DetectedEncoding::Utf16Be => UTF_16BE,
+            DetectedEncoding::EucKr => return euckr_to_utf8(bytes),
+            DetectedEncoding::Unknown => {
+                return Err(InvalidArgumentError::new("인코딩을 판별할 수 없습니다."))
+            }
+        };
+        let bytes = match Encoding::for_bom(bytes) {
+            Some((v, bom_len)) if v == encoding => &bytes[bom_len..],
+            _ => bytes,
+        };
+
+        encoding
+            .decode_without_bom_handling_and_without_replacement(bytes)
+            .map(|v| v.into_owned())
+            .ok_or_else(|| {
+                InvalidArgumentError::new(
+                    format!(
+                        "{}로 변환할 수 없는 바이트가 포함되어 있습니다.",
+                        encoding.name()
+                    )
+                    .as_str(),
+                )
+            })
+    }
+}
+
+/// 바이트의 문자 인코딩 판별
+///
+/// BOM(`EF BB BF`, `FF FE`, `FE FF`)이 있으면 BOM을 우선하며, 없을 경우 다음 순서의 휴리스틱으로 추정한다.
+///
+/// 1. 짝수 길이이고 코드 유닛의 절반 이상에 0 바이트가 있으며 0 바이트의 80% 이상이 홀수(혹은 짝수) 위치에
+///    있으면 UTF-16 LE(혹은 BE). ASCII 위주의 UTF-16 텍스트를 판별하기 위한 규칙으로, 0 바이트가 적은 UTF-16
+///    텍스트(e.g. 한글만 포함)는 판별하지 못한다.
+/// 2. 올바른 UTF-8이면 [DetectedEncoding::Utf8]
+/// 3. EUC-KR로 변환 가능하고 제어 문자(`\t`, `\r`, `\n` 제외)가 없으면 [DetectedEncoding::EucKr]
+/// 4. 그 외 [DetectedEncoding::Unknown]
+///
+/// 휴리스틱이므로 짧은 데이터는 잘못 판별할 수 있다.
+///
+/// # Arguments
+///
+/// - `bytes` - 판별 대상
+///
+/// # Return
+///
+/// - 판별 결과 [DetectedEncoding]
+///
+/// # Link
+///
+/// - [DetectedEncoding::decode]
+/// - [euckr_to_utf8]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{detect_encoding, DetectedEncoding};
+///
+/// assert_eq!(DetectedEncoding::Utf8, detect_encoding("한글".as_bytes()));
+/// assert_eq!(DetectedEncoding::EucKr, detect_encoding(&[0xC7, 0xD1, 0xB1, 0xDB]));
+/// assert_eq!(DetectedEncoding::Utf16Le, detect_encoding(&[0xFF, 0xFE, 0x5C, 0xD5]));
+///
+/// let bytes = [0xC7, 0xD1, 0xB1, 0xDB];
+///
+/// assert_eq!("한글", detect_encoding(&bytes).decode(&bytes).unwrap());
+/// ```
+pub fn detect_encoding(bytes: &[u8]) -> DetectedEncoding {
+    match Encoding::for_bom(bytes) {
+        Some((v, _)) if v == UTF_8 => return DetectedEncoding::Utf8,
+        Some((v, _)) if v == UTF_16LE => return DetectedEncoding::Utf16Le,
+        Some((v, _)) if v == UTF_16BE => return DetectedEncoding::Utf16Be,
+        _ => {}
+    }
+
+    if !bytes.is_empty() && bytes.len().is_multiple_of(2) {
+        let units = bytes.len() / 2;
+        let even_zero = bytes.iter().step_by(2).filter(|v| **v == 0).count();
+        let odd_zero = bytes.iter().skip(1).step_by(2).filter(|v| **v == 0).count();
+
+        if odd_zero * 2 >= units && odd_zero >= even_zero * 4 {
+            return DetectedEncoding::Utf16Le;
+        }
+
+        if even_zero * 2 >= units && even_zero >= odd_zero * 4 {
+            return DetectedEncoding::Utf16Be;
+        }
+    }
+
+    if std::str::from_utf8(bytes).is_ok() {
+        return DetectedEncoding::Utf8;
+    }
+
+    match euckr_to_utf8(bytes) {
+        Ok(v)
+            if !v
+                .chars()
+                .any(|c| c.is_control() && !matches!(c, '\t' | '\r' | '\n')) =>
+        {
+            DetectedEncoding::EucKr
+        }
+        _ => DetectedEncoding::Unknown,
+    }
+}
+
+/// `{key}` 형태의 플레이스홀더를 `vars`의 값으로 치환
+///
+/// - `{{`와 `}}`는 각각 리터럴 `{`, `}`로 변환된다.
+/// - `vars`에 없는 키, 빈 키(`{}`), 짝이 맞지 않거나 중첩된 중괄호는 오류로 처리한다.
+/// - 키 앞뒤의 공백은 무시한다. (e.g. `{ name }`)
+///
+/// # Arguments
+///
+/// - `template` - 템플릿 문자열 (e.g. `안녕하세요 {name}님, {count}개의 알림`)
+/// - `vars` - 치환할 키/값
+///
+/// # Return
+///
+/// - 치환 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 누락된 키, 빈 키 혹은 잘못된 중괄호 사용
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::render_template;
+/// use std::collections::HashMap;
+///
+/// let vars = HashMap::from([("name", "홍길동".to_owned()), ("count", "3".to_owned())]);
+///
+/// assert_eq!(
+///     "안녕하세요 홍길동님, 3개의 알림 {count}",
+///     render_template("안녕하세요 {name}님, {count}개의 알림 {{count}}", &vars).unwrap()
+/// );
+/// assert!(render_template("{unknown}", &vars).is_err());
+/// ```
+pub fn render_template(
+    template: &str,
+    vars: &HashMap<&str, String>,
+) -> Result<String, InvalidArgumentError> {
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|v| v.1) == Some('{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek().map(|v| v.1) == Some('}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let mut key = String::new();
+                let mut closed = false;
+
+                for (_, v) in chars.by_ref() {
+                    match v {
+                        '}' => {
+                            closed = true;
+                            break;
+                        }
+                        '{' => {
+                            return Err(InvalidArgumentError::new(
+                                format!("중첩된 중괄호는 사용할 수 없습니다(위치: {})", index)
+                                    .as_str(),
+                            ));
+                        }
+                        _ => key.push(v),
+                    }
+                }
+
+                if !closed {
+                    return Err(InvalidArgumentError::new(
+                        format!("닫히지 않은 중괄호가 있습니다(위치: {})", index).as_str(),
+                    ));
+                }
+
+                let key = key.trim();
+
+                if key.is_empty() {
+                    return Err(InvalidArgumentError::new(
+                        format!("빈 플레이스홀더가 있습니다(위치: {})", index).as_str(),
+                    ));
+                }
+
+                match vars.get(key) {
+                    Some(v) => result.push_str(v),
+                    None => {
+                        return Err(InvalidArgumentError::new(
+                            format!("템플릿 변수가 없습니다 : {}", key).as_str(),
+                        ));
+                    }
+                }
+            }
+            '}' => {
+                return Err(InvalidArgumentError::new(
+                    format!("짝이 맞지 않는 닫는 중괄호가 있습니다(위치: {})", index).as_str(),
+                ));
+            }
+            _ => result.push(c),
+        }
+    }
+
+    Ok(result)
+}
+
+/// 자연 정렬(natural sort) 방식으로 두 문자열 비교
+///
+/// 연속된 ASCII 숫자는 하나의 수로 취급하여 수치로 비교하고(`file2` < `file10`), 나머지 문자는 한 글자씩 비교한다.
+///
+/// - 숫자는 자릿수 기반으로 비교하므로 `u64` 범위를 넘는 긴 숫자도 오버플로 없이 비교된다.
+/// - 선행 0은 수치 비교에서 무시한다(`file02` == `file2`). 나머지가 모두 같을 경우 선행 0이 적은 쪽이 앞선다.
+/// - 문자는 대/소문자를 구분하지 않고 비교한다. 나머지가 모두 같을 경우 일반 문자열 비교([str::cmp]) 결과를 따른다.
+/// - 숫자는 문자보다 앞선다. 한글 등 그 외 문자는 유니코드 코드 포인트 순서(한글의 경우 가나다 순)로 비교한다.
+///
+/// # Arguments
+///
+/// - `a` - 비교 대상 문자열
+/// - `b` - 비교 대상 문자열
+///
+/// # Return
+///
+/// - 비교 결과 [std::cmp::Ordering]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::natural_compare;
+///
+/// let mut files = vec!["file10", "file2", "File1", "file02"];
+///
+/// files.sort_by(|a, b| natural_compare(a, b));
+///
+/// assert_eq!(vec!["File1", "file2", "file02", "file10"], files);
+/// ```
+pub fn natural_compare(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    let mut left = a.chars().peekable();
+    let mut right = b.chars().peekable();
+    let mut zero_order = Ordering::Equal; // 선행 0 개수 비교 결과(처음 차이가 난 위치 기준)
+
+    loop {
+        let (l, r) = match (left.peek().copied(), right.peek().copied()) {
+            (None, None) => break,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) => (l, r),
+        };
+
+        if l.is_ascii_digit() && r.is_ascii_digit() {
+            let take_digits = |iter: &mut std::iter::Peekable<std::str::Chars>| {
+                let mut digits = String::new();
+
+                while let Some(c) = iter.next_if(|c| c.is_ascii_digit()) {
+                    digits.push(c);
+                }
+
+                digits
+            };
+            let l_digits = take_digits(&mut left);
+            let r_digits = take_digits(&mut right);
+            let l_trimmed = l_digits.trim_start_matches('0');
+            let r_trimmed = r_digits.trim_start_matches('0');
+            let ordering = l_trimmed
+                .len()
+                .cmp(&r_trimmed.len())
+                .then_with(|| l_trimmed.cmp(r_trimmed));
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+
+            if zero_order == Ordering::Equal {
+                zero_order = l_digits.len().cmp(&r_digits.len());
+            }
+
+            continue;
+        }
+
+        let ordering = match (l.is_ascii_digit(), r.is_ascii_digit()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => l.to_lowercase().cmp(r.to_lowercase()),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        left.next();
+        right.next();
+    }
+
+    zero_order.then_with(|| a.cmp(b))
+}
+
+/// 같은 문자가 `max_repeat`번을 초과하여 연속될 경우 `max_repeat`개로 축약
+///
+/// `char` 단위로 비교하므로 한글 자모(e.g. `ㅋㅋㅋㅋ`)나 이모지도 한 글자로 취급한다. 공백도 동일하게 처리된다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+/// - `max_repeat` - 허용할 최대 연속 횟수. `0`일 경우 `1`로 취급한다.
+///
+/// # Return
+///
+/// - 축약 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::collapse_repeats;
+///
+/// assert_eq!("wow!", collapse_repeats("wooooow!!!", 1));
+/// assert_eq!("aabbcc", collapse_repeats("aaabbbccc", 2));
+/// assert_eq!("ㅋㅋㅋ", collapse_repeats("ㅋㅋㅋㅋㅋㅋ", 3));
+/// ```
+pub fn collapse_repeats(target: &str, max_repeat: usize) -> String {
+    let max_repeat = max_repeat.max(1);
+    let mut result = String::with_capacity(target.len());
+    let mut previous: Option<char> = None;
+    let mut count: usize = 0;
+
+    for c in target.chars() {
+        if previous == Some(c) {
+            count += 1;
+        } else {
+            previous = Some(c);
+            count = 1;
+        }
+
+        if count <= max_repeat {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// [word_wrap_with]에서 줄 폭을 계산하는 방식
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum WrapWidth {
+    /// 문자(`char`) 수 기준
+    #[default]
+    Chars,
+
+    /// 화면 표시폭 기준. 한글 등 CJK 전각 문자는 `2`로 계산한다. ([display_width] 참고)
+    Display,
+}
+
+/// 문자열을 지정된 폭으로 줄바꿈(word wrap). 폭은 문자 수 기준으로 계산한다.
+///
+/// [WrapWidth::Chars]를 사용하는 [word_wrap_with]와 같다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `width` - 줄 폭
+///
+/// # Return
+///
+/// - 줄바꿈 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::word_wrap;
+///
+/// assert_eq!("The quick\nbrown fox", word_wrap("The quick brown fox", 10));
+/// ```
+pub fn word_wrap(text: &str, width: usize) -> String {
+    word_wrap_with(text, width, WrapWidth::Chars)
+}
+
+/// 문자열을 지정된 폭과 계산 방식으로 줄바꿈(word wrap)
+///
+/// - 공백을 기준으로 단어를 나누며, 단어 하나가 `width`보다 길 경우 강제로 자른다.
+/// - 기존 개행(`\n`)은 유지되며 빈 줄도 그대로 남는다. 줄 안의 연속 공백과 앞뒤 공백은 정리된다.
+/// - `width`가 `0`이면 줄바꿈 없이 원본을 반환한다.
+/// - [WrapWidth::Display]에서 표시폭이 `width`보다 큰 문자(e.g. `width`가 `1`일 때의 한글)는 한 줄에 하나씩 배치한다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `width` - 줄 폭
+/// - `mode` - 폭 계산 방식 ([WrapWidth])
+///
+/// # Return
+///
+/// - 줄바꿈 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{word_wrap_with, WrapWidth};
+///
+/// assert_eq!("가나다 라마\n바사", word_wrap_with("가나다 라마 바사", 6, WrapWidth::Chars));
+/// assert_eq!("가나다\n라마\n바사", word_wrap_with("가나다 라마 바사", 6, WrapWidth::Display));
+/// ```
+pub fn word_wrap_with(text: &str, width: usize, mode: WrapWidth) -> String {
+    if width == 0 {
+        return text.to_owned();
+    }
+
+    let char_width = |c: char| match mode {
+        WrapWidth::Chars => 1,
+        WrapWidth::Display => unicode_width::UnicodeWidthChar::width(c).unwrap_or(0),
+    };
+    let mut lines: Vec<String> = vec![];
+
+    for line in text.split('\n') {
+        let mut current = String::new();
+        let mut current_width: usize = 0;
+        let line_start = lines.len();
+
+        for word in line.split_whitespace() {
+            let word_width: usize = word.chars().map(char_width).sum();
+
+            if !current.is_empty() && current_width + 1 + word_width <= width {
+                current.push(' ');
+                current.push_str(word);
+                current_width += 1 + word_width;
+
+                continue;
+            }
+
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+
+            if word_width <= width {
+                current.push_str(word);
+                current_width = word_width;
+
+                continue;
+            }
+
+            // 폭보다 긴 단어는 강제로 자름
+            for c in word.chars() {
+                let w = char_width(c);
+
+                if !current.is_empty() && current_width + w > width {
+                    lines.push(std::mem::take(&mut current));
+                    current_width = 0;
+                }
+
+                current.push(c);
+                current_width += w;
+            }
+        }
+
+        if !current.is_empty() || lines.len() == line_start {
+            lines.push(current);
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// 정규식의 이름 있는 캡처 그룹(named capture group)을 맵으로 추출
+///
+/// `text`에서 처음 매치된 결과만 사용한다.
+///
+/// - 매치되지 않을 경우 빈 맵을 반환한다.
+/// - 선택적 그룹(e.g. `(?P<code>\d+)?`)이 매치에 참여하지 않은 경우 해당 키는 맵에 포함되지 않는다.
+///   빈 문자열로 매치된 경우에는 빈 문자열 값으로 포함된다.
+/// - 이름 없는 그룹은 무시한다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `pattern` - 정규식 (e.g. `(?P<level>\w+): (?P<message>.*)`)
+///
+/// # Return
+///
+/// - 그룹 이름/값 맵 `Result<HashMap<String, String>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 정규식이 올바르지 않을 경우
+///
+/// # Link
+///
+/// - [Regex::captures]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::extract_named_captures;
+///
+/// let result = extract_named_captures("ERROR: disk full", r"(?P<level>\w+): (?P<message>.*)").unwrap();
+///
+/// assert_eq!("ERROR", result["level"]);
+/// assert_eq!("disk full", result["message"]);
+/// assert!(extract_named_captures("no match", r"(?P<n>\d+)").unwrap().is_empty());
+/// ```
+pub fn extract_named_captures(
+    text: &str,
+    pattern: &str,
+) -> Result<HashMap<String, String>, InvalidArgumentError> {
+    let regex =
+        Regex::new(pattern).map_err(|e| InvalidArgumentError::new(format!("{}", e).as_str()))?;
+    let captures = match regex.captures(text) {
+        None => return Ok(HashMap::new()),
+        Some(v) => v,
+    };
+
+    Ok(regex
+        .capture_names()
+        .flatten()
+        .filter_map(|name| {
+            captures
+                .name(name)
+                .map(|v| (name.to_owned(), v.as_str().to_owned()))
+        })
+        .collect())
+}
+
+/// 대상 문자열이 지정된 접두사 중 하나로 시작하는지 검사
+///
+/// `prefixes`가 비어 있으면 `false`를 반환한다. 빈 문자열 접두사는 [str::starts_with]와 같이 모든 문자열과 일치한다.
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 문자열
+/// - `prefixes` - 접두사 목록
+///
+/// # Return
+///
+/// - 일치 여부
+///
+/// # Link
+///
+/// - [starts_with_any_ignore_case]
+/// - [ends_with_any]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::starts_with_any;
+///
+/// assert!(starts_with_any("/api/v1/users", &["/admin", "/api"]));
+/// assert!(!starts_with_any("/static/app.js", &["/admin", "/api"]));
+/// assert!(!starts_with_any("/api", &[]));
+/// ```
+pub fn starts_with_any(target: &str, prefixes: &[&str]) -> bool {
+    prefixes.iter().any(|v| target.starts_with(v))
+}
+
+/// 대상 문자열이 지정된 접미사 중 하나로 끝나는지 검사
+///
+/// `suffixes`가 비어 있으면 `false`를 반환한다. 빈 문자열 접미사는 [str::ends_with]와 같이 모든 문자열과 일치한다.
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 문자열
+/// - `suffixes` - 접미사 목록
+///
+/// # Return
+///
+/// - 일치 여부
+///
+/// # Link
+///
+/// - [ends_with_any_ignore_case]
+/// - [starts_with_any]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::ends_with_any;
+///
+/// assert!(ends_with_any("photo.png", &[".jpg", ".png"]));
+/// assert!(!ends_with_any("photo.PNG", &[".jpg", ".png"]));
+/// assert!(!ends_with_any("photo.png", &[]));
+/// ```
+pub fn ends_with_any(target: &str, suffixes: &[&str]) -> bool {
+    suffixes.iter().any(|v| target.ends_with(v))
+}
+
+/// 대소문자를 구분하지 않고 대상 문자열이 지정된 접두사 중 하나로 시작하는지 검사
+///
+/// 대상과 접두사를 모두 [str::to_lowercase]로 변환한 후 비교한다. `prefixes`가 비어 있으면 `false`를 반환한다.
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 문자열
+/// - `prefixes` - 접두사 목록
+///
+/// # Return
+///
+/// - 일치 여부
+///
+/// # Link
+///
+/// - [starts_with_any]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::starts_with_any_ignore_case;
+///
+/// assert!(starts_with_any_ignore_case("HTTPS://example.com", &["http://", "https://"]));
+/// assert!(!starts_with_any_ignore_case("ftp://example.com", &["http://", "https://"]));
+/// ```
+pub fn starts_with_any_ignore_case(target: &str, prefixes: &[&str]) -> bool {
+    let target = target.to_lowercase();
+
+    prefixes
+        .iter()
+        .any(|v| target.starts_with(v.to_lowercase().as_str()))
+}
+
+/// 대소문자를 구분하지 않고 대상 문자열이 지정된 접미사 중 하나로 끝나는지 검사
+///
+/// 대상과 접미사를 모두 [str::to_lowercase]로 변환한 후 비교한다. `suffixes`가 비어 있으면 `false`를 반환한다.
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 문자열
+/// - `suffixes` - 접미사 목록
+///
+/// # Return
+///
+/// - 일치 여부
+///
+/// # Link
+///
+/// - [ends_with_any]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::ends_with_any_ignore_case;
+///
+/// assert!(ends_with_any_ignore_case("photo.PNG", &[".jpg", ".png"]));
+/// ```
+pub fn ends_with_any_ignore_case(target: &str, suffixes: &[&str]) -> bool {
+    let target = target.to_lowercase();
+
+    suffixes
+        .iter()
+        .any(|v| target.ends_with(v.to_lowercase().as_str()))
+}
+
+/// camelCase/PascalCase 식별자를 단어 단위로 분할
+///
+/// 케이스 변환(snake_case, kebab-case 등)의 기반이 되는 토크나이저로, 다음 경계에서 분할한다.
+///
+/// - 소문자(대소문자가 없는 문자 포함) → 대문자 (e.g. `getName` → `get`, `Name`)
+/// - 연속된 대문자 뒤의 대문자 + 소문자. 약어를 하나의 단어로 유지한다. (e.g. `HTTPResponse` → `HTTP`, `Response`)
+/// - 숫자 ↔ 숫자가 아닌 문자 (e.g. `item2` → `item`, `2`)
+/// - 영문자와 숫자가 아닌 문자(e.g. `_`, `-`, 공백)는 구분자로 처리하며 결과에 포함하지 않는다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 식별자
+///
+/// # Return
+///
+/// - 분할된 단어 목록. 대소문자는 변경하지 않는다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::split_camel_case;
+///
+/// assert_eq!(vec!["get", "HTTP", "Response", "Code"], split_camel_case("getHTTPResponseCode"));
+/// assert_eq!(vec!["parse", "URL", "From", "123"], split_camel_case("parseURLFrom123"));
+/// assert_eq!(vec!["user", "id", "Value"], split_camel_case("user_id-Value"));
+/// ```
+pub fn split_camel_case(target: &str) -> Vec<String> {
+    let chars: Vec<char> = target.chars().collect();
+    let mut result: Vec<String> = vec![];
+    let mut word = String::new();
+
+    for (i, c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                result.push(std::mem::take(&mut word));
+            }
+
+            continue;
+        }
+
+        if let Some(prev) = word.chars().last() {
+            let next_is_lower = chars.get(i + 1).is_some_and(|v| v.is_lowercase());
+            let boundary = (prev.is_numeric() != c.is_numeric())
+                || (c.is_uppercase() && !prev.is_uppercase() && !prev.is_numeric())
+                || (c.is_uppercase() && prev.is_uppercase() && next_is_lower);
+
+            if boundary {
+                result.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.push(*c);
+    }
+
+    if !word.is_empty() {
+        result.push(word);
+    }
+
+    result
+}
+
+/// 어두(단어 첫 글자)에 대표적인 두음법칙 적용
+///
+/// 한글 음절 앞 글자가 한글 음절이 아닌 위치(문자열 시작, 공백, 문장 부호 뒤)를 어두로 보고 다음 규칙을 적용한다.
+/// 받침은 유지한다. (e.g. `량심` → `양심`)
+///
+/// - `ㄹ` + `ㅑ`, `ㅕ`, `ㅖ`, `ㅛ`, `ㅠ`, `ㅣ` → `ㅇ` (e.g. `리씨` → `이씨`, `력사` → `역사`)
+/// - `ㄹ` + `ㅏ`, `ㅐ`, `ㅓ`, `ㅗ`, `ㅚ`, `ㅜ`, `ㅡ` → `ㄴ` (e.g. `로동` → `노동`, `래일` → `내일`)
+/// - `ㄴ` + `ㅕ`, `ㅛ`, `ㅠ`, `ㅣ` → `ㅇ` (e.g. `녀자` → `여자`, `뉴대` → `유대`)
+///
+/// ### 한계
+///
+/// - 두음법칙은 한자어에만 적용되지만 한자어 여부를 판별하지 않는다. 외래어 및 고유어는 [DUEUM_EXCEPTIONS]에 등록된
+///   단어만 제외하며, 등록되지 않은 단어(e.g. `레몬`은 대상 아님, `라켓`은 변환됨)는 규칙대로 변환된다.
+/// - 단어 중간의 두음법칙 예외(e.g. `신여성`, `역이용`) 및 합성어의 뒷말 어두는 처리하지 않는다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::apply_dueum;
+///
+/// assert_eq!("노동 이씨 여자", apply_dueum("로동 리씨 녀자"));
+/// assert_eq!("양심", apply_dueum("량심"));
+/// assert_eq!("라면", apply_dueum("라면"));
+/// assert_eq!("노래", apply_dueum("노래"));
+/// ```
+pub fn apply_dueum(target: &str) -> String {
+    const CHO_N: u32 = 2; // ㄴ
+    const CHO_R: u32 = 5; // ㄹ
+    const CHO_O: u32 = 11; // ㅇ
+    const R_TO_O: &[u32] = &[2, 6, 7, 12, 17, 20]; // ㅑ ㅕ ㅖ ㅛ ㅠ ㅣ
+    const R_TO_N: &[u32] = &[0, 1, 4, 8, 11, 13, 18]; // ㅏ ㅐ ㅓ ㅗ ㅚ ㅜ ㅡ
+    const N_TO_O: &[u32] = &[6, 12, 17, 20]; // ㅕ ㅛ ㅠ ㅣ
+
+    let is_syllable = |c: char| ('가'..='힣').contains(&c);
+    let mut result = String::with_capacity(target.len());
+    let mut prev: Option<char> = None;
+
+    for (i, c) in target.char_indices() {
+        let word_start = prev.is_none_or(|v| !is_syllable(v));
+
+        prev = Some(c);
+
+        if !word_start
+            || !is_syllable(c)
+            || DUEUM_EXCEPTIONS.iter().any(|v| target[i..].starts_with(v))
+        {
+            result.push(c);
+            continue;
+        }
+
+        let code = c as u32 - '가' as u32;
+        let (cho, jung, jong) = (code / 588, (code % 588) / 28, code % 28);
+        let cho = match cho {
+            CHO_R if R_TO_O.contains(&jung) => CHO_O,
+            CHO_R if R_TO_N.contains(&jung) => CHO_N,
+            CHO_N if N_TO_O.contains(&jung) => CHO_O,
+            v => v,
+        };
+
+        result.push(char::from_u32('가' as u32 + cho * 588 + jung * 28 + jong).unwrap());
+    }
+
+    result
+}
+
+/// [bytes_to_string]에서 UTF-8이 아닌 바이트의 처리 방식
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum Utf8Mode {
+    /// UTF-8이 아니면 오류 (기본값)
+    #[default]
+    Strict,
+
+    /// 잘못된 바이트 시퀀스를 대체 문자(`U+FFFD`)로 변환
+    Lossy,
+
+    /// 첫 번째 잘못된 바이트 이전까지만 변환
+    Truncate,
+}
+
+/// 바이트를 지정된 방식([Utf8Mode])에 따라 UTF-8 문자열로 변환
+///
+/// 복호화 결과처럼 UTF-8임이 보장되지 않는 바이트를 `String::from_utf8(..).unwrap()`으로 변환하면 panic이
+/// 발생할 수 있으므로, 실패 처리 방식을 명시적으로 선택할 때 사용한다.
+///
+/// # Arguments
+///
+/// - `bytes` - 변환 대상
+/// - `mode` - 잘못된 바이트 처리 방식
+///
+/// # Return
+///
+/// - 변환 결과 `Result<String, InvalidArgumentError>`. [Utf8Mode::Lossy]와 [Utf8Mode::Truncate]는 항상 `Ok`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - [Utf8Mode::Strict]에서 UTF-8이 아닌 바이트가 포함된 경우. 메시지에 위치가 포함된다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{bytes_to_string, Utf8Mode};
+///
+/// // 복호화 결과 등 외부에서 전달받은 바이트를 panic 없이 문자열로 변환
+/// let decrypted: Vec<u8> = "복호화 결과".as_bytes().to_vec();
+///
+/// match bytes_to_string(&decrypted, Utf8Mode::Strict) {
+///     Ok(v) => assert_eq!("복호화 결과", v),
+///     Err(e) => panic!("UTF-8이 아닌 복호화 결과 : {}", e),
+/// }
+///
+/// let invalid = [b'a', b'b', 0xFF, b'c'];
+///
+/// assert!(bytes_to_string(&invalid, Utf8Mode::Strict).is_err());
+/// assert_eq!("ab\u{FFFD}c", bytes_to_string(&invalid, Utf8Mode::Lossy).unwrap());
+/// assert_eq!("ab", bytes_to_string(&invalid, Utf8Mode::Truncate).unwrap());
+/// ```
+pub fn bytes_to_string(bytes: &[u8], mode: Utf8Mode) -> Result<String, InvalidArgumentError> {
+    match std::str::from_utf8(bytes) {
+        Ok(v) => Ok(v.to_owned()),
+        Err(e) => match mode {
+            Utf8Mode::Strict => Err(InvalidArgumentError::new(
+                format!(
+                    "UTF-8이 아닌 바이트가 포함되어 있습니다(위치: {})",
+                    e.valid_up_to()
+                )
+                .as_str(),
+            )),
+            Utf8Mode::Lossy => Ok(String::from_utf8_lossy(bytes).into_owned()),
+            Utf8Mode::Truncate => {
+                Ok(String::from_utf8_lossy(&bytes[..e.valid_up_to()]).into_owned())
+            }
+        },
+    }
+}
+
+/// 문자열을 유니코드 코드 포인트 목록으로 변환
+///
+/// # Arguments
+///
+/// - `s` - 대상 문자열
+///
+/// # Return
+///
+/// - 코드 포인트 목록
+///
+/// # Link
+///
+/// - [from_code_points]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::to_code_points;
+///
+/// assert_eq!(vec![0xD55C, 0xAE00, 0x41, 0x1F600], to_code_points("한글A😀"));
+/// ```
+pub fn to_code_points(s: &str) -> Vec<u32> {
+    s.chars().map(u32::from).collect()
+}
+
+/// 유니코드 코드 포인트 목록을 문자열로 변환
+///
+/// # Arguments
+///
+/// - `points` - 코드 포인트 목록
+///
+/// # Return
+///
+/// - 변환 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 서로게이트 영역(`U+D800` ~ `U+DFFF`)이거나 `U+10FFFF`를 초과하는 값이 포함된 경우.
+///   메시지에 첫 번째 잘못된 값의 위치가 포함된다.
+///
+/// # Link
+///
+/// - [to_code_points]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::from_code_points;
+///
+/// assert_eq!("한글A😀", from_code_points(&[0xD55C, 0xAE00, 0x41, 0x1F600]).unwrap());
+/// assert!(from_code_points(&[0xD800]).is_err());
+/// assert!(from_code_points(&[0x110000]).is_err());
+/// ```
+pub fn from_code_points(points: &[u32]) -> Result<String, InvalidArgumentError> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            char::from_u32(*v).ok_or_else(|| {
+                InvalidArgumentError::new(
+                    format!("유효하지 않은 코드 포인트입니다(위치: {}) : 0x{:X}", i, v).as_str(),
+                )
+            })
+        })
+        .collect()
+}
+
+/// 단어의 마지막 글자에 받침이 있는지 확인. 조사(와/과 등) 선택에 사용한다.
+///
+/// - 한글 음절 : 종성 유무
+/// - 숫자 : 한자어 읽기 기준 (e.g. `1`(일), `3`(삼)은 받침 있음, `2`(이), `5`(오)는 받침 없음)
+/// - 그 외(영문, 기호 등) : 받침 없음으로 처리
+fn has_final_consonant(word: &str) -> bool {
+    match word.chars().last() {
+        Some(c @ '가'..='힣') => !(c as u32 - '가' as u32).is_multiple_of(28),
+        Some(c) => matches!(c, '0' | '1' | '3' | '6' | '7' | '8'),
+        None => false,
+    }
+}
+
+/// 문자열 목록을 자연스러운 한국어 나열 표현으로 결합
+///
+/// - 0개 : 빈 문자열
+/// - 1개 : 해당 항목
+/// - 2개 : 첫 번째 항목의 받침 유무에 따라 `A와 B` 혹은 `A과 B`
+/// - 3개 이상 : 마지막 항목 앞에 `및`을 넣고 나머지는 쉼표로 연결 (e.g. `사과, 배 및 포도`)
+///
+/// 받침은 항목의 마지막 글자로 판단하며, 숫자는 한자어 읽기(e.g. `3`(삼) → `과`)를 기준으로 하고 영문 등 한글이 아닌
+/// 글자는 받침이 없는 것으로 처리한다.
+///
+/// # Arguments
+///
+/// - `items` - 결합 대상 목록
+///
+/// # Return
+///
+/// - 결합 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::join_korean;
+///
+/// assert_eq!("", join_korean(&[]));
+/// assert_eq!("사과", join_korean(&["사과"]));
+/// assert_eq!("사과와 배", join_korean(&["사과", "배"]));
+/// assert_eq!("수박과 배", join_korean(&["수박", "배"]));
+/// assert_eq!("사과, 배 및 포도", join_korean(&["사과", "배", "포도"]));
+/// ```
+pub fn join_korean(items: &[&str]) -> String {
+    match items {
+        [] => String::new(),
+        [single] => (*single).to_owned(),
+        [first, second] => {
+            let josa = if has_final_consonant(first) {
+                "과"
+            } else {
+                "와"
+            };
+
+            format!("{}{} {}", first, josa, second)
+        }
+        [rest @ .., last] => format!("{} 및 {}", rest.join(", "), last),
+    }
+}
+
+/// 금지어 등 대량의 단어 포함 여부를 빠르게 검사하기 위한 집합
+///
+/// 내부적으로 [HashSet]을 사용하므로 [WordSet::contains]는 단어 수와 관계없이 O(1)로 동작한다.
+/// [WordSet::build_ignore_case]로 생성한 경우 대소문자를 구분하지 않는다.
+///
+/// # Link
+///
+/// - [mask_banned_words]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::WordSet;
+///
+/// let set = WordSet::build(&["바보", "spam"]);
+///
+/// assert!(set.contains("바보"));
+/// assert!(!set.contains("SPAM"));
+/// assert!(WordSet::build_ignore_case(&["spam"]).contains("SPAM"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WordSet {
+    words: HashSet<String>,
+    ignore_case: bool,
+    min_chars: usize,
+    max_chars: usize,
+}
+
+impl WordSet {
+    /// 대소문자를 구분하는 집합 생성. 빈 문자열은 제외된다.
+    pub fn build(words: &[&str]) -> Self {
+        Self::build_with(words, false)
+    }
+
+    /// 대소문자를 구분하지 않는 집합 생성. 빈 문자열은 제외된다.
+    pub fn build_ignore_case(words: &[&str]) -> Self {
+        Self::build_with(words, true)
+    }
+
+    fn build_with(words: &[&str], ignore_case: bool) -> Self {
+        let mut set = WordSet {
+            ignore_case,
+            ..Default::default()
+        };
+
+        for word in words.iter().filter(|v| !v.is_empty()) {
+            let count = word.chars().count();
+
+            set.min_chars = if set.words.is_empty() {
+                count
+            } else {
+                set.min_chars.min(count)
+            };
+            set.max_chars = set.max_chars.max(count);
+            set.words.insert(set.normalize(word.chars()));
+        }
+
+        set
+    }
+
+    /// 비교용 문자열 생성. 대소문자 무시일 경우 글자 단위로 소문자 변환
+    fn normalize(&self, chars: impl Iterator<Item = char>) -> String {
+        if self.ignore_case {
+            chars.flat_map(char::to_lowercase).collect()
+        } else {
+            chars.collect()
+        }
+    }
+
+    /// 단어 포함 여부 반환
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&self.normalize(word.chars()))
+    }
+
+    /// 집합의 단어 수 반환
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// 집합이 비어 있는지 여부 반환
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// 대소문자 무시 여부 반환
+    #[inline]
+    pub fn is_ignore_case(&self) -> bool {
+        self.ignore_case
+    }
+}
+
+/// 텍스트에 포함된 금지어를 찾아 마스킹
+///
+/// 텍스트의 각 위치에서 [WordSet]에 포함된 가장 긴 단어를 찾아 해당 구간의 글자를 `mask`로 대체하고, 마스킹한
+/// 구간 다음 위치부터 다시 탐색한다. 단어 경계는 고려하지 않으므로 다른 단어의 일부로 포함된 금지어도
+/// 마스킹된다. 대소문자 구분 여부는 `set`의 생성 방식을 따르며, 마스킹은 [mask_in_place]로 처리한다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 텍스트
+/// - `set` - 금지어 집합
+/// - `mask` - 마스크 문자
+///
+/// # Return
+///
+/// - 금지어가 마스킹된 문자열. 글자(`char`) 수는 원문과 같다.
+///
+/// # Link
+///
+/// - [WordSet]
+/// - [mask_in_place]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{mask_banned_words, WordSet};
+///
+/// let set = WordSet::build_ignore_case(&["바보", "spam"]);
+///
+/// assert_eq!("이 ** 같은 ****", mask_banned_words("이 바보 같은 SPAM", &set, '*'));
+/// ```
+pub fn mask_banned_words(text: &str, set: &WordSet, mask: char) -> String {
+    let mut result = text.to_owned();
+
+    if set.is_empty() {
+        return result;
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let longest = (i + set.min_chars..=(i + set.max_chars).min(chars.len()))
+            .rev()
+            .find(|&end| {
+                set.words
+                    .contains(&set.normalize(chars[i..end].iter().copied()))
+            });
+
+        match longest {
+            Some(end) => {
+                // 구간은 항상 글자 수 이내이므로 실패하지 않음
+                mask_in_place(&mut result, i, end, mask).unwrap();
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+
+    result
+}
+
+/// 문자열에서 제어 문자 제거
+///
+/// 유니코드 `Cc` 범주의 C0(`U+0000` ~ `U+001F`, `U+007F`) 및 C1(`U+0080` ~ `U+009F`) 제어 문자를 모두
+/// 제거한다. 탭과 개행을 유지하려면 [remove_control_chars_keep_whitespace]를 사용한다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 제어 문자가 제거된 문자열
+///
+/// # Link
+///
+/// - [char::is_control]
+/// - [remove_control_chars_keep_whitespace]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::remove_control_chars;
+///
+/// assert_eq!("한글ABC", remove_control_chars("한글\u{0}\tA\u{1b}B\u{85}C\r\n"));
+/// ```
+pub fn remove_control_chars(target: &str) -> String {
+    target.chars().filter(|c| !c.is_control()).collect()
+}
+
+/// 탭과 개행을 유지하며 문자열에서 제어 문자 제거
+///
+/// 탭(`\t`), 개행(`\n`), 캐리지 리턴(`\r`)을 제외한 제어 문자를 제거하며, 그 외에는 [remove_control_chars]와 같다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 제어 문자가 제거된 문자열
+///
+/// # Link
+///
+/// - [remove_control_chars]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::remove_control_chars_keep_whitespace;
+///
+/// assert_eq!("한글\tAB\r\n", remove_control_chars_keep_whitespace("한글\u{0}\tA\u{1b}B\r\n"));
+/// ```
+pub fn remove_control_chars_keep_whitespace(target: &str) -> String {
+    target
+        .chars()
+        .filter(|c| !c.is_control() || matches!(c, '\t' | '\n' | '\r'))
+        .collect()
+}
+
+/// 이모지 및 이모지 구성 문자 여부
+fn is_emoji_char(c: char) -> bool {
+    matches!(c,
+        '\u{1F000}'..='\u{1FAFF}' // 마작/카드, 국기(regional indicator), 그림 문자, 이모티콘, 교통, 보충 기호 등
+        | '\u{2600}'..='\u{27BF}' // 기타 기호, 딩뱃
+        | '\u{231A}'..='\u{231B}'
+        | '\u{23E9}'..='\u{23F3}'
+        | '\u{23F8}'..='\u{23FA}'
+        | '\u{2B1B}'..='\u{2B1C}'
+        | '\u{2B50}'
+        | '\u{2B55}'
+        | '\u{200D}' // ZWJ
+        | '\u{FE0E}'..='\u{FE0F}' // variation selector
+        | '\u{20E3}' // keycap
+        | '\u{E0020}'..='\u{E007F}' // tag(지역 국기)
+    )
+}
+
+/// 문자열에서 이모지 제거
+///
+/// 아래 범위의 문자를 제거한다. ZWJ 시퀀스(e.g. `👨‍👩‍👧`)와 피부색 조합, 국기도 구성 문자가 모두 제거된다.
+///
+/// - `U+1F000` ~ `U+1FAFF` : 그림 문자, 이모티콘, 교통/지도 기호, 국기(regional indicator), 피부색 수식자 등
+/// - `U+2600` ~ `U+27BF` : 기타 기호 및 딩뱃(e.g. `☀`, `✂`, `❤`)
+/// - `⌚`, `⏩` ~ `⏳`, `⭐`, `⭕` 등 일부 기호
+/// - ZWJ(`U+200D`), variation selector(`U+FE0E`, `U+FE0F`), keycap(`U+20E3`), tag(`U+E0020` ~ `U+E007F`)
+///
+/// 키캡 이모지(e.g. `1️⃣`)의 숫자나 `©️`의 `©`처럼 일반 문자를 기반으로 한 이모지는 기반 문자가 남는다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 이모지가 제거된 문자열
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::remove_emoji;
+///
+/// assert_eq!("안녕 Hello!", remove_emoji("안녕😀 Hello👨‍👩‍👧!🇰🇷"));
+/// ```
+pub fn remove_emoji(target: &str) -> String {
+    target.chars().filter(|c| !is_emoji_char(*c)).collect()
+}
+
+/// 중복을 제거하고 코드 포인트 순으로 정렬한 문자열 반환
+///
+/// 문자 집합 비교 등에 사용한다. (e.g. `"banana"` => `"abn"`)
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 고유 문자를 코드 포인트 순으로 정렬한 문자열
+///
+/// # Link
+///
+/// - [is_anagram]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::unique_sorted_chars;
+///
+/// assert_eq!("abn", unique_sorted_chars("banana"));
+/// assert_eq!("리스아", unique_sorted_chars("아리스아리스"));
+/// ```
+pub fn unique_sorted_chars(target: &str) -> String {
+    let mut chars: Vec<char> = target.chars().collect();
+
+    chars.sort_unstable();
+    chars.dedup();
+
+    chars.into_iter().collect()
+}
+
+/// 두 문자열이 아나그램(같은 문자들을 재배열한 관계)인지 검사
+///
+/// 두 문자열의 전체 문자를 정렬하여 비교하므로 각 문자의 개수까지 같아야 한다. 대소문자와 공백을 구분하며,
+/// 무시하려면 [is_anagram_with_option]을 사용한다.
+///
+/// # Arguments
+///
+/// - `a` - 비교 대상 문자열
+/// - `b` - 비교 대상 문자열
+///
+/// # Return
+///
+/// - 아나그램 여부
+///
+/// # Link
+///
+/// - [is_anagram_with_option]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::is_anagram;
+///
+/// assert!(is_anagram("listen", "silent"));
+/// assert!(is_anagram("리스아", "아리스"));
+/// assert!(!is_anagram("aab", "abb"));
+/// ```
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    is_anagram_with_option(a, b, false, false)
+}
+
+/// 대소문자 및 공백 무시 여부를 지정하여 두 문자열이 아나그램인지 검사
+///
+/// # Arguments
+///
+/// - `a` - 비교 대상 문자열
+/// - `b` - 비교 대상 문자열
+/// - `ignore_case` - 대소문자 무시 여부. 무시할 경우 [char::to_lowercase]로 변환 후 비교
+/// - `ignore_whitespace` - 공백([char::is_whitespace]) 무시 여부
+///
+/// # Return
+///
+/// - 아나그램 여부
+///
+/// # Link
+///
+/// - [is_anagram]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::is_anagram_with_option;
+///
+/// assert!(is_anagram_with_option("Dormitory", "dirty room", true, true));
+/// assert!(!is_anagram_with_option("Dormitory", "dirty room", false, true));
+/// assert!(!is_anagram_with_option("Dormitory", "dirty room", true, false));
+/// ```
+pub fn is_anagram_with_option(
+    a: &str,
+    b: &str,
+    ignore_case: bool,
+    ignore_whitespace: bool,
+) -> bool {
+    let sorted = |v: &str| {
+        let mut chars: Vec<char> = v
+            .chars()
+            .filter(|c| !(ignore_whitespace && c.is_whitespace()))
+            .flat_map(|c| {
+                if ignore_case {
+                    c.to_lowercase().collect::<Vec<char>>()
+                } else {
+                    vec![c]
+                }
+            })
+            .collect();
+
+        chars.sort_unstable();
+
+        chars
+    };
+
+    sorted(a) == sorted(b)
+}
+
+/// 모든 항목이 공유하는 최장 공통 접두사 반환
+///
+/// 글자(`char`) 단위로 비교하므로 한글 등 멀티바이트 문자의 중간에서 잘리지 않는다.
+///
+/// # Arguments
+///
+/// - `items` - 대상 문자열 목록
+///
+/// # Return
+///
+/// - 공통 접두사. 항목이 없거나 공통 부분이 없으면 빈 문자열, 항목이 하나이면 그 자신
+///
+/// # Link
+///
+/// - [common_suffix]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::common_prefix;
+///
+/// assert_eq!("보고서_2024_", common_prefix(&["보고서_2024_1월.pdf", "보고서_2024_2월.pdf"]));
+/// assert_eq!("", common_prefix(&["abc", "xyz"]));
+/// ```
+pub fn common_prefix(items: &[&str]) -> String {
+    match items.split_first() {
+        None => String::new(),
+        Some((first, rest)) => {
+            let mut prefix: Vec<char> = first.chars().collect();
+
+            for item in rest {
+                let len = prefix
+                    .iter()
+                    .zip(item.chars())
+                    .take_while(|(a, b)| **a == *b)
+                    .count();
+
+                prefix.truncate(len);
+            }
+
+            prefix.into_iter().collect()
+        }
+    }
+}
+
+/// 모든 항목이 공유하는 최장 공통 접미사 반환
+///
+/// 글자(`char`) 단위로 비교하므로 한글 등 멀티바이트 문자의 중간에서 잘리지 않는다.
+///
+/// # Arguments
+///
+/// - `items` - 대상 문자열 목록
+///
+/// # Return
+///
+/// - 공통 접미사. 항목이 없거나 공통 부분이 없으면 빈 문자열, 항목이 하나이면 그 자신
+///
+/// # Link
+///
+/// - [common_prefix]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::common_suffix;
+///
+/// assert_eq!("_최종.docx", common_suffix(&["기획안_최종.docx", "보고서_최종.docx"]));
+/// assert_eq!("", common_suffix(&["abc", "xyz"]));
+/// ```
+pub fn common_suffix(items: &[&str]) -> String {
+    match items.split_first() {
+        None => String::new(),
+        Some((first, rest)) => {
+            // 역순으로 저장
+            let mut suffix: Vec<char> = first.chars().rev().collect();
+
+            for item in rest {
+                let len = suffix
+                    .iter()
+                    .zip(item.chars().rev())
+                    .take_while(|(a, b)| **a == *b)
+                    .count();
+
+                suffix.truncate(len);
+            }
+
+            suffix.into_iter().rev().collect()
+        }
+    }
+}
+
+/// `ranges`(byte 구간)에 포함된 글자를 `mask`로 대체. 구간은 겹쳐도 된다.
+fn mask_byte_ranges(text: &str, mut ranges: Vec<std::ops::Range<usize>>, mask: char) -> String {
+    ranges.sort_by_key(|v| v.start);
+
+    let mut result = String::with_capacity(text.len());
+    let mut ranges = ranges.into_iter().peekable();
+    let mut current: Option<std::ops::Range<usize>> = None;
+
+    for (i, c) in text.char_indices() {
+        while current.as_ref().is_none_or(|v| v.end <= i) {
+            match ranges.next_if(|v| v.start <= i) {
+                Some(v) => current = Some(v),
+                None => {
+                    current = None;
+                    break;
+                }
+            }
+        }
+
+        if current.as_ref().is_some_and(|v| v.contains(&i)) {
+            result.push(mask);
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// 정규식에 매칭되는 구간을 마스킹
+///
+/// 매칭된 각 구간의 글자(`char`)를 같은 개수의 `mask`로 대체한다. 매칭되지 않은 부분은 그대로 유지된다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `pattern` - 정규식
+/// - `mask` - 마스크 문자
+///
+/// # Return
+///
+/// - 마스킹 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 정규식이 올바르지 않을 경우
+///
+/// # Link
+///
+/// - [mask_by_regex_groups]
+/// - [Regex::find_iter]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask_by_regex;
+///
+/// let result = mask_by_regex("카드: 1234-5678-9012-3456 결제", r"\d{4}-\d{4}-\d{4}-\d{4}", '*').unwrap();
+///
+/// assert_eq!("카드: ******************* 결제", result);
+/// assert!(mask_by_regex("text", "(", '*').is_err());
+/// ```
+pub fn mask_by_regex(
+    text: &str,
+    pattern: &str,
+    mask: char,
+) -> Result<String, InvalidArgumentError> {
+    let regex =
+        Regex::new(pattern).map_err(|e| InvalidArgumentError::new(format!("{}", e).as_str()))?;
+    let ranges = regex.find_iter(text).map(|v| v.range()).collect();
+
+    Ok(mask_byte_ranges(text, ranges, mask))
+}
+
+/// 정규식에 매칭된 구간 중 캡처 그룹 부분만 마스킹
+///
+/// 각 매칭에서 캡처 그룹(이름 있는 그룹 포함)에 해당하는 글자만 `mask`로 대체한다. 정규식에 캡처 그룹이 없으면
+/// [mask_by_regex]와 같이 매칭 전체를 마스킹한다. 매칭에 참여하지 않은 선택적 그룹은 무시한다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `pattern` - 캡처 그룹을 포함한 정규식
+/// - `mask` - 마스크 문자
+///
+/// # Return
+///
+/// - 마스킹 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 정규식이 올바르지 않을 경우
+///
+/// # Link
+///
+/// - [mask_by_regex]
+/// - [Regex::captures_iter]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask_by_regex_groups;
+///
+/// // 전화번호 가운데 자리만 마스킹
+/// let result = mask_by_regex_groups("010-1234-5678, 02-987-6543", r"\d{2,3}-(\d{3,4})-\d{4}", '*').unwrap();
+///
+/// assert_eq!("010-****-5678, 02-***-6543", result);
+/// ```
+pub fn mask_by_regex_groups(
+    text: &str,
+    pattern: &str,
+    mask: char,
+) -> Result<String, InvalidArgumentError> {
+    let regex =
+        Regex::new(pattern).map_err(|e| InvalidArgumentError::new(format!("{}", e).as_str()))?;
+
+    if regex.captures_len() <= 1 {
+        return mask_by_regex(text, pattern, mask);
+    }
+
+    let ranges = regex
+        .captures_iter(text)
+        .flat_map(|v| {
+            v.iter()
+                .skip(1) // 0번은 매칭 전체
+                .flatten()
+                .map(|m| m.range())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    Ok(mask_byte_ranges(text, ranges, mask))
+}
+
+/// 한 글자의 1차 정렬 키 `[분류, a, b, c]`
+///
+/// 분류는 `1`: 한글, `2`: 영문, `3`: 숫자, `4`: 그 외 문자이며, [korean_sort_key]에서 구분자로 사용하는 `0`보다 크다.
+fn korean_primary_key(c: char) -> [u8; 4] {
+    match c {
+        '가'..='힣' => {
+            let code = c as u32 - '가' as u32;
+
+            // 자모만 있는 글자(e.g. `ㄱ`)가 같은 초성의 음절보다 앞서도록 중성은 1부터 시작
+            [
+                1,
+                (code / 588) as u8,
+                (code % 588 / 28 + 1) as u8,
+                (code % 28) as u8,
+            ]
+        }
+        'a'..='z' | 'A'..='Z' => [2, c.to_ascii_lowercase() as u8, 0, 0],
+        '0'..='9' => [3, c as u8, 0, 0],
+        _ => {
+            if let Some(i) = KO_CONSONANTS.iter().position(|v| *v == c) {
+                return [1, i as u8, 0, 0];
+            }
+
+            // 겹받침(e.g. `ㄳ`)은 첫 자음 뒤에 위치. 0번(받침 없음, `'\0'`)은 제외
+            if let Some(i) = KO_FINAL_CONSONANTS
+                .iter()
+                .skip(1)
+                .position(|v| *v == c)
+                .map(|v| v + 1)
+            {
+                let first = KO_SEPARATED_FINAL_CONSONANTS[i][0];
+                let cho = KO_CONSONANTS.iter().position(|v| *v == first).unwrap_or(0);
+
+                return [1, cho as u8, 0, i as u8];
+            }
+
+            // 모음만 있는 글자는 모든 자음 뒤에 위치
+            if let Some(i) = KO_VOWELS.iter().position(|v| *v == c) {
+                return [1, KO_CONSONANTS.len() as u8, (i + 1) as u8, 0];
+            }
+
+            let [_, a, b, c] = (c as u32).to_be_bytes();
+
+            [4, a, b, c]
+        }
+    }
+}
+
+/// 한글 가나다순 정렬을 위한 정렬 키 생성
+///
+/// 완성형 한글을 초성, 중성, 종성 순서로 분해한 값을 키로 사용하므로, 코드 포인트 순서와 달리 호환 자모
+/// (e.g. `ㄱ`, `ㄳ`, `ㅏ`)도 가나다순으로 정렬된다. 생성된 키는 바이트 단위 비교([Ord])로 정렬한다.
+///
+/// 정렬 규칙은 아래와 같다.
+///
+/// - 글자 분류 순서 : 한글 < 영문 < 숫자 < 그 외 문자(공백, 기호 등. 코드 포인트 순)
+/// - 한글 : 초성 > 중성 > 종성 순으로 비교. 자음만 있는 글자(e.g. `ㄱ`)는 같은 초성의 음절(e.g. `가`)보다 앞서며,
+///   모음만 있는 글자(e.g. `ㅏ`)는 모든 자음 뒤에 위치한다.
+/// - 영문 : 대소문자를 구분하지 않고 비교한 후, 나머지가 모두 같으면 대문자가 뒤에 위치한다.
+/// - 숫자 : 자릿값이 아닌 글자 단위로 비교한다. (e.g. `"10"` < `"9"`)
+/// - 한 문자열이 다른 문자열의 접두사이면 짧은 쪽이 앞에 위치한다.
+///
+/// # Arguments
+///
+/// - `s` - 대상 문자열
+///
+/// # Return
+///
+/// - 정렬 키
+///
+/// # Link
+///
+/// - [korean_compare]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::korean_sort_key;
+///
+/// let mut words = vec!["하늘", "apple", "바다", "가방", "123"];
+///
+/// words.sort_by_cached_key(|v| korean_sort_key(v));
+///
+/// assert_eq!(vec!["가방", "바다", "하늘", "apple", "123"], words);
+/// ```
+pub fn korean_sort_key(s: &str) -> Vec<u8> {
+    let mut key: Vec<u8> = s.chars().flat_map(korean_primary_key).collect();
+
+    // 1차 키가 같을 경우 대소문자(소문자 우선)로 비교
+    key.push(0);
+    key.extend(s.chars().map(|c| c.is_ascii_uppercase() as u8));
+
+    key
+}
+
+/// 한글 가나다순 비교
+///
+/// [korean_sort_key]로 생성한 정렬 키를 비교하며, 정렬 규칙은 [korean_sort_key]와 같다. 여러 번 비교하는
+/// 정렬에서는 [slice::sort_by_cached_key]와 [korean_sort_key]를 사용하는 것이 효율적이다.
+///
+/// # Arguments
+///
+/// - `a` - 비교 대상 문자열
+/// - `b` - 비교 대상 문자열
+///
+/// # Return
+///
+/// - 비교 결과 [std::cmp::Ordering]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::korean_compare;
+/// use std::cmp::Ordering;
+///
+/// let mut words = vec!["하늘", "바다", "가방"];
+///
+/// words.sort_by(|a, b| korean_compare(a, b));
+///
+/// assert_eq!(vec!["가방", "바다", "하늘"], words);
+/// assert_eq!(Ordering::Less, korean_compare("ㄱ", "가"));
+/// ```
+pub fn korean_compare(a: &str, b: &str) -> std::cmp::Ordering {
+    korean_sort_key(a).cmp(&korean_sort_key(b))
+}
+
+/// 글자(`char`) 위치 기준으로 부분 문자열 반환
+///
+/// 바이트 위치 슬라이싱(`&s[a..b]`)은 글자 경계가 아니면 panic이 발생하지만, 이 함수는 글자 위치를 바이트
+/// 위치로 변환하므로 한글이나 이모지가 포함된 문자열에서도 안전하다. 새 문자열을 할당하지 않고 원본의 slice를
+/// 반환한다.
+///
+/// # Arguments
+///
+/// - `s` - 대상 문자열
+/// - `start_char` - 시작 위치(`char` 기준, 포함)
+/// - `end_char` - 종료 위치(`char` 기준, 미포함)
+///
+/// # Return
+///
+/// - 부분 문자열. `start_char`가 `end_char`보다 크거나 `end_char`가 글자 수를 넘으면 `None`
+///
+/// # Link
+///
+/// - [str::char_indices]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::substring;
+///
+/// assert_eq!(Some("글A"), substring("한글ABC", 1, 3));
+/// assert_eq!(Some(""), substring("한글ABC", 5, 5));
+/// assert_eq!(None, substring("한글ABC", 3, 6));
+/// assert_eq!(None, substring("한글ABC", 3, 1));
+/// ```
+pub fn substring(s: &str, start_char: usize, end_char: usize) -> Option<&str> {
+    if start_char > end_char {
+        return None;
+    }
+
+    // 글자 위치 => 바이트 위치. 글자 수와 같은 위치는 문자열 끝
+    let byte_index = |s: &str, n: usize| {
+        s.char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(s.len()))
+            .nth(n)
+    };
+    let start = byte_index(s, start_char)?;
+    let end = start + byte_index(&s[start..], end_char - start_char)?;
+
+    Some(&s[start..end])
+}
+
+/// 문자(`char`)별 출현 빈도 집계
+///
+/// 공백과 문장부호를 포함한 모든 문자를 집계한다. 빈 문자열은 빈 [HashMap]을 반환한다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+///
+/// # Return
+///
+/// - 문자별 출현 횟수
+///
+/// # Link
+///
+/// - [word_frequency]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::char_frequency;
+///
+/// let result = char_frequency("가나가a");
+///
+/// assert_eq!(2, result[&'가']);
+/// assert_eq!(1, result[&'a']);
+/// ```
+pub fn char_frequency(text: &str) -> HashMap<char, usize> {
+    let mut result: HashMap<char, usize> = HashMap::new();
+
+    text.chars()
+        .for_each(|c| *result.entry(c).or_default() += 1);
+
+    result
+}
+
+/// 단어별 출현 빈도 집계
+///
+/// 글자나 숫자([char::is_alphanumeric])가 아닌 모든 문자(유니코드 공백, 문장부호, 기호 등)를 구분자로 하여
+/// 단어를 분리한다. 대소문자를 구분하며, 무시하려면 [word_frequency_ignore_case]를 사용한다.
+/// 빈 문자열은 빈 [HashMap]을 반환한다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+///
+/// # Return
+///
+/// - 단어별 출현 횟수
+///
+/// # Link
+///
+/// - [word_frequency_ignore_case]
+/// - [top_n_words]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::word_frequency;
+///
+/// let result = word_frequency("Rust는 빠르다. Rust, rust! Rust?");
+///
+/// assert_eq!(2, result["Rust"]);
+/// assert_eq!(1, result["rust"]);
+/// assert_eq!(1, result["Rust는"]);
+/// ```
+pub fn word_frequency(text: &str) -> HashMap<String, usize> {
+    word_frequency_with(text, false)
+}
+
+/// 대소문자를 무시하고 단어별 출현 빈도 집계
+///
+/// 단어를 [str::to_lowercase]로 변환한 후 집계하며, 그 외에는 [word_frequency]와 같다. 결과의 key는 소문자이다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+///
+/// # Return
+///
+/// - 단어(소문자)별 출현 횟수
+///
+/// # Link
+///
+/// - [word_frequency]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::word_frequency_ignore_case;
+///
+/// assert_eq!(3, word_frequency_ignore_case("Rust, rust! RUST")["rust"]);
+/// ```
+pub fn word_frequency_ignore_case(text: &str) -> HashMap<String, usize> {
+    word_frequency_with(text, true)
+}
+
+fn word_frequency_with(text: &str, ignore_case: bool) -> HashMap<String, usize> {
+    let mut result: HashMap<String, usize> = HashMap::new();
+
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|v| !v.is_empty())
+    {
+        let word = if ignore_case {
+            word.to_lowercase()
+        } else {
+            word.to_owned()
+        };
+
+        *result.entry(word).or_default() += 1;
+    }
+
+    result
+}
+
+/// 빈도 집계 결과에서 가장 빈번한 `n`개 단어 반환
+///
+/// 출현 횟수 내림차순으로 정렬하며, 횟수가 같으면 단어의 사전순(코드 포인트 순)으로 정렬하므로 결과는 항상
+/// 같다. `n`이 단어 수보다 크면 전체를 반환한다.
+///
+/// # Arguments
+///
+/// - `frequency` - [word_frequency] 혹은 [word_frequency_ignore_case] 결과
+/// - `n` - 반환할 단어 수
+///
+/// # Return
+///
+/// - `(단어, 출현 횟수)` 목록
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{top_n_words, word_frequency};
+///
+/// let frequency = word_frequency("a b c a b a 가 가 가 가");
+///
+/// assert_eq!(
+///     vec![("가".to_owned(), 4), ("a".to_owned(), 3)],
+///     top_n_words(&frequency, 2)
+/// );
+/// ```
+pub fn top_n_words(frequency: &HashMap<String, usize>, n: usize) -> Vec<(String, usize)> {
+    let mut result: Vec<(String, usize)> = frequency
+        .iter()
+        .map(|(word, count)| (word.clone(), *count))
+        .collect();
+
+    result.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    result.truncate(n);
+
+    result
+}
+
+/// 지정된 괄호 쌍들이 올바르게 열고 닫혔는지 검사
+///
+/// 스택 기반으로 검사하며 `pairs`에 지정되지 않은 문자는 무시한다. 여는 문자와 닫는 문자가 같은 쌍(e.g. `('|', '|')`)도
+/// 지정할 수 있다. 문자열 리터럴 내부의 괄호를 무시하려면 [is_balanced_with_option]을 사용한다.
+///
+/// # Arguments
+///
+/// - `text` - 검사 대상 문자열
+/// - `pairs` - `(여는 문자, 닫는 문자)` 목록
+///
+/// # Return
+///
+/// - 균형 여부. 빈 문자열은 `true`
+///
+/// # Link
+///
+/// - [is_balanced_brackets]
+/// - [is_balanced_with_option]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::is_balanced;
+///
+/// let pairs = [('(', ')'), ('<', '>')];
+///
+/// assert!(is_balanced("<a(b)>", &pairs));
+/// assert!(!is_balanced("<a(b>)", &pairs));
+/// assert!(is_balanced("[(a)", &pairs)); // 지정되지 않은 '['는 무시
+/// ```
+pub fn is_balanced(text: &str, pairs: &[(char, char)]) -> bool {
+    is_balanced_with_option(text, pairs, false)
+}
+
+/// 기본 괄호 쌍([DEFAULT_BRACKET_PAIRS], `()`, `[]`, `{}`)의 균형 검사
+///
+/// # Arguments
+///
+/// - `text` - 검사 대상 문자열
+///
+/// # Return
+///
+/// - 균형 여부
+///
+/// # Link
+///
+/// - [is_balanced]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::is_balanced_brackets;
+///
+/// assert!(is_balanced_brackets("fn main() { let v = [1, (2 + 3)]; }"));
+/// assert!(!is_balanced_brackets("{[}]"));
+/// assert!(!is_balanced_brackets("())"));
+/// ```
+pub fn is_balanced_brackets(text: &str) -> bool {
+    is_balanced(text, DEFAULT_BRACKET_PAIRS)
+}
+
+/// 문자열 리터럴 무시 여부를 지정하여 괄호 쌍의 균형 검사
+///
+/// `ignore_quoted`가 `true`이면 큰따옴표(`"`) 혹은 작은따옴표(`'`)로 감싼 문자열 리터럴 내부의 괄호를
+/// 무시한다. 리터럴 내부의 `\`는 다음 문자를 escape 한다. 이 경우 닫히지 않은 문자열 리터럴이 있으면
+/// `false`를 반환하므로 `it's`와 같이 작은따옴표가 단독으로 쓰이는 일반 문장에는 사용하지 않는다.
+///
+/// # Arguments
+///
+/// - `text` - 검사 대상 문자열
+/// - `pairs` - `(여는 문자, 닫는 문자)` 목록
+/// - `ignore_quoted` - 문자열 리터럴 내부 무시 여부
+///
+/// # Return
+///
+/// - 균형 여부
+///
+/// # Link
+///
+/// - [is_balanced]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::{is_balanced_with_option, DEFAULT_BRACKET_PAIRS};
+///
+/// let text = r#"print("(")"#;
+///
+/// assert!(!is_balanced_with_option(text, DEFAULT_BRACKET_PAIRS, false));
+/// assert!(is_balanced_with_option(text, DEFAULT_BRACKET_PAIRS, true));
+/// ```
+pub fn is_balanced_with_option(text: &str, pairs: &[(char, char)], ignore_quoted: bool) -> bool {
+    let mut stack: Vec<char> = vec![];
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for c in text.chars() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+
+            continue;
+        }
+
+        if ignore_quoted && (c == '"' || c == '\'') {
+            quote = Some(c);
+
+            continue;
+        }
+
+        let closes_top = stack
+            .last()
+            .is_some_and(|top| pairs.iter().any(|(o, e)| o == top && *e == c));
+
+        if closes_top {
+            stack.pop();
+        } else if pairs.iter().any(|(o, _)| *o == c) {
+            stack.push(c);
+        } else if pairs.iter().any(|(_, e)| *e == c) {
+            return false;
+        }
+    }
+
+    stack.is_empty() && quote.is_none()
+}
+
+/// 구분자로 연결된 `key=value` 문자열을 [HashMap]으로 파싱
+///
+/// 설정 문자열(e.g. `"a=1;b=2"`)을 파싱하며 처리 정책은 다음과 같다.
+///
+/// - `pair_sep`으로 토큰을 나누고, 각 토큰을 처음 나오는 `kv_sep` 기준으로 key와 value로 나눈다.
+///   (`"a=b=c"` -> `a`: `"b=c"`)
+/// - key와 value의 앞뒤 공백은 제거한다.
+/// - 빈 토큰(e.g. `"a=1;;b=2"`, 끝의 `;`)은 무시한다.
+/// - value는 비어 있을 수 있다. (`"a="` -> `a`: `""`)
+/// - 중복 key는 마지막 값이 우선한다.
+/// - `kv_sep`이 없는 토큰이나 빈 key는 오류로 처리한다.
+///
+/// 쿼리스트링처럼 URL 인코딩된 값은 [parse_key_value_with_option]을 사용한다.
+///
+/// # Arguments
+///
+/// - `input` - 파싱 대상 문자열
+/// - `pair_sep` - 쌍 구분자 (e.g. `;`, `&`)
+/// - `kv_sep` - key/value 구분자 (e.g. `=`)
+///
+/// # Return
+///
+/// - 파싱 결과 `Result<HashMap<String, String>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `kv_sep`이 없는 토큰 혹은 빈 key
+///
+/// # Link
+///
+/// - [parse_key_value_with_option]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::parse_key_value;
+///
+/// let result = parse_key_value("a=1; b=2;c=;a=3", ';', '=').unwrap();
+///
+/// assert_eq!("3", result["a"]);
+/// assert_eq!("2", result["b"]);
+/// assert_eq!("", result["c"]);
+/// assert!(parse_key_value("a=1;b", ';', '=').is_err());
+/// ```
+pub fn parse_key_value(
+    input: &str,
+    pair_sep: char,
+    kv_sep: char,
+) -> Result<HashMap<String, String>, InvalidArgumentError> {
+    parse_key_value_with_option(input, pair_sep, kv_sep, false)
+}
+
+/// URL 디코딩 여부를 지정하여 `key=value` 문자열을 [HashMap]으로 파싱
+///
+/// `url_decode`가 `true`이면 key와 value를 나눈 후 각각 URL 디코딩(`%XX` 및 `+` -> 공백)한다. 그 외의 처리
+/// 정책은 [parse_key_value]와 같다.
+///
+/// # Arguments
+///
+/// - `input` - 파싱 대상 문자열
+/// - `pair_sep` - 쌍 구분자
+/// - `kv_sep` - key/value 구분자
+/// - `url_decode` - URL 디코딩 여부
+///
+/// # Return
+///
+/// - 파싱 결과 `Result<HashMap<String, String>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `kv_sep`이 없는 토큰, 빈 key, 잘못된 `%XX` 형식 혹은 디코딩 결과가
+///   UTF-8이 아닐 경우
+///
+/// # Link
+///
+/// - [parse_key_value]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::parse_key_value_with_option;
+///
+/// let result = parse_key_value_with_option("q=%ED%95%9C%EA%B8%80+rust&page=2", '&', '=', true).unwrap();
+///
+/// assert_eq!("한글 rust", result["q"]);
+/// assert_eq!("2", result["page"]);
+/// ```
+pub fn parse_key_value_with_option(
+    input: &str,
+    pair_sep: char,
+    kv_sep: char,
+    url_decode: bool,
+) -> Result<HashMap<String, String>, InvalidArgumentError> {
+    let mut result: HashMap<String, String> = HashMap::new();
+
+    for token in input
+        .split(pair_sep)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        let (key, value) = token.split_once(kv_sep).ok_or_else(|| {
+            InvalidArgumentError::new(
+                format!("구분자({})가 없는 항목입니다 : {}", kv_sep, token).as_str(),
+            )
+        })?;
+        let (key, value) = if url_decode {
+            (
+                url_decode_component(key.trim())?,
+                url_decode_component(value.trim())?,
+            )
+        } else {
+            (key.trim().to_owned(), value.trim().to_owned())
+        };
+
+        if key.is_empty() {
+            return Err(InvalidArgumentError::new(
+                format!("key가 비어 있습니다 : {}", token).as_str(),
+            ));
+        }
+
+        result.insert(key, value);
+    }
+
+    Ok(result)
+}
+
+/// `application/x-www-form-urlencoded` 형식 디코딩(`%XX` 및 `+` -> 공백)
+fn url_decode_component(s: &str) -> Result<String, InvalidArgumentError> {
+    let bytes = s.as_bytes();
+    let mut result: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => result.push(b' '),
+            b'%' => {
+                let decoded = bytes
+                    .get(i + 1..i + 3)
+                    .filter(|v| v.iter().all(u8::is_ascii_hexdigit))
+                    .and_then(|v| std::str::from_utf8(v).ok())
+                    .and_then(|v| u8::from_str_radix(v, 16).ok())
+                    .ok_or_else(|| {
+                        InvalidArgumentError::new(
+                            format!("잘못된 URL 인코딩입니다 : {}", s).as_str(),
+                        )
+                    })?;
+
+                result.push(decoded);
+                i += 2;
+            }
+            v => result.push(v),
+        }
+
+        i += 1;
+    }
+
+    String::from_utf8(result)
+        .map_err(|_| InvalidArgumentError::new(format!("UTF-8 문자열이 아닙니다 : {}", s).as_str()))
+}
+
+/// 각 줄 앞에 우측 정렬된 줄 번호와 구분자(`" | "`) 추가
+///
+/// 구분자를 지정하려면 [number_lines_with_separator]를 사용한다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `start` - 첫 줄 번호 (e.g. `1`)
+/// - `width` - 줄 번호 최소 폭
+///
+/// # Return
+///
+/// - 줄 번호가 추가된 문자열
+///
+/// # Link
+///
+/// - [number_lines_with_separator]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number_lines;
+///
+/// assert_eq!("  1 | fn main() {\n  2 | }\n", number_lines("fn main() {\n}\n", 1, 3));
+/// ```
+pub fn number_lines(text: &str, start: usize, width: usize) -> String {
+    number_lines_with_separator(text, start, width, " | ")
+}
+
+/// 각 줄 앞에 우측 정렬된 줄 번호와 지정된 구분자 추가
+///
+/// 줄 번호 정책은 다음과 같다.
+///
+/// - 줄은 `\n` 기준으로 나누며 각 줄의 개행 문자(`\n` 혹은 `\r\n`)와 마지막 줄의 개행 유무를 그대로 유지한다.
+///   마지막 개행 뒤에는 줄 번호를 붙이지 않는다.
+/// - 빈 문자열은 빈 문자열을 반환한다.
+/// - `width`는 최소 폭이다. 마지막 줄 번호의 자릿수가 `width`보다 크면 모든 줄이 정렬되도록 폭을 마지막 줄 번호의
+///   자릿수로 자동 확장한다. (번호를 잘라내지 않는다)
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `start` - 첫 줄 번호
+/// - `width` - 줄 번호 최소 폭
+/// - `separator` - 줄 번호와 내용 사이의 구분자
+///
+/// # Return
+///
+/// - 줄 번호가 추가된 문자열
+///
+/// # Link
+///
+/// - [number_lines]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number_lines_with_separator;
+///
+/// let text = "a\nb\nc";
+///
+/// assert_eq!(" 9: a\n10: b\n11: c", number_lines_with_separator(text, 9, 1, ": "));
+/// ```
+pub fn number_lines_with_separator(
+    text: &str,
+    start: usize,
+    width: usize,
+    separator: &str,
+) -> String {
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+
+    if lines.is_empty() {
+        return String::new();
+    }
+
+    let last = start + lines.len() - 1;
+    let width = width.max(last.to_string().len());
+    let mut result = String::with_capacity(text.len() + lines.len() * (width + separator.len()));
+
+    for (i, line) in lines.iter().enumerate() {
+        result.push_str(format!("{:>width$}", start + i, width = width).as_str());
+        result.push_str(separator);
+        result.push_str(line);
+    }
+
+    result
+}
+
+/// ASCII 알파벳(`A-Z`)만 소문자로 변환하고 나머지 문자는 그대로 유지
+///
+/// 식별자, 헤더 이름, 프로토콜 토큰 등 ASCII 규칙으로 정규화해야 하는 값에 사용한다. [str::to_lowercase]와의
+/// 차이는 다음과 같다.
+///
+/// - [str::to_lowercase]는 유니코드 규칙으로 모든 문자를 변환한다. 터키어 `'İ'`(U+0130)는 `"i̇"`(`i` + 결합
+///   점, 2 char)가 되고 `'Σ'`는 위치에 따라 `'σ'` 혹은 `'ς'`가 되는 등 결과 길이와 형태가 입력과 달라질 수 있다.
+/// - 이 함수는 ASCII 알파벳만 변환하므로 `'İ'`, `'ı'`, `'É'`, 한글 등은 그대로 유지되며 결과의 byte 길이가
+///   항상 입력과 같다.
+///
+/// [str::to_ascii_lowercase]와 결과가 같으며, 의도를 명시적으로 드러내기 위한 함수이다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Link
+///
+/// - [to_ascii_upper_preserving]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::to_ascii_lower_preserving;
+///
+/// assert_eq!("content-type: İstanbul → istanbul", to_ascii_lower_preserving("Content-Type: İstanbul → ISTANBUL"));
+/// assert_eq!("İ".len() + 1, "İ".to_lowercase().len()); // 유니코드 변환은 길이가 달라짐
+/// assert_eq!("İ", to_ascii_lower_preserving("İ"));
+/// ```
+pub fn to_ascii_lower_preserving(target: &str) -> String {
+    target.to_ascii_lowercase()
+}
+
+/// ASCII 알파벳(`a-z`)만 대문자로 변환하고 나머지 문자는 그대로 유지
+///
+/// [str::to_uppercase]는 `'ß'`를 `"SS"`로, `'ı'`(터키어 점 없는 i)를 `'I'`로 변환하는 등 유니코드 규칙을
+/// 적용하지만, 이 함수는 ASCII 알파벳만 변환하므로 결과의 byte 길이가 항상 입력과 같다. 자세한 내용은
+/// [to_ascii_lower_preserving] 참고.
+///
+/// # Arguments
+///
+/// - `target` - 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Link
+///
+/// - [to_ascii_lower_preserving]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::to_ascii_upper_preserving;
+///
+/// assert_eq!("STRASSE", "straße".to_uppercase());
+/// assert_eq!("STRAßE", to_ascii_upper_preserving("straße"));
+/// ```
+pub fn to_ascii_upper_preserving(target: &str) -> String {
+    target.to_ascii_uppercase()
+}
+
+/// 문자열에서 키워드가 출현하는 위치를 `prefix`, `suffix`로 감싸서 강조
+///
+/// 대소문자를 구분하며, 무시하려면 [highlight_with_option]을 사용한다. 처리 정책은 [highlight_with_option] 참고.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `keywords` - 강조할 키워드 목록
+/// - `prefix` - 키워드 앞에 삽입할 문자열 (e.g. `<mark>`)
+/// - `suffix` - 키워드 뒤에 삽입할 문자열 (e.g. `</mark>`)
+///
+/// # Return
+///
+/// - 강조 결과
+///
+/// # Link
+///
+/// - [highlight_with_option]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::highlight;
+///
+/// assert_eq!(
+///     "<mark>Rust</mark> 문자열 <mark>유틸</mark>",
+///     highlight("Rust 문자열 유틸", &["Rust", "유틸"], "<mark>", "</mark>")
+/// );
+/// ```
+pub fn highlight(text: &str, keywords: &[&str], prefix: &str, suffix: &str) -> String {
+    highlight_with_option(text, keywords, prefix, suffix, false)
+}
+
+/// 대소문자 무시 여부를 지정하여 키워드 출현 위치를 `prefix`, `suffix`로 강조
+///
+/// 처리 정책은 다음과 같다.
+///
+/// - 앞에서부터 탐색하며 한 위치에서 여러 키워드가 일치하면 가장 긴 키워드를 우선한다. 강조된 구간은 다시
+///   검사하지 않으므로 키워드가 겹치는 경우 먼저 시작하는 키워드가 강조된다.
+/// - `ignore_case`가 `true`이면 문자 단위로 대소문자를 무시하여 비교하며, 강조 결과에는 원본 문자의 대소문자를
+///   그대로 유지한다.
+/// - 이미 `prefix`와 `suffix`로 감싸진 구간은 그대로 유지하므로 결과를 다시 강조해도 마커가 중복되지 않는다.
+///   (`prefix`, `suffix`가 모두 비어 있지 않은 경우)
+/// - 빈 키워드는 무시하며, 연속된 일치 구간은 각각 감싼다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `keywords` - 강조할 키워드 목록
+/// - `prefix` - 키워드 앞에 삽입할 문자열
+/// - `suffix` - 키워드 뒤에 삽입할 문자열
+/// - `ignore_case` - 대소문자 무시 여부
+///
+/// # Return
+///
+/// - 강조 결과
+///
+/// # Link
+///
+/// - [highlight]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::highlight_with_option;
+///
+/// let result = highlight_with_option("New York, new", &["new york", "new"], "[", "]", true);
+///
+/// assert_eq!("[New York], [new]", result);
+/// assert_eq!(result, highlight_with_option(&result, &["new york", "new"], "[", "]", true));
+/// ```
+pub fn highlight_with_option(
+    text: &str,
+    keywords: &[&str],
+    prefix: &str,
+    suffix: &str,
+    ignore_case: bool,
+) -> String {
+    let mut keywords: Vec<Vec<char>> = keywords
+        .iter()
+        .filter(|v| !v.is_empty())
+        .map(|v| v.chars().collect())
+        .collect();
+
+    keywords.sort_by_key(|v| std::cmp::Reverse(v.len()));
+
+    let chars: Vec<char> = text.chars().collect();
+    let prefix_chars: Vec<char> = prefix.chars().collect();
+    let suffix_chars: Vec<char> = suffix.chars().collect();
+    let eq = |a: &char, b: &char| {
+        if ignore_case {
+            a.to_lowercase().eq(b.to_lowercase())
+        } else {
+            a == b
+        }
+    };
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        // 이미 강조된 구간은 그대로 유지
+        if !prefix_chars.is_empty()
+            && !suffix_chars.is_empty()
+            && chars[i..].starts_with(&prefix_chars)
+        {
+            let body = i + prefix_chars.len();
+            let closed = chars[body..]
+                .windows(suffix_chars.len())
+                .position(|v| v == suffix_chars.as_slice());
+
+            if let Some(pos) = closed {
+                let end = body + pos + suffix_chars.len();
+
+                result.extend(&chars[i..end]);
+                i = end;
+
+                continue;
+            }
+        }
+
+        let matched = keywords.iter().find(|k| {
+            chars.len() - i >= k.len()
+                && chars[i..i + k.len()]
+                    .iter()
+                    .zip(k.iter())
+                    .all(|(a, b)| eq(a, b))
+        });
+
+        match matched {
+            Some(k) => {
+                result.push_str(prefix);
+                result.extend(&chars[i..i + k.len()]);
+                result.push_str(suffix);
+                i += k.len();
+            }
+            None => {
+                result.push(chars[i]);
+                i += 1;
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LibError;
+
+    #[test]
+    fn validate_email_test() {
+        let mut email = "joonho.son@me.com";
+        let result = validate_email(Some(email));
+
+        assert!(!result.is_err());
+        assert!(
+            validate_email(Some(email)).unwrap(),
+            "정상적인 이메일 유효성 검사 실패"
+        );
+
+        email = "test@test";
+
+        assert!(!validate_email(Some(email)).unwrap());
+
+        email = "test@test.";
+
+        assert!(!validate_email(Some(email)).unwrap());
+
+        email = "";
+
+        assert!(!validate_email(Some(email)).unwrap());
+
+        assert!(validate_email(None).is_err());
+
+        // 반환되는 에러가 ValidateError인제 확인
+        assert_eq!(
+            validate_email(None).unwrap_err(),
+            MissingArgumentError::default(),
+            "에러 불일치"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_email_should_panic_test() {
+        validate_email(None).unwrap();
+    }
+
+    #[test]
+    fn korean_domain_fail_test() {
+        let mut email = "한글ID@test.com";
+
+        assert!(
+            !validate_email(Some(email)).is_err(),
+            "한글 ID를 포함하는 이메일 검사 실패"
+        );
+
+        email = "test@한글도메인.com";
+
+        assert!(
+            !validate_email(Some(email)).is_err(),
+            "한글 도메인을 포함하는 이메일 검사 실패"
+        );
+
+        email = "홍길동@한글도메인.com";
+
+        assert!(
+            !validate_email(Some(email)).is_err(),
+            "한글 ID 및 한글 도메인을 포함하는 이메일 검사 실패"
+        );
+    }
+
+    #[test]
+    fn extract_initial_consonant_test() {
+        let mut target = "한글만 있습니다.";
+        let mut result = extract_initial_consonant(Some(target)).unwrap();
+
+        println!("extract result : {}", result);
+
+        assert_eq!(
+            "ㅎㄱㅁ ㅇㅅㄴㄷ.",
+            result.as_str(),
+            "한글만 있을 경우 초성 추출 실패"
+        );
+
+        target = "한글과 English가 함께 있습니다.";
+        result = extract_initial_consonant(Some(target)).unwrap();
+
+        println!("extract result : {}", result);
+
+        assert_eq!(
+            "ㅎㄱㄱ Englishㄱ ㅎㄲ ㅇㅅㄴㄷ.",
+            result.as_str(),
+            "한글과 영어가 혼재되어 있을 경우 추출 실패"
+        );
+
+        target = "세종대왕(世宗大王)";
+        result = extract_initial_consonant(Some(target)).unwrap();
+
+        println!("extract result : {}", result);
+
+        assert_eq!(
+            "ㅅㅈㄷㅇ(世宗大王)",
+            result.as_str(),
+            "한글과 한자가 혼재되어 있을 경우 추출 실패"
+        );
+
+        target = "이건 이모티콘(❤😑😊😂)을 포함합니다.";
+        result = extract_initial_consonant(Some(target)).unwrap();
+
+        println!("extract result : {}", result);
+
+        assert_eq!(
+            "ㅇㄱ ㅇㅁㅌㅋ(❤😑😊😂)ㅇ ㅍㅎㅎㄴㄷ.",
+            result.as_str(),
+            "한글과 이모티콘이 혼재되어 있을 경우 추출 실패"
+        );
+    }
+
+    #[test]
+    fn separate_consonant_vowel_test() {
+        let mut target = "한글만";
+        let mut result = separate_simple_consonant_vowel(Some(target)).unwrap();
+
+        println!("separate result : {}", result);
+
+        assert_eq!(
+            "ㅎㅏㄴㄱㅡㄹㅁㅏㄴ",
+            result.as_str(),
+            "한글만 있는 초/중/종성 분리 실패"
+        );
+
+        target = "한글과 English가 함께";
+        result = separate_simple_consonant_vowel(Some(target)).unwrap();
+
+        println!("separate result : {}", result);
+
+        assert_eq!(
+            "ㅎㅏㄴㄱㅡㄹㄱㅘ Englishㄱㅏ ㅎㅏㅁㄲㅔ",
+            result.as_str(),
+            "한글과 영어가 혼재되어 있을 경우 초/중/종성 분리 실패"
+        );
+
+        target = "맑음";
+        result = separate_simple_consonant_vowel(Some(target)).unwrap();
+
+        println!("separate result : {}", result);
+
+        assert_eq!(
+            "ㅁㅏㄺㅇㅡㅁ",
+            result.as_str(),
+            "겹받침이 있을 경우 초/중/종성 분리 실패"
+        );
+
+        target = "많이 주세요.";
+        result = separate_simple_consonant_vowel(Some(target)).unwrap();
+
+        println!("separate result : {}", result);
+
+        assert_eq!(
+            "ㅁㅏㄶㅇㅣ ㅈㅜㅅㅔㅇㅛ.",
+            result.as_str(),
+            "겹받침이 있을 경우 초/중/종성 분리 실패"
+        );
+
+        target = "꽊꽊이";
+        result = separate_simple_consonant_vowel(Some(target)).unwrap();
+
+        println!("separate result : {}", result);
+
+        assert_eq!("ㄲㅘㄲㄲㅘㄲㅇㅣ", result.as_str());
+    }
+
+    #[test]
+    fn separate_consonant_vowel_completely_test() {
+        let mut target = "한글만";
+        let mut result = separate_consonant_vowel_completely(Some(target)).unwrap();
+
+        println!("separate result : {}", result);
+
+        assert_eq!(
+            "ㅎㅏㄴㄱㅡㄹㅁㅏㄴ",
+            result.as_str(),
+            "한글만 있는 초/중/종성 분리 실패"
+        );
+
+        target = "꽊꽊이";
+        result = separate_consonant_vowel_completely(Some(target)).unwrap();
+
+        println!("separate result : {}", result);
+
+        assert_eq!(
+            "ㄱㄱㅗㅏㄱㄱㄱㄱㅗㅏㄱㄱㅇㅣ",
+            result.as_str(),
+            "쌍자음, 이중 모음이 있을 경우 분리 실패"
+        );
+
+        target = "꽊많이 줬으면 좋겠어요1❤❤.";
+        result = separate_consonant_vowel_completely(Some(target)).unwrap();
+
+        println!("separate result : {}", result);
+
+        assert_eq!(
+            "ㄱㄱㅗㅏㄱㄱㅁㅏㄴㅎㅇㅣ ㅈㅜㅓㅅㅅㅇㅡㅁㅕㄴ ㅈㅗㅎㄱㅔㅅㅅㅇㅓㅇㅛ1❤❤.",
+            result.as_str(),
+            "쌍자음, 이중모음이 있을 경우 분리 실패"
+        );
+
+        target =
+            r#""투표율을 40%(percentage) 초중반대는 충분히 되지 않을까 생각한다"며 말문을 뗐다."#;
+        result = separate_consonant_vowel_completely(Some(target)).unwrap();
+
+        println!("separate result : {}", result);
+
+        assert_eq!(
+            r#""ㅌㅜㅍㅛㅇㅠㄹㅇㅡㄹ 40%(percentage) ㅊㅗㅈㅜㅇㅂㅏㄴㄷㅐㄴㅡㄴ ㅊㅜㅇㅂㅜㄴㅎㅣ ㄷㅗㅣㅈㅣ ㅇㅏㄴㅎㅇㅡㄹㄱㄱㅏ ㅅㅐㅇㄱㅏㄱㅎㅏㄴㄷㅏ"ㅁㅕ ㅁㅏㄹㅁㅜㄴㅇㅡㄹ ㄷㄷㅔㅅㅅㄷㅏ."#,
+            result.as_str(),
+            "쌍자음, 이중모음, 특수 기호를 포함하는 경우 분리 실패"
+        );
+    }
+
+    #[test]
+    fn random_string_test() {
+        let length = 17;
+        let result = generate_random_string(length);
+
+        assert!(result.is_some());
+
+        let result = result.unwrap();
+
+        assert_eq!(length, result.len() as u32);
+
+        println!(
+            "--------------------------\nrandom string result1: {}--------------------\n",
+            result
+        );
+
+        let length = 38;
+        let result = generate_random_string(length);
+
+        assert!(result.is_some());
+
+        let result = result.unwrap();
+
+        assert_eq!(length, result.len() as u32);
+
+        println!(
+            "--------------------------\nrandom string result2: {}\n--------------------\n",
+            result
+        );
+
+        loop {
+            let result: Option<String> = generate_random_string_with_spec(40);
+
+            if result.is_none() {
+                continue;
+            }
+
+            if result.as_ref().unwrap().contains("!") {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn to_title_case_test() {
+        assert_eq!(
+            "The Lord of the Rings",
+            to_title_case("the lord of the rings"),
+            "관사/전치사 예외 처리 실패"
+        );
+        assert_eq!(
+            "Of Mice and Men",
+            to_title_case("of mice and men"),
+            "첫 단어 대문자 처리 실패"
+        );
+        assert_eq!(
+            "What Are You Looking At",
+            to_title_case("what are you looking at"),
+            "마지막 단어 대문자 처리 실패"
+        );
+        assert_eq!("Élan and Über", to_title_case("élan and über"));
+        assert_eq!(
+            "반지의 제왕 the Return of the King",
+            to_title_case("반지의 제왕 the return of the king"),
+            "한글 혼합 처리 실패"
+        );
+        assert_eq!("", to_title_case(""));
+    }
+
+    #[test]
+    fn base62_test() {
+        assert_eq!("0", encode_base62(0));
+        assert_eq!(0, decode_base62("0").unwrap());
+        assert_eq!("Z", encode_base62(61));
+        assert_eq!("10", encode_base62(62));
+
+        let max = encode_base62(u64::MAX);
+
+        println!("u64::MAX base62 : {}", max);
+
+        assert_eq!("lYGhA16ahyf", max);
+        assert_eq!(u64::MAX, decode_base62(&max).unwrap(), "u64::MAX 왕복 실패");
+
+        for v in [
+            1u64,
+            61,
+            62,
+            3_843,
+            1_234_567_890,
+            9_876_543_210_123,
+            u64::MAX - 1,
+        ] {
+            assert_eq!(
+                v,
+                decode_base62(&encode_base62(v)).unwrap(),
+                "왕복 실패 : {}",
+                v
+            );
+        }
+
+        // u64::MAX + 1
+        assert!(decode_base62("lYGhA16ahyg").is_err(), "오버플로 검사 실패");
+        assert!(decode_base62("zzzzzzzzzzzz").is_err(), "오버플로 검사 실패");
+        assert!(decode_base62("").is_err());
+        assert!(decode_base62("abc-def").is_err(), "비허용 문자 검사 실패");
+        assert!(decode_base62("한글").is_err(), "비허용 문자 검사 실패");
+    }
+
+    #[test]
+    fn separate_consonant_vowel_with_mode_test() {
+        let target = "꽊한글 ABC";
+        let compatibility =
+            separate_consonant_vowel_with_mode(Some(target), JamoMode::Compatibility).unwrap();
+        let conjoining =
+            separate_consonant_vowel_with_mode(Some(target), JamoMode::Conjoining).unwrap();
+
+        println!(
+            "compatibility : {:?}\nconjoining : {:?}",
+            compatibility, conjoining
+        );
+
+        assert_eq!(
+            separate_consonant_vowel_completely(Some(target)).unwrap(),
+            compatibility,
+            "기본 동작 불일치"
+        );
+        assert_eq!(JamoMode::Compatibility, JamoMode::default());
+        assert_ne!(compatibility, conjoining, "자모 집합 구분 실패");
+
+        // 꽊 : ᄁ(U+1101) ᅪ(U+116A) ᆩ(U+11A9)
+        assert_eq!(
+            vec![
+                0x1101, 0x116A, 0x11A9, 0x1112, 0x1161, 0x11AB, 0x1100, 0x1173, 0x11AF, 0x20, 0x41,
+                0x42, 0x43
+            ],
+            conjoining.chars().map(|c| c as u32).collect::<Vec<u32>>(),
+            "첫가끝 자모 변환 실패"
+        );
+        assert!(compatibility
+            .chars()
+            .filter(|c| !c.is_ascii())
+            .all(|c| ('\u{3131}'..='\u{318E}').contains(&c)));
+
+        // 호환 자모는 그대로 반환
+        assert_eq!(
+            "ㄱㅏ",
+            separate_consonant_vowel_with_mode(Some("ㄱㅏ"), JamoMode::Conjoining).unwrap()
+        );
+        assert!(separate_consonant_vowel_with_mode(None, JamoMode::Conjoining).is_err());
+    }
+
+    #[test]
+    fn random_string_unambiguous_test() {
+        for _ in 0..100 {
+            let result = generate_random_string_unambiguous(64).unwrap();
+
+            assert_eq!(64, result.len());
+            assert!(
+                !AMBIGUOUS_CHARACTERS.iter().any(|c| result.contains(c)),
+                "혼동 문자 포함 : {}",
+                result
+            );
+
+            let result = generate_random_string_unambiguous_upper(64).unwrap();
+
+            assert_eq!(64, result.len());
+            assert!(
+                !AMBIGUOUS_CHARACTERS.iter().any(|c| result.contains(c)),
+                "혼동 문자 포함 : {}",
+                result
+            );
+            assert!(
+                result
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()),
+                "숫자/대문자 외 문자 포함 : {}",
+                result
+            );
+        }
+
+        assert_eq!("", generate_random_string_unambiguous(0).unwrap());
+    }
+
+    #[test]
+    fn safe_repeat_test() {
+        assert_eq!("====", safe_repeat("=", 4, 4).unwrap());
+        assert_eq!("", safe_repeat("abc", 0, 0).unwrap(), "count 0 처리 실패");
+        assert_eq!("", safe_repeat("", 100, 0).unwrap());
+
+        // 한글은 한 글자당 3 bytes
+        let result = safe_repeat("한글", 3, 18).unwrap();
+
+        assert_eq!("한글한글한글", result);
+        assert_eq!(18, result.len());
+        assert!(
+            safe_repeat("한글", 3, 17).is_err(),
+            "멀티바이트 길이 계산 실패"
+        );
+        assert!(safe_repeat("😊", 2, 7).is_err());
+
+        // overflow
+        assert!(
+            safe_repeat("ab", usize::MAX, usize::MAX).is_err(),
+            "overflow 검사 실패"
+        );
+    }
+
+    #[test]
+    fn display_width_test() {
+        assert_eq!(0, display_width(""));
+        assert_eq!(3, display_width("ABC"));
+        assert_eq!(4, display_width("한글"));
+        assert_eq!(7, display_width("한글ABC"), "한글 2자(4) + ABC(3)");
+        assert_eq!(6, display_width("世界ab"), "한자 2자(4) + ab(2)");
+        assert_eq!(2, display_width("a\tb"), "제어 문자");
+        assert_eq!(4, display_width("줄\n바"), "개행 문자");
+        assert_eq!(1, display_width("e\u{301}"), "결합 문자");
+        assert_eq!(2, display_width("😊"));
+        assert_eq!(2, display_width("👨‍👩‍👧"), "ZWJ 시퀀스");
+    }
+
+    #[test]
+    fn transform_lines_test() {
+        let trim_non_empty = |l: &str| Some(l.trim()).filter(|v| !v.is_empty()).map(String::from);
+
+        assert_eq!("", transform_lines("", trim_non_empty), "빈 입력");
+        assert_eq!("", transform_lines("\n\n  \n", trim_non_empty), "모두 제거");
+        assert_eq!(
+            "a\nb",
+            transform_lines(" a \n\n b ", trim_non_empty),
+            "마지막 개행 없음"
+        );
+        assert_eq!(
+            "a\nb\n",
+            transform_lines(" a \n\n b \n", trim_non_empty),
+            "마지막 개행 유지"
+        );
+        assert_eq!(
+            "a\nb\n",
+            transform_lines("a\r\n\r\nb\r\n", trim_non_empty),
+            "CRLF 정규화"
+        );
+        assert_eq!(
+            "1: 한글\n2: english",
+            transform_lines("1: 한글\n2: english", |l| Some(l.to_owned())),
+            "원본 유지"
+        );
+        assert_eq!(
+            "HELLO\nWORLD",
+            transform_lines("hello\n#comment\nworld", |l| {
+                if l.starts_with('#') {
+                    None
+                } else {
+                    Some(l.to_uppercase())
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn korean_number_test() {
+        let cases: Vec<(u64, &str)> = vec![
+            (0, "영"),
+            (1, "일"),
+            (10, "십"),
+            (11, "십일"),
+            (20, "이십"),
+            (101, "백일"),
+            (1_000, "천"),
+            (1_234, "천이백삼십사"),
+            (10_000, "만"),
+            (10_001, "만일"),
+            (21_000, "이만천"),
+            (3_200_000, "삼백이십만"),
+            (100_000_000, "일억"),
+            (100_010_000, "일억만"),
+            (1_000_000_000_000, "일조"),
+            (
+                u64::MAX,
+                "천팔백사십사경육천칠백사십사조칠백삼십칠억구백오십오만천육백십오",
+            ),
+        ];
+
+        for (num, text) in cases {
+            assert_eq!(text, number_to_korean(num), "{} 변환 실패", num);
+            assert_eq!(num, korean_to_number(text).unwrap(), "{} 역변환 실패", text);
+        }
+
+        for v in [7u64, 99, 12_345, 987_654_321, 1_000_000_007, u64::MAX - 1] {
+            assert_eq!(
+                v,
+                korean_to_number(&number_to_korean(v)).unwrap(),
+                "{} 왕복 실패",
+                v
+            );
+        }
+
+        // 일 생략하지 않은 표기와 공백 허용
+        assert_eq!(11_000, korean_to_number("일만 일천").unwrap());
+        assert_eq!(10, korean_to_number("일십").unwrap());
+
+        // 파싱 불가
+        assert!(korean_to_number("").is_err());
+        assert!(
+            korean_to_number("일금 삼백원").is_err(),
+            "한글 숫자 외 문자"
+        );
+        assert!(korean_to_number("이삼").is_err(), "연속된 숫자");
+        assert!(korean_to_number("십백").is_err(), "단위 순서 오류");
+        assert!(korean_to_number("만억").is_err(), "단위 순서 오류");
+        assert!(korean_to_number("영일").is_err());
+        assert!(korean_to_number("이천경").is_err(), "u64 범위 초과");
+    }
+
+    #[test]
+    fn similarity_ratio_test() {
+        assert_eq!(0, levenshtein_distance("", ""));
+        assert_eq!(3, levenshtein_distance("", "abc"));
+        assert_eq!(3, levenshtein_distance("kitten", "sitting"));
+        assert_eq!(1, levenshtein_distance("대한민국", "대한제국"));
+
+        assert_eq!(1.0, similarity_ratio("", ""));
+        assert_eq!(1.0, similarity_ratio("대한민국", "대한민국"));
+        assert_eq!(0.0, similarity_ratio("abc", "xyz"));
+        assert_eq!(0.0, similarity_ratio("", "abc"));
+
+        // char 단위로 계산되어야 함 (byte 단위일 경우 값이 달라짐)
+        assert_eq!(0.75, similarity_ratio("대한민국", "대한제국"));
+
+        let partial = similarity_ratio("kitten", "sitting");
+
+        assert!(partial > 0.5 && partial < 0.6, "부분 일치 : {}", partial);
+    }
+
+    #[test]
+    fn password_strength_test() {
+        let empty = password_strength("");
+
+        assert_eq!(0, empty.score);
+        assert_eq!(StrengthLevel::Weak, empty.level);
+
+        assert_eq!(StrengthLevel::Weak, password_strength("password").level);
+        assert_eq!(StrengthLevel::Weak, password_strength("Password").level);
+        assert_eq!(StrengthLevel::Weak, password_strength("aaaaaaa").level);
+        assert_eq!(StrengthLevel::Weak, password_strength("abcdefg").level);
+        assert_eq!(StrengthLevel::Fair, password_strength("sunflower7").level);
+
+        let strong = password_strength("aX9!mQ2#zL");
+
+        assert_eq!(StrengthLevel::Strong, strong.level);
+        assert!(strong.score <= 100);
+
+        // 한글은 자모 분해 길이 기준
+        assert_eq!(StrengthLevel::Weak, password_strength("가가가가").level);
+        assert_eq!(StrengthLevel::Fair, password_strength("햇살가득").level);
+        assert_eq!(
+            StrengthLevel::Strong,
+            password_strength("햇살가득2024!").level
+        );
+
+        // 반복/연속 패턴은 감점
+        assert!(password_strength("zq8wm3").score > password_strength("abc123").score);
+    }
+
+    #[test]
+    fn caesar_cipher_test() {
+        let target = "The Quick Brown Fox, 빠른 갈색 여우 2024!";
+
+        assert_eq!("Gur Dhvpx Oebja Sbk, 빠른 갈색 여우 2024!", rot13(target));
+        assert_eq!(target, rot13(&rot13(target)));
+
+        assert_eq!("xyz", caesar_cipher("abc", 23));
+        assert_eq!("XYZ", caesar_cipher("ABC", -3));
+        assert_eq!(caesar_cipher(target, 1), caesar_cipher(target, 27));
+        assert_eq!(caesar_cipher(target, -1), caesar_cipher(target, 25));
+        assert_eq!(target, caesar_cipher(target, 26));
+        assert_eq!(target, caesar_cipher(&caesar_cipher(target, 7), -7));
+        assert_eq!("cde", caesar_cipher("abc", i32::MIN)); // i32::MIN % 26 == -24
+        assert_eq!("", caesar_cipher("", 5));
+    }
+
+    #[test]
+    fn extract_emails_urls_test() {
+        let text = "담당자(joonho.son@example.com)와 admin@test.co.kr; 그리고 \
+                    user-1@mail.example.org. 중복 admin@test.co.kr, 잘못된 foo@bar, @none.com";
+
+        assert_eq!(
+            vec![
+                "joonho.son@example.com",
+                "admin@test.co.kr",
+                "user-1@mail.example.org",
+                "admin@test.co.kr"
+            ],
+            extract_emails(text, false)
+        );
+        assert_eq!(3, extract_emails(text, true).len());
+        assert!(extract_emails("이메일 없음", false).is_empty());
+
+        // 주소 뒤에 붙은 한글 조사
+        assert_eq!(
+            vec!["help@example.com"],
+            extract_emails("help@example.com으로 문의하세요", false)
+        );
+        assert_eq!(
+            vec!["admin@test.co.kr"],
+            extract_emails("admin@test.co.kr로 보내주세요", false)
+        );
+        assert_eq!(
+            vec!["a@b.io", "c@d.com"],
+            extract_emails("a@b.io와 c@d.com에게 전달", false)
+        );
+        // TLD 뒤에 영문/숫자가 이어지면 주소로 보지 않음
+        assert!(extract_emails("a@b.com1", false).is_empty());
+
+        // 기존 검증 결과는 유지
+        assert!(validate_email(Some("admin@test.co.kr")).unwrap());
+        assert!(!validate_email(Some("admin@test.co.kr.")).unwrap());
+
+        let text = "https://x.com. 링크 <http://a.b/c?d=1&e=2>, \"https://q.kr/x\" \
+                    HTTPS://X.COM/path! 또 https://x.com? 빈 링크 https://";
+
+        assert_eq!(
+            vec![
+                "https://x.com",
+                "http://a.b/c?d=1&e=2",
+                "https://q.kr/x",
+                "HTTPS://X.COM/path",
+                "https://x.com"
+            ],
+            extract_urls(text, false)
+        );
+        assert_eq!(4, extract_urls(text, true).len());
+        assert!(extract_urls("ftp://x.com", false).is_empty());
+    }
+
+    #[test]
+    fn parse_lenient_test() {
+        let cases: Vec<(&str, i64)> = vec![
+            ("1,234", 1234),
+            (" 42 ", 42),
+            ("３", 3),
+            ("１２，３４５", 12345),
+            ("－７", -7),
+            ("+10", 10),
+            ("1,000,000원", 1_000_000),
+            ("300 개", 300),
+            ("-9223372036854775808", i64::MIN),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                expected,
+                parse_lenient_i64(input).unwrap(),
+                "{:?} 파싱 실패",
+                input
+            );
+        }
+
+        for input in [
+            "",
+            "   ",
+            "원",
+            "1.5원",
+            "abc",
+            "12a3",
+            ",123",
+            "1-2",
+            "9223372036854775808",
+        ] {
+            let result = parse_lenient_i64(input);
+
+            assert!(result.is_err(), "{:?} 실패해야 함", input);
+            assert!(result
+                .unwrap_err()
+                .get_message()
+                .contains(&format!("{:?}", input)));
+        }
+
+        assert_eq!(1.5, parse_lenient_f64("1.5원").unwrap());
+        assert_eq!(1234.5, parse_lenient_f64(" 1,234.5 ").unwrap());
+        assert_eq!(0.25, parse_lenient_f64("０．２５").unwrap());
+        assert_eq!(-3.0, parse_lenient_f64("-3kg").unwrap());
+        assert!(parse_lenient_f64("1.2.3").is_err());
+        assert!(parse_lenient_f64("NaN").is_err());
+        assert!(parse_lenient_f64("").is_err());
+    }
+
+    #[test]
+    fn extract_medial_final_test() {
+        assert_eq!("ㅏㅜ", extract_medial_vowel(Some("강물")).unwrap());
+        assert_eq!("ㅇㄹ", extract_final_consonant(Some("강물")).unwrap());
+
+        let target = "닭과 English 😊";
+
+        assert_eq!(
+            "ㅏㅘ English 😊",
+            extract_medial_vowel(Some(target)).unwrap()
+        );
+        assert_eq!(
+            "ㄺ English 😊",
+            extract_final_consonant(Some(target)).unwrap(),
+            "받침 없는 음절 생략"
+        );
+
+        // 초성 추출과 같은 시그니처
+        assert_eq!("ㄱㅁ", extract_initial_consonant(Some("강물")).unwrap());
+        assert!(extract_medial_vowel(None).is_err());
+        assert!(extract_final_consonant(None).is_err());
+        assert_eq!("", extract_final_consonant(Some("가나다")).unwrap());
+        assert_eq!("ㅎ", extract_final_consonant(Some("힣")).unwrap());
+    }
+
+    #[test]
+    fn text_stats_test() {
+        assert_eq!(TextStats::default(), text_stats(""));
+
+        let stats = text_stats("한글과 English 😊\r\n두 번째\u{3000}줄\n");
+
+        assert_eq!(
+            TextStats {
+                bytes: 41,
+                chars: 22,
+                words: 6,
+                lines: 2,
+                hangul_syllables: 7,
+            },
+            stats
+        );
+
+        assert_eq!(1, text_stats("a").lines);
+        assert_eq!(1, text_stats("a\n").lines);
+        assert_eq!(2, text_stats("a\n\n").lines);
+        assert_eq!(1, text_stats("\n").lines);
+        assert_eq!(0, text_stats(" \t\n ").words);
+        assert_eq!(
+            0,
+            text_stats("ㄱㄴㄷ").hangul_syllables,
+            "호환 자모는 음절이 아님"
+        );
+    }
+
+    #[test]
+    fn mask_string_fixed_test() {
+        assert_eq!("12****89", mask_string_fixed("123456789", 2, 2, "****"));
+
+        // 가운데 길이와 관계없이 같은 마스크
+        assert_eq!("12****89", mask_string_fixed("12389", 2, 2, "****"));
+        assert_eq!(
+            "12****89",
+            mask_string_fixed("12345678901234567890189", 2, 2, "****")
+        );
+
+        assert_eq!("010-****", mask_string_fixed("010-1234-5678", 4, 0, "****"));
+        assert_eq!(
+            "***.com",
+            mask_string_fixed("user@example.com", 0, 4, "***")
+        );
+        assert_eq!("김*수", mask_string_fixed("김철수", 1, 1, "*"));
+        assert_eq!("****", mask_string_fixed("1234", 2, 2, "****"));
+        assert_eq!("****", mask_string_fixed("", 0, 0, "****"));
+        assert_eq!("****", mask_string_fixed("abc", usize::MAX, 1, "****"));
+    }
+
+    #[test]
+    fn replace_all_pairs_test() {
+        // 연쇄 치환 방지
+        assert_eq!("bc", replace_all_pairs("ab", &[("a", "b"), ("b", "c")]));
+        assert_eq!(
+            "ba",
+            replace_all_pairs("ab", &[("a", "b"), ("b", "a")]),
+            "swap"
+        );
+
+        // 겹치는 패턴은 가장 긴 매치 우선
+        assert_eq!("X", replace_all_pairs("abc", &[("ab", "Y"), ("abc", "X")]));
+        assert_eq!("Yd", replace_all_pairs("abd", &[("ab", "Y"), ("abc", "X")]));
+        assert_eq!(
+            "1",
+            replace_all_pairs("a", &[("a", "1"), ("a", "2")]),
+            "같은 길이는 앞선 쌍"
+        );
+
+        // 한글 및 빈 패턴
+        assert_eq!(
+            "좋은 아침 철수",
+            replace_all_pairs(
+                "안녕 {이름}",
+                &[("안녕", "좋은 아침"), ("{이름}", "철수"), ("", "X")]
+            )
+        );
+        assert_eq!("abc", replace_all_pairs("abc", &[]));
+        assert_eq!("", replace_all_pairs("", &[("a", "b")]));
+        assert_eq!("xx", replace_all_pairs("aaaa", &[("aa", "x")]));
+    }
+
+    #[test]
+    fn validate_email_strict_test() {
+        let valid = |v: &str| validate_email(Some(v)).unwrap();
+
+        assert!(valid("user@example.museum"), "4자 초과 TLD 허용");
+        assert!(valid(&format!("user@example.{}", "a".repeat(63))));
+        assert!(valid(&format!("{}@example.com", "a".repeat(64))));
+        assert!(valid("a-b.c_d@sub-domain.example.co.kr"));
+
+        // 연속/선행/후행 점
+        assert!(!valid("a..b@x.com"));
+        assert!(!valid(".ab@x.com"));
+        assert!(!valid("ab.@x.com"));
+        assert!(!valid("ab@x..com"));
+
+        // 도메인 label 형식
+        assert!(!valid("ab@-x.com"));
+        assert!(!valid("ab@x-.com"));
+
+        // TLD 길이
+        assert!(!valid("ab@x.c"));
+        assert!(!valid(&format!("user@example.{}", "a".repeat(64))));
+
+        // 길이 제한
+        assert!(
+            !valid(&format!("{}@example.com", "a".repeat(65))),
+            "로컬 파트 64자 초과"
+        );
+
+        let long_domain = format!("{}.com", vec!["a".repeat(60); 5].join("."));
+        let long_email = format!("user@{}", long_domain);
+
+        assert!(long_email.len() > 254);
+        assert!(!valid(&long_email), "전체 254자 초과");
+    }
+
+    #[test]
+    fn slugify_test() {
+        assert_eq!("hello-world-2024", slugify("Hello, World! 2024"));
+        assert_eq!("my-post", slugify("제목 My Post!"));
+        assert_eq!("dont-stop-me-now", slugify("  Don't -- stop_me   NOW!!  "));
+        assert_eq!("caf", slugify("Café"), "ASCII 외 문자 제거");
+        assert_eq!("", slugify("한글만 있는 제목"));
+        assert_eq!("", slugify("!!! --- ???"));
+
+        assert_eq!(
+            "러스트-유틸-v0-3",
+            slugify_with("러스트 유틸 (v0_3)", SlugHangul::Keep)
+        );
+        assert_eq!("ㅋㅋ-good", slugify_with("ㅋㅋ... Good", SlugHangul::Keep));
+        assert_eq!(
+            slugify("Title 제목"),
+            slugify_with("Title 제목", SlugHangul::default())
+        );
+    }
+
+    #[test]
+    fn split_regex_test() {
+        assert_eq!(
+            vec!["a", "", "b", ""],
+            split_regex("a,,b,", ",", true).unwrap()
+        );
+        assert_eq!(vec!["a", "b"], split_regex("a,,b,", ",", false).unwrap());
+
+        assert_eq!(
+            vec!["사과", "배", "포도"],
+            split_regex("사과, 배;;포도", r"[,;]\s*", false).unwrap()
+        );
+        assert_eq!(vec![""], split_regex("", ",", true).unwrap());
+        assert!(split_regex("", ",", false).unwrap().is_empty());
+
+        assert!(split_regex("a,b", "(", true).is_err());
+        assert!(split_regex("a,b", "[a-", false).is_err());
+    }
+
+    #[test]
+    fn reverse_graphemes_test() {
+        assert_eq!("", reverse_graphemes(""));
+        assert_eq!("olleh", reverse_graphemes("hello"));
+        assert_eq!("!dlroW ,olleH", reverse_graphemes("Hello, World!"));
+        assert_eq!("다나가", reverse_graphemes("가나다"));
+
+        // 결합 악센트가 앞 글자에서 분리되지 않아야 함
+        let combined = "가\u{0301}나";
+        let by_chars: String = combined.chars().rev().collect();
+
+        assert_eq!("나가\u{0301}", reverse_graphemes(combined));
+        assert_ne!(by_chars, reverse_graphemes(combined));
+        assert_eq!("e\u{0301}a", reverse_graphemes("ae\u{0301}"));
+
+        // 이모지 가족(ZWJ 시퀀스)은 하나의 단위로 유지
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let target = format!("a{}b", family);
+
+        assert_eq!(format!("b{}a", family), reverse_graphemes(&target));
+        assert_eq!(target, reverse_graphemes(&reverse_graphemes(&target)));
+    }
+
+    #[test]
+    fn generate_grouped_key_test() {
+        assert!(generate_grouped_key(0, 4, '-', CharsetOption::Alphanumeric).is_none());
+        assert!(generate_grouped_key(3, 0, '-', CharsetOption::Alphanumeric).is_none());
+
+        let key = generate_grouped_key(3, 4, '-', CharsetOption::UnambiguousUpper).unwrap();
+        let chars: Vec<char> = key.chars().collect();
+
+        assert_eq!(14, chars.len());
+        assert_eq!('-', chars[4]);
+        assert_eq!('-', chars[9]);
+        assert_eq!(2, chars.iter().filter(|c| **c == '-').count());
+
+        for group in key.split('-') {
+            assert_eq!(4, group.len());
+            assert!(group
+                .chars()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()));
+            assert!(!group
+                .chars()
+                .any(|c| AMBIGUOUS_CHARACTERS.contains(&c.to_string().as_str())));
+        }
+
+        // 단일 그룹은 구분자를 포함하지 않음
+        let single = generate_grouped_key(1, 8, '-', CharsetOption::Alphanumeric).unwrap();
+
+        assert_eq!(8, single.len());
+        assert!(single.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        // 멀티바이트 구분자
+        let key = generate_grouped_key(4, 5, '·', CharsetOption::Unambiguous).unwrap();
+
+        assert_eq!(4 * 5 + 3, key.chars().count());
+        assert_eq!(4, key.split('·').count());
+        assert!(key.split('·').all(|v| v.len() == 5));
+
+        let key = generate_grouped_key(2, 16, '_', CharsetOption::AlphanumericWithSpec).unwrap();
+
+        assert_eq!(33, key.chars().count());
+    }
+
+    #[test]
+    fn euckr_conversion_test() {
+        // "안녕하세요, EUC-KR!"의 EUC-KR 바이트
+        let euckr: Vec<u8> = vec![
+            0xBE, 0xC8, 0xB3, 0xE7, 0xC7, 0xCF, 0xBC, 0xBC, 0xBF, 0xE4, 0x2C, 0x20, 0x45, 0x55,
+            0x43, 0x2D, 0x4B, 0x52, 0x21,
+        ];
+        let plain = "안녕하세요, EUC-KR!";
+
+        assert_eq!(plain, euckr_to_utf8(&euckr).unwrap());
+        assert_eq!(euckr, utf8_to_euckr(plain).unwrap());
+        assert_eq!("", euckr_to_utf8(&[]).unwrap());
+        assert!(utf8_to_euckr("").unwrap().is_empty());
+
+        // 왕복 변환 (CP949 확장 음절 "똠" 포함)
+        let text = "대한민국 똠방각하 ABC 123 ㄱㄴㄷ";
+        let encoded = utf8_to_euckr(text).unwrap();
+
+        assert_ne!(text.as_bytes(), encoded.as_slice());
+        assert_eq!(text, euckr_to_utf8(&encoded).unwrap());
+
+        // 잘못된 바이트 시퀀스
+        assert!(euckr_to_utf8(&[0xB0]).is_err());
+        assert!(euckr_to_utf8(&[0xB0, 0x20]).is_err());
+
+        // EUC-KR에 없는 문자는 대체하지 않고 오류
+        let result = utf8_to_euckr("한글😀테스트");
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().get_message().contains("U+1F600"));
+    }
+
+    #[test]
+    fn render_template_test() {
+        let vars: HashMap<&str, String> = HashMap::from([
+            ("name", "홍길동".to_owned()),
+            ("count", "3".to_owned()),
+            ("empty", "".to_owned()),
+            ("brace", "{name}".to_owned()),
+        ]);
+
+        assert_eq!(
+            "안녕하세요 홍길동님, 3개의 알림",
+            render_template("안녕하세요 {name}님, {count}개의 알림", &vars).unwrap()
+        );
+        assert_eq!("", render_template("", &vars).unwrap());
+        assert_eq!("plain", render_template("plain", &vars).unwrap());
+        assert_eq!("[]", render_template("[{empty}]", &vars).unwrap());
+        assert_eq!("홍길동", render_template("{ name }", &vars).unwrap());
+
+        // 치환된 값은 다시 해석하지 않음
+        assert_eq!("{name}", render_template("{brace}", &vars).unwrap());
+
+        // 이스케이프
+        assert_eq!("{name}", render_template("{{name}}", &vars).unwrap());
+        assert_eq!("{홍길동}", render_template("{{{name}}}", &vars).unwrap());
+        assert_eq!("a { b } c", render_template("a {{ b }} c", &vars).unwrap());
+
+        // 누락 키
+        let result = render_template("{name} {missing}", &vars);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().get_message().contains("missing"));
+
+        // 잘못된 중괄호
+        assert!(render_template("{}", &vars).is_err());
+        assert!(render_template("{name", &vars).is_err());
+        assert!(render_template("name}", &vars).is_err());
+        assert!(render_template("{na{me}}", &vars).is_err());
+        assert!(render_template("{{name}", &vars).is_err());
+    }
+
+    #[test]
+    fn natural_compare_test() {
+        use std::cmp::Ordering;
+
+        assert_eq!(Ordering::Less, natural_compare("file2", "file10"));
+        assert_eq!(Ordering::Greater, natural_compare("file10", "file9"));
+        assert_eq!(Ordering::Equal, natural_compare("file10", "file10"));
+        assert_eq!(Ordering::Less, natural_compare("", "a"));
+        assert_eq!(Ordering::Less, natural_compare("file", "file1"));
+
+        // 선행 0은 수치로 같고, 나머지가 같으면 0이 적은 쪽이 앞
+        assert_eq!(Ordering::Less, natural_compare("file2", "file02"));
+        assert_eq!(Ordering::Less, natural_compare("file02", "file3"));
+        assert_eq!(Ordering::Less, natural_compare("file002a", "file2b"));
+
+        // 대/소문자 무시, 동일하면 일반 문자열 비교
+        assert_eq!(Ordering::Less, natural_compare("Apple", "banana"));
+        assert_eq!(Ordering::Less, natural_compare("File", "file"));
+
+        // u64 범위를 넘는 숫자
+        assert_eq!(
+            Ordering::Less,
+            natural_compare("v99999999999999999999999", "v100000000000000000000000")
+        );
+        assert_eq!(
+            Ordering::Greater,
+            natural_compare(
+                "123456789012345678901234567891",
+                "123456789012345678901234567890"
+            )
+        );
+
+        let mut files = vec![
+            "file10.txt",
+            "file2.txt",
+            "File1.txt",
+            "file02.txt",
+            "file1.txt",
+            "파일3",
+            "파일10",
+            "가나2",
+            "10",
+            "9",
+            "a",
+        ];
+
+        files.sort_by(|a, b| natural_compare(a, b));
+
+        assert_eq!(
+            vec![
+                "9",
+                "10",
+                "a",
+                "File1.txt",
+                "file1.txt",
+                "file2.txt",
+                "file02.txt",
+                "file10.txt",
+                "가나2",
+                "파일3",
+                "파일10",
+            ],
+            files
+        );
+
+        // 대칭성
+        for a in files.iter() {
+            for b in files.iter() {
+                assert_eq!(natural_compare(a, b), natural_compare(b, a).reverse());
+            }
+        }
+    }
+
+    #[test]
+    fn collapse_repeats_test() {
+        assert_eq!("aabbcc", collapse_repeats("aaabbbccc", 2));
+        assert_eq!("abc", collapse_repeats("aaabbbccc", 1));
+        assert_eq!("aaabbbccc", collapse_repeats("aaabbbccc", 3));
+        assert_eq!("wow", collapse_repeats("wooooow", 1));
+        assert_eq!("!", collapse_repeats("!!!", 1));
+        assert_eq!("", collapse_repeats("", 2));
+
+        // max_repeat 0은 1로 취급
+        assert_eq!(collapse_repeats("aaab", 1), collapse_repeats("aaab", 0));
+
+        // 떨어져 있는 같은 문자는 각각 계산
+        assert_eq!("aabaa", collapse_repeats("aaabaaa", 2));
+
+        // 한글, 자모, 이모지
+        assert_eq!("ㅋㅋ 하하", collapse_repeats("ㅋㅋㅋㅋㅋ 하하하", 2));
+        assert_eq!("우와~", collapse_repeats("우와~~~~", 1));
+        assert_eq!("😀😀", collapse_repeats("😀😀😀😀", 2));
+        assert_eq!("a  b", collapse_repeats("a     b", 2));
+    }
+
+    #[test]
+    fn word_wrap_test() {
+        let text = "The quick brown fox jumps over the lazy dog";
+        let wrapped = word_wrap(text, 10);
+
+        assert_eq!("The quick\nbrown fox\njumps over\nthe lazy\ndog", wrapped);
+        assert!(wrapped.lines().all(|v| v.chars().count() <= 10));
+
+        // 폭보다 긴 단어는 강제로 자름
+        assert_eq!("abcd\nefgh\nij k", word_wrap("abcdefghij k", 4));
+
+        // 기존 개행과 빈 줄 보존
+        assert_eq!("ab\ncd\n\nef", word_wrap("ab cd\n\nef", 3));
+        assert_eq!("a\n", word_wrap("a\n", 3));
+        assert_eq!("", word_wrap("", 3));
+
+        // width 0은 원본 유지
+        assert_eq!(text, word_wrap(text, 0));
+
+        // 한글 혼합 문장
+        let mixed = "대한민국 헌법 제1조 Republic of Korea 민주공화국이다";
+
+        for width in [4, 7, 10, 15] {
+            for mode in [WrapWidth::Chars, WrapWidth::Display] {
+                let wrapped = word_wrap_with(mixed, width, mode);
+
+                for line in wrapped.lines() {
+                    let line_width = match mode {
+                        WrapWidth::Chars => line.chars().count(),
+                        WrapWidth::Display => display_width(line),
+                    };
+
+                    assert!(line_width <= width, "{:?} {} : {:?}", mode, width, line);
+                }
+
+                // 공백을 제외한 내용은 그대로 유지
+                assert_eq!(mixed.replace(' ', ""), wrapped.replace(['\n', ' '], ""));
+            }
+        }
+
+        assert_eq!(
+            "대한민국\n헌법\n제1조\nRepublic\nof Korea\n민주공화\n국이다",
+            word_wrap_with(mixed, 8, WrapWidth::Display)
+        );
+
+        // 표시폭이 width보다 큰 문자는 한 줄에 하나
+        assert_eq!("가\n나", word_wrap_with("가나", 1, WrapWidth::Display));
+    }
+
+    #[test]
+    fn extract_named_captures_test() {
+        let pattern = r"^(?P<date>\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}) \[(?P<level>[A-Z]+)\] (?:\((?P<code>E\d+)\) )?(?P<message>.*)$";
+        let line = "2024-11-22 10:29:48 [ERROR] (E500) 데이터베이스 연결 실패";
+        let result = extract_named_captures(line, pattern).unwrap();
+
+        assert_eq!(4, result.len());
+        assert_eq!("2024-11-22 10:29:48", result["date"]);
+        assert_eq!("ERROR", result["level"]);
+        assert_eq!("E500", result["code"]);
+        assert_eq!("데이터베이스 연결 실패", result["message"]);
+
+        // 매치되지 않은 선택적 그룹은 제외
+        let result =
+            extract_named_captures("2024-11-22 10:30:00 [INFO] 서버 시작", pattern).unwrap();
+
+        assert_eq!(3, result.len());
+        assert!(!result.contains_key("code"));
+        assert_eq!("INFO", result["level"]);
+
+        // 빈 문자열로 매치된 그룹은 포함
+        let result = extract_named_captures("2024-11-22 10:30:00 [WARN] ", pattern).unwrap();
+
+        assert_eq!(Some(&"".to_owned()), result.get("message"));
+
+        // 매치 없음, 이름 없는 그룹
+        assert!(extract_named_captures("invalid log line", pattern)
+            .unwrap()
+            .is_empty());
+        assert!(extract_named_captures("abc 123", r"(\w+) (\d+)")
+            .unwrap()
+            .is_empty());
+
+        // 첫 번째 매치만 사용
+        let result = extract_named_captures("a=1, b=2", r"(?P<key>\w)=(?P<value>\d)").unwrap();
+
+        assert_eq!("a", result["key"]);
+        assert_eq!("1", result["value"]);
+
+        // 잘못된 정규식
+        assert!(extract_named_captures("text", r"(?P<name>").is_err());
+    }
+
+    #[test]
+    fn mask_in_place_test() {
+        // ASCII 원문 + ASCII 마스크: 할당 없이 제자리 치환
+        let mut buf = String::with_capacity(64);
+
+        buf.push_str("card 1234-5678-9012-3456");
+
+        let ptr = buf.as_ptr();
+        let capacity = buf.capacity();
+
+        mask_in_place(&mut buf, 10, 19, '*').unwrap();
+
+        assert_eq!("card 1234-*********-3456", buf);
+        assert_eq!(ptr, buf.as_ptr());
+        assert_eq!(capacity, buf.capacity());
+
+        // 끝까지, 빈 구간
+        mask_in_place(&mut buf, 20, 24, '#').unwrap();
+        assert_eq!("card 1234-*********-####", buf);
+        mask_in_place(&mut buf, 3, 3, '#').unwrap();
+        mask_in_place(&mut buf, 24, 24, '#').unwrap();
+        assert_eq!("card 1234-*********-####", buf);
+        assert_eq!(ptr, buf.as_ptr());
+
+        // 한글 원문 + 3바이트 마스크: 멀티바이트 경계를 지키며 제자리 치환
+        let mut name = String::from("홍길동abc");
+        let ptr = name.as_ptr();
+
+        mask_in_place(&mut name, 1, 3, '●').unwrap();
+        assert_eq!("홍●●abc", name);
+        assert_eq!(ptr, name.as_ptr());
+
+        // 바이트 길이가 다른 경우 구간 전체 교체
+        let mut mixed = String::from("김a철수b");
+
+        mask_in_place(&mut mixed, 0, 4, '*').unwrap();
+        assert_eq!("****b", mixed);
+
+        let mut grow = String::from("abc");
+
+        mask_in_place(&mut grow, 1, 3, '●').unwrap();
+        assert_eq!("a●●", grow);
+
+        // 오류
+        let mut target = String::from("한글");
+
+        assert!(mask_in_place(&mut target, 2, 1, '*')
+            .unwrap_err()
+            .get_message()
+            .contains("2 > 1"));
+        assert!(mask_in_place(&mut target, 0, 3, '*')
+            .unwrap_err()
+            .get_message()
+            .contains("3 > 2"));
+        assert!(mask_in_place(&mut target, 3, 3, '*').is_err());
+        assert_eq!("한글", target);
+        assert!(mask_in_place(&mut String::new(), 0, 0, '*').is_ok());
+    }
+
+    #[test]
+    fn starts_ends_with_any_test() {
+        let prefixes = ["/admin", "/api/", "/static"];
+
+        assert!(starts_with_any("/api/v1/users", &prefixes));
+        assert!(starts_with_any("/static/app.js", &prefixes));
+        assert!(starts_with_any("/admin", &prefixes));
+        assert!(!starts_with_any("/ap", &prefixes));
+        assert!(!starts_with_any("/API/v1", &prefixes));
+        assert!(!starts_with_any("", &prefixes));
+
+        assert!(ends_with_any("photo.png", &[".jpg", ".png"]));
+        assert!(ends_with_any("이미지.jpg", &[".jpg", ".png"]));
+        assert!(!ends_with_any("photo.PNG", &[".jpg", ".png"]));
+        assert!(!ends_with_any("png", &[".png"]));
+
+        // 빈 목록은 false, 빈 문자열 패턴은 항상 일치
+        assert!(!starts_with_any("abc", &[]));
+        assert!(!ends_with_any("abc", &[]));
+        assert!(!starts_with_any_ignore_case("abc", &[]));
+        assert!(!ends_with_any_ignore_case("abc", &[]));
+        assert!(starts_with_any("abc", &["x", ""]));
+        assert!(ends_with_any("", &[""]));
+
+        // 대소문자 무시
+        assert!(starts_with_any_ignore_case("/API/v1", &prefixes));
+        assert!(starts_with_any_ignore_case(
+            "https://a.com",
+            &["HTTP://", "HTTPS://"]
+        ));
+        assert!(!starts_with_any_ignore_case(
+            "ftp://a.com",
+            &["http://", "https://"]
+        ));
+        assert!(ends_with_any_ignore_case("photo.PNG", &[".jpg", ".png"]));
+        assert!(ends_with_any_ignore_case("Straße", &["STRASSE", "STRAßE"]));
+        assert!(!ends_with_any_ignore_case("photo.gif", &[".JPG", ".PNG"]));
+    }
+
+    #[test]
+    fn detect_encoding_test() {
+        // BOM
+        assert_eq!(
+            DetectedEncoding::Utf8,
+            detect_encoding(&[0xEF, 0xBB, 0xBF, 0xC7, 0xD1])
+        );
+        assert_eq!(DetectedEncoding::Utf16Le, detect_encoding(&[0xFF, 0xFE]));
+        assert_eq!(
+            DetectedEncoding::Utf16Be,
+            detect_encoding(&[0xFE, 0xFF, 0xD5, 0x5C])
+        );
+
+        // 순수 ASCII, 빈 데이터, UTF-8
+        assert_eq!(DetectedEncoding::Utf8, detect_encoding(b"plain ascii\r\n"));
+        assert_eq!(DetectedEncoding::Utf8, detect_encoding(b""));
+        assert_eq!(
+            DetectedEncoding::Utf8,
+            detect_encoding("한글 텍스트".as_bytes())
+        );
+
+        // EUC-KR
+        let euckr = utf8_to_euckr("안녕하세요, EUC-KR 텍스트\n").unwrap();
+
+        assert_eq!(DetectedEncoding::EucKr, detect_encoding(&euckr));
+        assert_eq!(
+            "안녕하세요, EUC-KR 텍스트\n",
+            detect_encoding(&euckr).decode(&euckr).unwrap()
+        );
+
+        // BOM 없는 UTF-16(ASCII 위주)
+        let utf16le: Vec<u8> = "hello 한글"
+            .encode_utf16()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let utf16be: Vec<u8> = "hello 한글"
+            .encode_utf16()
+            .flat_map(|v| v.to_be_bytes())
+            .collect();
+
+        assert_eq!(DetectedEncoding::Utf16Le, detect_encoding(&utf16le));
+        assert_eq!(DetectedEncoding::Utf16Be, detect_encoding(&utf16be));
+        assert_eq!(
+            "hello 한글",
+            DetectedEncoding::Utf16Le.decode(&utf16le).unwrap()
+        );
+        assert_eq!(
+            "hello 한글",
+            DetectedEncoding::Utf16Be.decode(&utf16be).unwrap()
+        );
+
+        // BOM 제거 후 변환
+        let mut with_bom = vec![0xFF, 0xFE];
+
+        with_bom.extend_from_slice(&utf16le);
+        assert_eq!(
+            "hello 한글",
+            detect_encoding(&with_bom).decode(&with_bom).unwrap()
+        );
+        assert_eq!(
+            "abc",
+            DetectedEncoding::Utf8
+                .decode(&[0xEF, 0xBB, 0xBF, b'a', b'b', b'c'])
+                .unwrap()
+        );
+
+        // 판별 불가 및 변환 실패
+        assert_eq!(
+            DetectedEncoding::Unknown,
+            detect_encoding(&[0x00, 0x00, 0xFF, 0x01, 0xC7])
+        );
+        assert_eq!(
+            DetectedEncoding::Unknown,
+            detect_encoding(&[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A])
+        );
+        assert!(DetectedEncoding::Unknown.decode(b"abc").is_err());
+        assert!(DetectedEncoding::Utf8.decode(&[0xC7, 0xD1]).is_err());
+    }
+
+    #[test]
+    fn split_camel_case_test() {
+        let cases: [(&str, &[&str]); 15] = [
+            ("getHTTPResponseCode", &["get", "HTTP", "Response", "Code"]),
+            ("parseURLFrom123", &["parse", "URL", "From", "123"]),
+            ("item2", &["item", "2"]),
+            ("HTTP2Server", &["HTTP", "2", "Server"]),
+            ("v2Api", &["v", "2", "Api"]),
+            ("PascalCase", &["Pascal", "Case"]),
+            ("camelCase", &["camel", "Case"]),
+            ("ABC", &["ABC"]),
+            ("ABCd", &["AB", "Cd"]),
+            ("aB", &["a", "B"]),
+            ("snake_case_name", &["snake", "case", "name"]),
+            (
+                "kebab-caseMixed_Value",
+                &["kebab", "case", "Mixed", "Value"],
+            ),
+            (
+                "__leading--and  trailing__",
+                &["leading", "and", "trailing"],
+            ),
+            ("한글Name", &["한글", "Name"]),
+            ("userID", &["user", "ID"]),
+        ];
+
+        for (target, expected) in cases {
+            assert_eq!(expected.to_vec(), split_camel_case(target), "{}", target);
+        }
+
+        assert!(split_camel_case("").is_empty());
+        assert!(split_camel_case("_-_ ").is_empty());
+    }
+
+    #[test]
+    fn apply_dueum_test() {
+        let cases = [
+            // ㄹ → ㄴ
+            ("로동", "노동"),
+            ("래일", "내일"),
+            ("루각", "누각"),
+            ("뢰성", "뇌성"),
+            ("락원", "낙원"),
+            // ㄹ → ㅇ (받침 유지)
+            ("리씨", "이씨"),
+            ("량심", "양심"),
+            ("력사", "역사"),
+            ("례의", "예의"),
+            ("료리", "요리"),
+            ("류행", "유행"),
+            // ㄴ → ㅇ
+            ("녀자", "여자"),
+            ("뇨소", "요소"),
+            ("뉴대", "유대"),
+            ("닉명", "익명"),
+            // 단어 중간은 변환하지 않음
+            ("노래", "노래"),
+            ("신녀성", "신녀성"),
+            ("고려", "고려"),
+            ("요리", "요리"),
+            // 예외 단어
+            ("라면", "라면"),
+            ("라디오 뉴스", "라디오 뉴스"),
+            ("녀석", "녀석"),
+            // 여러 단어 및 문장 부호
+            ("로동 리씨 녀자", "노동 이씨 여자"),
+            ("(로인)과 \"래일\"", "(노인)과 \"내일\""),
+            ("abc리", "abc이"),
+            ("", ""),
+            ("ㄹ", "ㄹ"),
+        ];
+
+        for (target, expected) in cases {
+            assert_eq!(expected, apply_dueum(target), "{}", target);
+        }
+    }
+
+    #[test]
+    fn grapheme_count_test() {
+        // (대상, chars().count(), grapheme_count)
+        let cases = [
+            ("", 0, 0),
+            ("abc", 3, 3),
+            ("한글", 2, 2),
+            ("👨\u{200D}👩\u{200D}👧", 5, 1),
+            ("👍🏽", 2, 1),
+            ("🇰🇷", 2, 1),
+            ("e\u{0301}", 2, 1),
+            ("cafe\u{0301}!", 6, 5),
+            ("\u{1112}\u{1161}\u{11AB}", 3, 1),
+            ("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}", 6, 2),
+            ("ㅎㅏㄴ", 3, 3),
+            ("\r\n", 2, 1),
+        ];
+
+        for (target, chars, graphemes) in cases {
+            assert_eq!(chars, target.chars().count(), "{:?}", target);
+            assert_eq!(graphemes, grapheme_count(target), "{:?}", target);
+        }
 
         assert_eq!(
-            "ㅅㅈㄷㅇ(世宗大王)",
-            result.as_str(),
-            "한글과 한자가 혼재되어 있을 경우 추출 실패"
+            grapheme_count("a👨\u{200D}👩\u{200D}👧e\u{0301}"),
+            reverse_graphemes("a👨\u{200D}👩\u{200D}👧e\u{0301}")
+                .graphemes(true)
+                .count()
         );
+    }
 
-        target = "이건 이모티콘(❤😑😊😂)을 포함합니다.";
-        result = extract_initial_consonant(Some(target)).unwrap();
+    #[test]
+    fn bytes_to_string_test() {
+        let modes = [Utf8Mode::Strict, Utf8Mode::Lossy, Utf8Mode::Truncate];
 
-        println!("extract result : {}", result);
+        // 유효한 UTF-8은 모든 모드에서 동일
+        for mode in modes {
+            assert_eq!("", bytes_to_string(b"", mode).unwrap());
+            assert_eq!(
+                "한글 abc 😀",
+                bytes_to_string("한글 abc 😀".as_bytes(), mode).unwrap()
+            );
+        }
+
+        assert_eq!(Utf8Mode::Strict, Utf8Mode::default());
+
+        // (대상, Lossy 결과, Truncate 결과, Strict 오류 위치)
+        let invalid: [(&[u8], &str, &str, usize); 5] = [
+            (&[0xFF], "\u{FFFD}", "", 0),
+            (b"ab\xFFcd", "ab\u{FFFD}cd", "ab", 2),
+            // 잘린 멀티바이트 시퀀스 ("한" = ED 95 9C)
+            (&[b'a', 0xED, 0x95], "a\u{FFFD}", "a", 1),
+            (&[0xED, 0x95, 0x9C, 0xED], "한\u{FFFD}", "한", 3),
+            // 잘못된 연속 바이트 및 overlong 인코딩
+            (&[b'x', 0xC0, 0xAF, b'y'], "x\u{FFFD}\u{FFFD}y", "x", 1),
+        ];
+
+        for (bytes, lossy, truncated, position) in invalid {
+            assert!(bytes_to_string(bytes, Utf8Mode::Strict)
+                .unwrap_err()
+                .get_message()
+                .contains(format!("위치: {}", position).as_str()));
+            assert_eq!(lossy, bytes_to_string(bytes, Utf8Mode::Lossy).unwrap());
+            assert_eq!(
+                truncated,
+                bytes_to_string(bytes, Utf8Mode::Truncate).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn code_points_test() {
+        // 왕복 변환
+        for target in [
+            "",
+            "abc",
+            "한글",
+            "👨\u{200D}👩\u{200D}👧",
+            "가\u{0301}😀\u{10FFFF}\u{0}",
+        ] {
+            assert_eq!(
+                target,
+                from_code_points(&to_code_points(target)).unwrap(),
+                "{:?}",
+                target
+            );
+        }
 
+        assert_eq!(vec![0xD55C, 0xAE00], to_code_points("한글"));
+        assert_eq!(vec![0x1F600], to_code_points("😀"));
         assert_eq!(
-            "ㅇㄱ ㅇㅁㅌㅋ(❤😑😊😂)ㅇ ㅍㅎㅎㄴㄷ.",
-            result.as_str(),
-            "한글과 이모티콘이 혼재되어 있을 경우 추출 실패"
+            vec![0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467],
+            to_code_points("👨\u{200D}👩\u{200D}👧")
+        );
+
+        // 경계 값
+        assert_eq!("\u{D7FF}", from_code_points(&[0xD7FF]).unwrap());
+        assert_eq!("\u{E000}", from_code_points(&[0xE000]).unwrap());
+        assert_eq!("\u{10FFFF}", from_code_points(&[0x10FFFF]).unwrap());
+
+        // 잘못된 코드 포인트 거부
+        for invalid in [0xD800, 0xDBFF, 0xDC00, 0xDFFF, 0x110000, u32::MAX] {
+            assert!(from_code_points(&[0x41, invalid]).is_err(), "{:X}", invalid);
+        }
+
+        assert_eq!(
+            "유효하지 않은 코드 포인트입니다(위치: 2) : 0xD800",
+            from_code_points(&[0x41, 0x42, 0xD800, 0x110000])
+                .unwrap_err()
+                .get_message()
         );
     }
 
     #[test]
-    fn separate_consonant_vowel_test() {
-        let mut target = "한글만";
-        let mut result = separate_simple_consonant_vowel(Some(target)).unwrap();
+    fn join_korean_test() {
+        // 항목 수별
+        assert_eq!("", join_korean(&[]));
+        assert_eq!("사과", join_korean(&["사과"]));
+        assert_eq!("사과와 배", join_korean(&["사과", "배"]));
+        assert_eq!("사과, 배 및 포도", join_korean(&["사과", "배", "포도"]));
+        assert_eq!(
+            "사과, 배, 포도 및 수박",
+            join_korean(&["사과", "배", "포도", "수박"])
+        );
 
-        println!("separate result : {}", result);
+        // 받침에 따른 와/과
+        assert_eq!("수박과 배", join_korean(&["수박", "배"]));
+        assert_eq!("물과 불", join_korean(&["물", "불"]));
+        assert_eq!("닭과 오리", join_korean(&["닭", "오리"]));
+        assert_eq!("나무와 돌", join_korean(&["나무", "돌"]));
+
+        // 숫자(한자어 읽기) 및 한글이 아닌 글자
+        assert_eq!("1과 2", join_korean(&["1", "2"]));
+        assert_eq!("2와 3", join_korean(&["2", "3"]));
+        assert_eq!("10과 20", join_korean(&["10", "20"]));
+        assert_eq!("5와 6", join_korean(&["5", "6"]));
+        assert_eq!("Rust와 Go", join_korean(&["Rust", "Go"]));
+        assert_eq!("와 배", join_korean(&["", "배"]));
+    }
+
+    #[test]
+    fn word_set_test() {
+        let banned = ["바보", "멍청이", "spam", "spammer", ""];
+        let set = WordSet::build(&banned);
+
+        assert_eq!(4, set.len());
+        assert!(!set.is_ignore_case());
+        assert!(set.contains("바보"));
+        assert!(set.contains("spammer"));
+        assert!(!set.contains("SPAM"));
+        assert!(!set.contains("바"));
+        assert!(!set.contains(""));
+
+        let ignore_case = WordSet::build_ignore_case(&banned);
+
+        assert!(ignore_case.is_ignore_case());
+        assert!(ignore_case.contains("SPAM"));
+        assert!(ignore_case.contains("SpAmMeR"));
 
+        // 탐지 및 마스킹
         assert_eq!(
-            "ㅎㅏㄴㄱㅡㄹㅁㅏㄴ",
-            result.as_str(),
-            "한글만 있는 초/중/종성 분리 실패"
+            "너는 **야, SPAM은 싫어",
+            mask_banned_words("너는 바보야, SPAM은 싫어", &set, '*')
+        );
+        assert_eq!(
+            "너는 **야, ****은 싫어",
+            mask_banned_words("너는 바보야, SPAM은 싫어", &ignore_case, '*')
         );
 
-        target = "한글과 English가 함께";
-        result = separate_simple_consonant_vowel(Some(target)).unwrap();
+        // 가장 긴 단어 우선
+        assert_eq!("*******s", mask_banned_words("spammers", &set, '*'));
+        assert_eq!("●●● ●●●●", mask_banned_words("멍청이 spam", &set, '●'));
 
-        println!("separate result : {}", result);
+        // 금지어가 없거나 빈 집합
+        assert_eq!("hello", mask_banned_words("hello", &set, '*'));
+        assert_eq!("바보", mask_banned_words("바보", &WordSet::default(), '*'));
+        assert_eq!("", mask_banned_words("", &set, '*'));
 
+        // 대량 금지어
+        let words: Vec<String> = (0..20_000).map(|i| format!("word{}", i)).collect();
+        let refs: Vec<&str> = words.iter().map(String::as_str).collect();
+        let large = WordSet::build(&refs);
+
+        assert!(large.contains("word19999"));
+        assert!(!large.contains("word20000"));
         assert_eq!(
-            "ㅎㅏㄴㄱㅡㄹㄱㅘ Englishㄱㅏ ㅎㅏㅁㄲㅔ",
-            result.as_str(),
-            "한글과 영어가 혼재되어 있을 경우 초/중/종성 분리 실패"
+            "a ******** b",
+            mask_banned_words("a word1234 b", &large, '*')
         );
+    }
 
-        target = "맑음";
-        result = separate_simple_consonant_vowel(Some(target)).unwrap();
+    #[test]
+    fn remove_control_chars_test() {
+        let target = "\u{feff}한글\u{0}\tEnglish\u{7}\u{1b}[0m\u{7f}\u{85}\u{9f}\r\n끝";
 
-        println!("separate result : {}", result);
+        assert_eq!("\u{feff}한글English[0m끝", remove_control_chars(target));
+        assert_eq!(
+            "\u{feff}한글\tEnglish[0m\r\n끝",
+            remove_control_chars_keep_whitespace(target)
+        );
+        assert_eq!("한글 English", remove_control_chars("한글 English"));
+        assert_eq!("", remove_control_chars(""));
+    }
 
+    #[test]
+    fn remove_emoji_test() {
         assert_eq!(
-            "ㅁㅏㄺㅇㅡㅁ",
-            result.as_str(),
-            "겹받침이 있을 경우 초/중/종성 분리 실패"
+            "오늘 날씨 좋다 Good day!",
+            remove_emoji("오늘 날씨 좋다☀️ Good day!😀👍🏽")
         );
+        assert_eq!("가족: ", remove_emoji("가족: 👨‍👩‍👧‍👦"));
+        assert_eq!(
+            "국기 ",
+            remove_emoji("국기 🇰🇷🏴\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}")
+        );
+        assert_eq!("별 하트 시계", remove_emoji("별⭐ 하트❤️ 시계⌚"));
+        assert_eq!("1 ©", remove_emoji("1️⃣ ©️"));
 
-        target = "많이 주세요.";
-        result = separate_simple_consonant_vowel(Some(target)).unwrap();
+        // 한글, 영문, 숫자, 일반 기호는 보존
+        let plain = "한글 English 123 !@#$%^&*() ㄱㄴㄷ ←→ ①";
 
-        println!("separate result : {}", result);
+        assert_eq!(plain, remove_emoji(plain));
+    }
+
+    #[test]
+    fn anagram_test() {
+        assert_eq!("abn", unique_sorted_chars("banana"));
+        assert_eq!("리스아", unique_sorted_chars("아리스아리스"));
+        assert_eq!(" Aa가나", unique_sorted_chars("나가a A가나"));
+        assert_eq!("", unique_sorted_chars(""));
+
+        assert!(is_anagram("리스아", "아리스"));
+        assert!(is_anagram("listen", "silent"));
+        assert!(is_anagram("", ""));
+        assert!(!is_anagram("aab", "abb")); // 고유 문자 집합은 같지만 개수가 다름
+        assert!(!is_anagram("Listen", "silent"));
+        assert!(!is_anagram("a b", "ab"));
+
+        assert!(is_anagram_with_option("Listen", "Silent", true, false));
+        assert!(is_anagram_with_option("a b", "ab", false, true));
+        assert!(is_anagram_with_option("아리 스", "스리아", false, true));
+        assert!(is_anagram_with_option(
+            "Dormitory",
+            "Dirty Room",
+            true,
+            true
+        ));
+        assert!(!is_anagram_with_option(
+            "Dormitory",
+            "Dirty Rooms",
+            true,
+            true
+        ));
+    }
+
+    #[test]
+    fn common_prefix_suffix_test() {
+        let files = [
+            "사진_2024_여름.jpg",
+            "사진_2024_겨울.jpg",
+            "사진_2023_봄.jpg",
+        ];
+
+        assert_eq!("사진_202", common_prefix(&files));
+        assert_eq!(".jpg", common_suffix(&files));
+
+        // 멀티바이트 경계 : '한'(ED 95 9C)과 '핫'(ED 95 AB)은 앞 2바이트가 같음
+        assert_eq!("", common_prefix(&["한글", "핫도그"]));
+        assert_eq!("가", common_prefix(&["가한", "가핫"]));
+        assert_eq!("", common_suffix(&["국한", "국핫"]));
+
+        // 한 항목이 다른 항목의 접두/접미사
+        assert_eq!("abc", common_prefix(&["abcdef", "abc", "abcd"]));
+        assert_eq!("def", common_suffix(&["abcdef", "def", "cdef"]));
+
+        // 공통 부분 없음, 단일 항목, 빈 목록, 빈 문자열
+        assert_eq!("", common_prefix(&["abc", "xbc"]));
+        assert_eq!("", common_suffix(&["abc", "abx"]));
+        assert_eq!("단일 항목", common_prefix(&["단일 항목"]));
+        assert_eq!("단일 항목", common_suffix(&["단일 항목"]));
+        assert_eq!("", common_prefix(&[]));
+        assert_eq!("", common_suffix(&[]));
+        assert_eq!("", common_prefix(&["abc", ""]));
+    }
+
+    #[test]
+    fn mask_by_regex_test() {
+        let text = "홍길동 010-1234-5678, 사무실 02-987-6543 / 내선 123";
+        let phone = r"\d{2,3}-\d{3,4}-\d{4}";
 
         assert_eq!(
-            "ㅁㅏㄶㅇㅣ ㅈㅜㅅㅔㅇㅛ.",
-            result.as_str(),
-            "겹받침이 있을 경우 초/중/종성 분리 실패"
+            "홍길동 *************, 사무실 *********** / 내선 123",
+            mask_by_regex(text, phone, '*').unwrap()
+        );
+        assert_eq!(
+            "홍길동 010-****-5678, 사무실 02-***-6543 / 내선 123",
+            mask_by_regex_groups(text, r"\d{2,3}-(\d{3,4})-\d{4}", '*').unwrap()
+        );
+        assert_eq!(
+            "홍길동 ***-1234-****, 사무실 **-987-**** / 내선 123",
+            mask_by_regex_groups(text, r"(?P<head>\d{2,3})-\d{3,4}-(?P<tail>\d{4})", '*').unwrap()
         );
 
-        target = "꽊꽊이";
-        result = separate_simple_consonant_vowel(Some(target)).unwrap();
+        // 캡처 그룹이 없으면 매칭 전체
+        assert_eq!(
+            mask_by_regex(text, phone, '#').unwrap(),
+            mask_by_regex_groups(text, phone, '#').unwrap()
+        );
 
-        println!("separate result : {}", result);
+        // 중첩 그룹, 참여하지 않은 선택적 그룹
+        assert_eq!(
+            "a-●●●●",
+            mask_by_regex_groups("a-bcde", r"a-((bc)de)", '●').unwrap()
+        );
+        assert_eq!(
+            "x●y",
+            mask_by_regex_groups("x1y", r"x(\d)(z)?y", '●').unwrap()
+        );
 
-        assert_eq!("ㄲㅘㄲㄲㅘㄲㅇㅣ", result.as_str());
+        // 한글 매칭 및 매칭 없음
+        assert_eq!(
+            "***와 ***",
+            mask_by_regex("철수씨와 영희씨", r"[가-힣]{2}씨", '*').unwrap()
+        );
+        assert_eq!(
+            "**씨와 **씨",
+            mask_by_regex_groups("철수씨와 영희씨", r"([가-힣]{2})씨", '*').unwrap()
+        );
+        assert_eq!(text, mask_by_regex(text, r"\d{5,}", '*').unwrap());
+
+        let result = mask_by_regex(text, "(", '*');
+
+        assert!(result.is_err());
+        assert!(!result.unwrap_err().get_message().is_empty());
+        assert!(mask_by_regex_groups(text, "[", '*').is_err());
     }
 
     #[test]
-    fn separate_consonant_vowel_completely_test() {
-        let mut target = "한글만";
-        let mut result = separate_consonant_vowel_completely(Some(target)).unwrap();
+    fn korean_sort_test() {
+        use std::cmp::Ordering;
 
-        println!("separate result : {}", result);
+        let mut words = vec!["하늘", "바다", "가방", "나무", "가", "각", "까치", "다람쥐"];
+
+        words.sort_by(|a, b| korean_compare(a, b));
 
         assert_eq!(
-            "ㅎㅏㄴㄱㅡㄹㅁㅏㄴ",
-            result.as_str(),
-            "한글만 있는 초/중/종성 분리 실패"
+            vec!["가", "가방", "각", "까치", "나무", "다람쥐", "바다", "하늘"],
+            words
         );
 
-        target = "꽊꽊이";
-        result = separate_consonant_vowel_completely(Some(target)).unwrap();
+        // 호환 자모 : 코드 포인트 순서로는 'ㄳ'(U+3133)이 'ㄴ'(U+3134)보다 앞서지만 자음 'ㄱ' 계열로 정렬
+        let mut jamo = vec!["나", "ㅏ", "ㄴ", "ㄳ", "가", "ㄱ", "하"];
 
-        println!("separate result : {}", result);
+        jamo.sort_by_cached_key(|v| korean_sort_key(v));
+
+        assert_eq!(vec!["ㄱ", "ㄳ", "가", "ㄴ", "나", "하", "ㅏ"], jamo);
+
+        // 분류 순서 : 한글 < 영문 < 숫자 < 기타
+        let mut mixed = vec!["#tag", "2024", "Banana", "apple", "사과", "10", "9"];
+
+        mixed.sort_by(|a, b| korean_compare(a, b));
 
         assert_eq!(
-            "ㄱㄱㅗㅏㄱㄱㄱㄱㅗㅏㄱㄱㅇㅣ",
-            result.as_str(),
-            "쌍자음, 이중 모음이 있을 경우 분리 실패"
+            vec!["사과", "apple", "Banana", "10", "2024", "9", "#tag"],
+            mixed
         );
 
-        target = "꽊많이 줬으면 좋겠어요1❤❤.";
-        result = separate_consonant_vowel_completely(Some(target)).unwrap();
+        // 접두사, 대소문자, 동일 문자열
+        assert_eq!(Ordering::Less, korean_compare("가", "가나"));
+        assert_eq!(Ordering::Less, korean_compare("apple", "Apple"));
+        assert_eq!(Ordering::Less, korean_compare("Apple", "apricot"));
+        assert_eq!(Ordering::Equal, korean_compare("한글", "한글"));
+        assert_eq!(Ordering::Less, korean_compare("", "가"));
 
-        println!("separate result : {}", result);
+        // NUL 및 제어 문자는 기타 문자로 처리
+        assert!(!korean_sort_key("a\0b").is_empty());
+        assert_eq!(Ordering::Less, korean_compare("가\0", "가\u{1}"));
+        assert_eq!(Ordering::Greater, korean_compare("\0", "ㄳ"));
+        assert_eq!(Ordering::Greater, korean_compare("\t가", "a"));
+        assert_eq!(Ordering::Equal, korean_compare("a\0b", "a\0b"));
+    }
+
+    #[test]
+    fn substring_test() {
+        let target = "안녕 Rust😀👍🏽!";
+
+        assert_eq!(Some("안녕"), substring(target, 0, 2));
+        assert_eq!(Some("Rust"), substring(target, 3, 7));
+        assert_eq!(Some("😀"), substring(target, 7, 8));
+        assert_eq!(Some("👍🏽"), substring(target, 8, 10)); // 피부색 수식자 포함 2글자
+        assert_eq!(Some("!"), substring(target, 10, 11));
+        assert_eq!(Some(target), substring(target, 0, 11));
+        assert_eq!(Some(""), substring(target, 11, 11));
+        assert_eq!(Some(""), substring(target, 3, 3));
+
+        // 경계 초과, 역순 범위
+        assert_eq!(None, substring(target, 0, 12));
+        assert_eq!(None, substring(target, 12, 12));
+        assert_eq!(None, substring(target, 5, 4));
+        assert_eq!(None, substring("", 0, 1));
+        assert_eq!(Some(""), substring("", 0, 0));
+    }
+
+    #[test]
+    fn frequency_test() {
+        let text = "한글과 English가 섞인 text. 한글, 한글! english?\n섞인—텍스트";
+
+        // 문자 빈도
+        let chars = char_frequency("한글 한글 ab A");
 
+        assert_eq!(2, chars[&'한']);
+        assert_eq!(2, chars[&'글']);
+        assert_eq!(3, chars[&' ']);
+        assert_eq!(1, chars[&'a']);
+        assert_eq!(1, chars[&'A']);
+        assert_eq!(6, chars.len());
+        assert!(char_frequency("").is_empty());
+
+        // 단어 빈도 (대소문자 구분)
+        let words = word_frequency(text);
+
+        assert_eq!(2, words["한글"]);
+        assert_eq!(1, words["한글과"]);
+        assert_eq!(1, words["English가"]);
+        assert_eq!(1, words["english"]);
+        assert_eq!(2, words["섞인"]);
+        assert_eq!(1, words["텍스트"]);
+        assert_eq!(1, words["text"]);
+        assert_eq!(7, words.len());
+        assert!(word_frequency("").is_empty());
+        assert!(word_frequency(" .,!? ").is_empty());
+
+        // 대소문자 무시
+        let words = word_frequency_ignore_case("Apple apple APPLE banana Banana 사과");
+
+        assert_eq!(3, words["apple"]);
+        assert_eq!(2, words["banana"]);
+        assert_eq!(1, words["사과"]);
+        assert_eq!(3, words.len());
+
+        // 상위 N개 : 횟수 내림차순, 같으면 사전순
         assert_eq!(
-            "ㄱㄱㅗㅏㄱㄱㅁㅏㄴㅎㅇㅣ ㅈㅜㅓㅅㅅㅇㅡㅁㅕㄴ ㅈㅗㅎㄱㅔㅅㅅㅇㅓㅇㅛ1❤❤.",
-            result.as_str(),
-            "쌍자음, 이중모음이 있을 경우 분리 실패"
+            vec![
+                ("apple".to_owned(), 3),
+                ("banana".to_owned(), 2),
+                ("사과".to_owned(), 1)
+            ],
+            top_n_words(&words, 10)
         );
+        assert_eq!(
+            vec![("a".to_owned(), 2), ("b".to_owned(), 2)],
+            top_n_words(&word_frequency("c b a b a"), 2)
+        );
+        assert!(top_n_words(&words, 0).is_empty());
+    }
 
-        target =
-            r#""투표율을 40%(percentage) 초중반대는 충분히 되지 않을까 생각한다"며 말문을 뗐다."#;
-        result = separate_consonant_vowel_completely(Some(target)).unwrap();
+    #[test]
+    fn base32_test() {
+        // RFC 4648 §10 테스트 벡터
+        let vectors = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
 
-        println!("separate result : {}", result);
+        for (plain, encoded) in vectors {
+            assert_eq!(encoded, encode_base32(plain.as_bytes()));
+            assert_eq!(
+                encoded.trim_end_matches('='),
+                encode_base32_with_option(plain.as_bytes(), false)
+            );
+            assert_eq!(plain.as_bytes(), decode_base32(encoded).unwrap().as_slice());
+            assert_eq!(
+                plain.as_bytes(),
+                decode_base32(encoded.trim_end_matches('='))
+                    .unwrap()
+                    .as_slice(),
+                "padding 없는 디코딩 실패 : {}",
+                encoded
+            );
+            assert_eq!(
+                plain.as_bytes(),
+                decode_base32(&encoded.to_lowercase()).unwrap().as_slice(),
+                "소문자 디코딩 실패 : {}",
+                encoded
+            );
+        }
+
+        // 임의 바이트 왕복
+        let data: Vec<u8> = (0..=255u8).collect();
+
+        for len in 0..data.len() {
+            let encoded = encode_base32(&data[..len]);
+
+            assert!(encoded.len().is_multiple_of(8));
+            assert_eq!(&data[..len], decode_base32(&encoded).unwrap().as_slice());
+        }
+
+        // 잘못된 문자
+        let result = decode_base32("MZXW6YT1");
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().get_message().contains("1"));
+        assert!(decode_base32("MZXW 6YTB").is_err());
+        assert!(decode_base32("MZXW6YTß").is_err());
+
+        // 잘못된 padding 및 길이
+        assert!(decode_base32("MY=====").is_err()); // 전체 길이가 8의 배수가 아님
+        assert!(decode_base32("MY==MY==").is_err()); // padding 뒤 문자
+        assert!(decode_base32("========").is_err());
+        assert!(decode_base32("M").is_err());
+        assert!(decode_base32("MZX").is_err());
+        assert!(decode_base32("MZXW6Y").is_err());
+    }
+
+    #[test]
+    fn is_balanced_test() {
+        // 중첩된 균형
+        assert!(is_balanced_brackets(""));
+        assert!(is_balanced_brackets("텍스트만 있음"));
+        assert!(is_balanced_brackets("()[]{}"));
+        assert!(is_balanced_brackets("{[()()]}"));
+        assert!(is_balanced_brackets("a(b[c{d}e]f)g"));
+
+        // 불균형
+        assert!(!is_balanced_brackets("((")); // 닫히지 않음
+        assert!(!is_balanced_brackets("{[()]"));
+        assert!(!is_balanced_brackets(")")); // 닫힘만 있음
+        assert!(!is_balanced_brackets("]]"));
+        assert!(!is_balanced_brackets(")(")); // 순서 뒤바뀜
+        assert!(!is_balanced_brackets("([)]"));
+        assert!(!is_balanced_brackets("{(})"));
+
+        // 사용자 지정 쌍 (태그, 전각 괄호, 여닫는 문자가 같은 쌍)
+        let pairs = [('<', '>'), ('「', '」'), ('|', '|')];
+
+        assert!(is_balanced("<div><span></span></div>", &pairs));
+        assert!(is_balanced("「인용 <강조>」", &pairs));
+        assert!(!is_balanced("「인용 <강조」>", &pairs));
+        assert!(is_balanced("|a| + |<b>|", &pairs));
+        assert!(!is_balanced("|a| + |b", &pairs));
+        assert!(is_balanced("(]", &pairs));
+
+        // 문자열 리터럴 무시
+        let code = r#"if (s == ")" || c == '[') { call("\")(") }"#;
+
+        assert!(!is_balanced_brackets(code));
+        assert!(is_balanced_with_option(code, DEFAULT_BRACKET_PAIRS, true));
+        assert!(!is_balanced_with_option(
+            r#"f(")"#,
+            DEFAULT_BRACKET_PAIRS,
+            true
+        )); // 닫히지 않은 리터럴
+        assert!(is_balanced_with_option(
+            r#"f(")")"#,
+            DEFAULT_BRACKET_PAIRS,
+            true
+        ));
+        assert!(!is_balanced_with_option(
+            r#"f(")""#,
+            DEFAULT_BRACKET_PAIRS,
+            true
+        ));
+    }
+
+    #[test]
+    fn parse_key_value_test() {
+        let result = parse_key_value("a=1;b=2", ';', '=').unwrap();
+
+        assert_eq!(2, result.len());
+        assert_eq!("1", result["a"]);
+        assert_eq!("2", result["b"]);
+
+        // 빈 입력, 빈 토큰, 공백
+        assert!(parse_key_value("", ';', '=').unwrap().is_empty());
+        assert!(parse_key_value(" ; ;", ';', '=').unwrap().is_empty());
+
+        let result = parse_key_value(" a = 1 ;;b=2;", ';', '=').unwrap();
+
+        assert_eq!(2, result.len());
+        assert_eq!("1", result["a"]);
+
+        // 빈 값, 값에 포함된 kv_sep
+        let result = parse_key_value("empty=;expr=x=y", ';', '=').unwrap();
+
+        assert_eq!("", result["empty"]);
+        assert_eq!("x=y", result["expr"]);
+
+        // 중복 키는 마지막 우선
+        let result = parse_key_value("k=first&k=second&k=last", '&', '=').unwrap();
+
+        assert_eq!(1, result.len());
+        assert_eq!("last", result["k"]);
+
+        // 다른 구분자
+        let result = parse_key_value("이름:홍길동, 나이:30", ',', ':').unwrap();
+
+        assert_eq!("홍길동", result["이름"]);
+        assert_eq!("30", result["나이"]);
+
+        // 오류 : 구분자 없는 토큰, 빈 key
+        let result = parse_key_value("a=1;flag", ';', '=');
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().get_message().contains("flag"));
+        assert!(parse_key_value("=1", ';', '=').is_err());
+
+        // URL 디코딩
+        let result = parse_key_value_with_option(
+            "q=a%2Bb+c%3D%26&name=%EA%B9%80&%ED%82%A4=v",
+            '&',
+            '=',
+            true,
+        )
+        .unwrap();
 
+        assert_eq!("a+b c=&", result["q"]);
+        assert_eq!("김", result["name"]);
+        assert_eq!("v", result["키"]);
+
+        // 디코딩하지 않으면 원문 유지
         assert_eq!(
-            r#""ㅌㅜㅍㅛㅇㅠㄹㅇㅡㄹ 40%(percentage) ㅊㅗㅈㅜㅇㅂㅏㄴㄷㅐㄴㅡㄴ ㅊㅜㅇㅂㅜㄴㅎㅣ ㄷㅗㅣㅈㅣ ㅇㅏㄴㅎㅇㅡㄹㄱㄱㅏ ㅅㅐㅇㄱㅏㄱㅎㅏㄴㄷㅏ"ㅁㅕ ㅁㅏㄹㅁㅜㄴㅇㅡㄹ ㄷㄷㅔㅅㅅㄷㅏ."#,
-            result.as_str(),
-            "쌍자음, 이중모음, 특수 기호를 포함하는 경우 분리 실패"
+            "a%2Bb+c",
+            parse_key_value_with_option("q=a%2Bb+c", '&', '=', false).unwrap()["q"]
         );
+
+        // 잘못된 URL 인코딩
+        assert!(parse_key_value_with_option("q=%2", '&', '=', true).is_err());
+        assert!(parse_key_value_with_option("q=%zz", '&', '=', true).is_err());
+        assert!(parse_key_value_with_option("q=%+1", '&', '=', true).is_err());
+        assert!(parse_key_value_with_option("q=%FF", '&', '=', true).is_err());
+        assert!(parse_key_value_with_option("q=%EA%", '&', '=', true).is_err());
     }
 
     #[test]
-    fn random_string_test() {
-        let length = 17;
-        let result = generate_random_string(length);
+    fn number_lines_test() {
+        // 빈 입력
+        assert_eq!("", number_lines("", 1, 3));
 
-        assert!(result.is_some());
+        // 마지막 줄 개행 유무 보존
+        assert_eq!("1 | a", number_lines("a", 1, 1));
+        assert_eq!("1 | a\n", number_lines("a\n", 1, 1));
+        assert_eq!("1 | a\n2 | \n", number_lines("a\n\n", 1, 1));
+        assert_eq!("1 | \n", number_lines("\n", 1, 1));
 
-        let result = result.unwrap();
+        // CRLF/LF 혼합
+        assert_eq!(
+            "  1 | 첫째\r\n  2 | 둘째\n  3 | \r\n  4 | 넷째",
+            number_lines("첫째\r\n둘째\n\r\n넷째", 1, 3)
+        );
 
-        assert_eq!(length, result.len() as u32);
+        // start 오프셋
+        assert_eq!("0 | a\n1 | b", number_lines("a\nb", 0, 1));
+        assert_eq!("100 | a\n101 | b", number_lines("a\nb", 100, 2));
 
-        println!(
-            "--------------------------\nrandom string result1: {}--------------------\n",
-            result
+        // 폭 자동 확장 : 마지막 번호 자릿수 기준으로 모든 줄 정렬
+        let text = "x\n".repeat(10);
+        let result = number_lines(&text, 1, 1);
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(10, lines.len());
+        assert_eq!(" 1 | x", lines[0]);
+        assert_eq!("10 | x", lines[9]);
+        assert!(result.ends_with("x\n"));
+
+        // 구분자 지정
+        assert_eq!(
+            "   1\ta\n   2\tb",
+            number_lines_with_separator("a\nb", 1, 4, "\t")
         );
+    }
 
-        let length = 38;
-        let result = generate_random_string(length);
+    #[test]
+    fn ascii_case_preserving_test() {
+        let turkish = "İIıi Ğğ Şş";
 
-        assert!(result.is_some());
+        // 터키어 문자는 ASCII 'I', 'i'만 변환
+        assert_eq!("İiıi Ğğ Şş", to_ascii_lower_preserving(turkish));
+        assert_eq!("İIıI Ğğ Şş", to_ascii_upper_preserving(turkish));
 
-        let result = result.unwrap();
+        // 표준 변환과의 차이
+        assert_ne!(turkish.to_lowercase(), to_ascii_lower_preserving(turkish));
+        assert_ne!(turkish.to_uppercase(), to_ascii_upper_preserving(turkish));
+        assert_eq!("i\u{307}", "İ".to_lowercase());
+        assert_eq!("I", "ı".to_uppercase());
 
-        assert_eq!(length, result.len() as u32);
+        // 한글, 악센트 문자, 혼합 입력
+        let mixed = "HTTP-Header 한글값 Café ÉCOLE ß Σ";
 
-        println!(
-            "--------------------------\nrandom string result2: {}\n--------------------\n",
-            result
+        assert_eq!(
+            "http-header 한글값 café École ß Σ",
+            to_ascii_lower_preserving(mixed)
+        );
+        assert_eq!(
+            "HTTP-HEADER 한글값 CAFé ÉCOLE ß Σ",
+            to_ascii_upper_preserving(mixed)
         );
 
-        loop {
-            let result: Option<String> = generate_random_string_with_spec(40);
+        // byte 길이 보존
+        for v in [turkish, mixed, ""] {
+            assert_eq!(v.len(), to_ascii_lower_preserving(v).len());
+            assert_eq!(v.len(), to_ascii_upper_preserving(v).len());
+        }
+    }
 
-            if result.is_none() {
-                continue;
-            }
+    #[test]
+    fn highlight_test() {
+        let mark = |text: &str, keywords: &[&str]| highlight(text, keywords, "<mark>", "</mark>");
 
-            if result.as_ref().unwrap().contains("!") {
-                break;
-            }
-        }
+        // 기본 및 빈 입력
+        assert_eq!("", mark("", &["a"]));
+        assert_eq!("변화 없음", mark("변화 없음", &[]));
+        assert_eq!("변화 없음", mark("변화 없음", &[""]));
+        assert_eq!(
+            "<mark>한글</mark>과 <mark>한글</mark>",
+            mark("한글과 한글", &["한글"])
+        );
+
+        // 대소문자 구분
+        assert_eq!("Rust <mark>rust</mark>", mark("Rust rust", &["rust"]));
+
+        // 겹치는 키워드 : 같은 위치는 가장 긴 것 우선 (입력 순서 무관)
+        assert_eq!(
+            "<mark>서울특별시</mark> <mark>서울</mark>",
+            mark("서울특별시 서울", &["서울", "서울특별시"])
+        );
+        assert_eq!(
+            "<mark>서울특별시</mark> <mark>서울</mark>",
+            mark("서울특별시 서울", &["서울특별시", "서울"])
+        );
+
+        // 겹치는 키워드 : 먼저 시작하는 구간 우선, 강조된 구간은 재검사하지 않음
+        assert_eq!("<mark>abc</mark>d", mark("abcd", &["abc", "cd"]));
+
+        // 연속 일치
+        assert_eq!("<mark>ab</mark><mark>ab</mark>", mark("abab", &["ab"]));
+
+        // 대소문자 무시 : 원본 대소문자 보존
+        assert_eq!(
+            "<b>RUST</b>와 <b>Rust</b>, <b>rust</b>",
+            highlight_with_option("RUST와 Rust, rust", &["rust"], "<b>", "</b>", true)
+        );
+        assert_eq!(
+            "<b>Straße</b>",
+            highlight_with_option("Straße", &["STRAßE"], "<b>", "</b>", true)
+        );
+
+        // 이중 처리 방지
+        let once = mark("search keyword in search result", &["search", "result"]);
+
+        assert_eq!(
+            "<mark>search</mark> keyword in <mark>search</mark> <mark>result</mark>",
+            once
+        );
+        assert_eq!(once, mark(&once, &["search", "result"]));
+        // 키워드가 마커 문자열과 겹치더라도 마커 내부는 변경하지 않음
+        assert_eq!(once, mark(&once, &["search", "result", "mark"]));
+
+        // 닫히지 않은 prefix는 일반 텍스트로 처리
+        assert_eq!("<mark><mark>x</mark>", mark("<mark>x", &["x"]));
     }
 }